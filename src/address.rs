@@ -0,0 +1,785 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, BoundingBox, Coordinates, Country, Timezone};
+
+/// A city addresses are generated in.
+struct City {
+    name: &'static str,
+    /// The state, province, county or prefecture.
+    region: &'static str,
+    /// The leading characters of the city's postal codes.
+    postal_prefix: &'static str,
+    latitude: f64,
+    longitude: f64,
+    timezone: Timezone,
+}
+
+const fn city(
+    name: &'static str,
+    region: &'static str,
+    postal_prefix: &'static str,
+    latitude: f64,
+    longitude: f64,
+    timezone: Timezone,
+) -> City {
+    City {
+        name,
+        region,
+        postal_prefix,
+        latitude,
+        longitude,
+        timezone,
+    }
+}
+
+const US_CITIES: [City; 10] = [
+    city("New York", "NY", "100", 40.7128, -74.006, Timezone::NewYork),
+    city(
+        "Los Angeles",
+        "CA",
+        "900",
+        34.0522,
+        -118.2437,
+        Timezone::LosAngeles,
+    ),
+    city("Chicago", "IL", "606", 41.8781, -87.6298, Timezone::Chicago),
+    city("Houston", "TX", "770", 29.7604, -95.3698, Timezone::Chicago),
+    city("Phoenix", "AZ", "850", 33.4484, -112.074, Timezone::Phoenix),
+    city(
+        "Seattle",
+        "WA",
+        "981",
+        47.6062,
+        -122.3321,
+        Timezone::LosAngeles,
+    ),
+    city("Denver", "CO", "802", 39.7392, -104.9903, Timezone::Denver),
+    city("Boston", "MA", "021", 42.3601, -71.0589, Timezone::NewYork),
+    city("Atlanta", "GA", "303", 33.749, -84.388, Timezone::NewYork),
+    city("Miami", "FL", "331", 25.7617, -80.1918, Timezone::NewYork),
+];
+const CA_CITIES: [City; 6] = [
+    city("Toronto", "ON", "M5V", 43.6532, -79.3832, Timezone::Toronto),
+    city(
+        "Montreal",
+        "QC",
+        "H2X",
+        45.5017,
+        -73.5673,
+        Timezone::Toronto,
+    ),
+    city(
+        "Vancouver",
+        "BC",
+        "V6B",
+        49.2827,
+        -123.1207,
+        Timezone::Vancouver,
+    ),
+    city(
+        "Calgary",
+        "AB",
+        "T2P",
+        51.0447,
+        -114.0719,
+        Timezone::Edmonton,
+    ),
+    city("Ottawa", "ON", "K1P", 45.4215, -75.6972, Timezone::Toronto),
+    city(
+        "Winnipeg",
+        "MB",
+        "R3C",
+        49.8951,
+        -97.1384,
+        Timezone::Winnipeg,
+    ),
+];
+const GB_CITIES: [City; 8] = [
+    city(
+        "London",
+        "Greater London",
+        "SE1",
+        51.5074,
+        -0.1278,
+        Timezone::London,
+    ),
+    city(
+        "Manchester",
+        "Greater Manchester",
+        "M1",
+        53.4808,
+        -2.2426,
+        Timezone::London,
+    ),
+    city(
+        "Birmingham",
+        "West Midlands",
+        "B1",
+        52.4862,
+        -1.8904,
+        Timezone::London,
+    ),
+    city(
+        "Leeds",
+        "West Yorkshire",
+        "LS1",
+        53.8008,
+        -1.5491,
+        Timezone::London,
+    ),
+    city(
+        "Glasgow",
+        "Glasgow City",
+        "G1",
+        55.8642,
+        -4.2518,
+        Timezone::London,
+    ),
+    city(
+        "Edinburgh",
+        "City of Edinburgh",
+        "EH1",
+        55.9533,
+        -3.1883,
+        Timezone::London,
+    ),
+    city(
+        "Bristol",
+        "Bristol",
+        "BS1",
+        51.4545,
+        -2.5879,
+        Timezone::London,
+    ),
+    city(
+        "Liverpool",
+        "Merseyside",
+        "L1",
+        53.4084,
+        -2.9916,
+        Timezone::London,
+    ),
+];
+const DE_CITIES: [City; 8] = [
+    city("Berlin", "Berlin", "10", 52.52, 13.405, Timezone::Berlin),
+    city(
+        "Hamburg",
+        "Hamburg",
+        "20",
+        53.5511,
+        9.9937,
+        Timezone::Berlin,
+    ),
+    city("München", "Bayern", "80", 48.1351, 11.582, Timezone::Berlin),
+    city(
+        "Köln",
+        "Nordrhein-Westfalen",
+        "50",
+        50.9375,
+        6.9603,
+        Timezone::Berlin,
+    ),
+    city(
+        "Frankfurt am Main",
+        "Hessen",
+        "60",
+        50.1109,
+        8.6821,
+        Timezone::Berlin,
+    ),
+    city(
+        "Stuttgart",
+        "Baden-Württemberg",
+        "70",
+        48.7758,
+        9.1829,
+        Timezone::Berlin,
+    ),
+    city(
+        "Leipzig",
+        "Sachsen",
+        "04",
+        51.3397,
+        12.3731,
+        Timezone::Berlin,
+    ),
+    city(
+        "Dresden",
+        "Sachsen",
+        "01",
+        51.0504,
+        13.7373,
+        Timezone::Berlin,
+    ),
+];
+const FR_CITIES: [City; 8] = [
+    city(
+        "Paris",
+        "Île-de-France",
+        "75",
+        48.8566,
+        2.3522,
+        Timezone::Paris,
+    ),
+    city(
+        "Marseille",
+        "Provence-Alpes-Côte d'Azur",
+        "13",
+        43.2965,
+        5.3698,
+        Timezone::Paris,
+    ),
+    city(
+        "Lyon",
+        "Auvergne-Rhône-Alpes",
+        "69",
+        45.764,
+        4.8357,
+        Timezone::Paris,
+    ),
+    city(
+        "Toulouse",
+        "Occitanie",
+        "31",
+        43.6047,
+        1.4442,
+        Timezone::Paris,
+    ),
+    city(
+        "Nice",
+        "Provence-Alpes-Côte d'Azur",
+        "06",
+        43.7102,
+        7.262,
+        Timezone::Paris,
+    ),
+    city(
+        "Nantes",
+        "Pays de la Loire",
+        "44",
+        47.2184,
+        -1.5536,
+        Timezone::Paris,
+    ),
+    city(
+        "Bordeaux",
+        "Nouvelle-Aquitaine",
+        "33",
+        44.8378,
+        -0.5792,
+        Timezone::Paris,
+    ),
+    city(
+        "Lille",
+        "Hauts-de-France",
+        "59",
+        50.6292,
+        3.0573,
+        Timezone::Paris,
+    ),
+];
+const ES_CITIES: [City; 7] = [
+    city("Madrid", "Madrid", "28", 40.4168, -3.7038, Timezone::Madrid),
+    city(
+        "Barcelona",
+        "Barcelona",
+        "08",
+        41.3851,
+        2.1734,
+        Timezone::Madrid,
+    ),
+    city(
+        "Valencia",
+        "Valencia",
+        "46",
+        39.4699,
+        -0.3763,
+        Timezone::Madrid,
+    ),
+    city(
+        "Sevilla",
+        "Sevilla",
+        "41",
+        37.3891,
+        -5.9845,
+        Timezone::Madrid,
+    ),
+    city(
+        "Zaragoza",
+        "Zaragoza",
+        "50",
+        41.6488,
+        -0.8891,
+        Timezone::Madrid,
+    ),
+    city("Málaga", "Málaga", "29", 36.7213, -4.4214, Timezone::Madrid),
+    city("Bilbao", "Bizkaia", "48", 43.263, -2.935, Timezone::Madrid),
+];
+const IT_CITIES: [City; 7] = [
+    city("Roma", "RM", "001", 41.9028, 12.4964, Timezone::Rome),
+    city("Milano", "MI", "201", 45.4642, 9.19, Timezone::Rome),
+    city("Napoli", "NA", "801", 40.8518, 14.2681, Timezone::Rome),
+    city("Torino", "TO", "101", 45.0703, 7.6869, Timezone::Rome),
+    city("Palermo", "PA", "901", 38.1157, 13.3615, Timezone::Rome),
+    city("Bologna", "BO", "401", 44.4949, 11.3426, Timezone::Rome),
+    city("Firenze", "FI", "501", 43.7696, 11.2558, Timezone::Rome),
+];
+const NL_CITIES: [City; 6] = [
+    city(
+        "Amsterdam",
+        "Noord-Holland",
+        "10",
+        52.3676,
+        4.9041,
+        Timezone::Amsterdam,
+    ),
+    city(
+        "Rotterdam",
+        "Zuid-Holland",
+        "30",
+        51.9244,
+        4.4777,
+        Timezone::Amsterdam,
+    ),
+    city(
+        "Den Haag",
+        "Zuid-Holland",
+        "25",
+        52.0705,
+        4.3007,
+        Timezone::Amsterdam,
+    ),
+    city(
+        "Utrecht",
+        "Utrecht",
+        "35",
+        52.0907,
+        5.1214,
+        Timezone::Amsterdam,
+    ),
+    city(
+        "Eindhoven",
+        "Noord-Brabant",
+        "56",
+        51.4416,
+        5.4697,
+        Timezone::Amsterdam,
+    ),
+    city(
+        "Groningen",
+        "Groningen",
+        "97",
+        53.2194,
+        6.5665,
+        Timezone::Amsterdam,
+    ),
+];
+const AU_CITIES: [City; 7] = [
+    city("Sydney", "NSW", "20", -33.8688, 151.2093, Timezone::Sydney),
+    city(
+        "Melbourne",
+        "VIC",
+        "30",
+        -37.8136,
+        144.9631,
+        Timezone::Melbourne,
+    ),
+    city(
+        "Brisbane",
+        "QLD",
+        "40",
+        -27.4698,
+        153.0251,
+        Timezone::Brisbane,
+    ),
+    city("Perth", "WA", "60", -31.9505, 115.8605, Timezone::Perth),
+    city(
+        "Adelaide",
+        "SA",
+        "50",
+        -34.9285,
+        138.6007,
+        Timezone::Adelaide,
+    ),
+    city("Hobart", "TAS", "70", -42.8821, 147.3272, Timezone::Hobart),
+    city("Canberra", "ACT", "26", -35.2809, 149.13, Timezone::Sydney),
+];
+const JP_CITIES: [City; 7] = [
+    city("Tokyo", "Tokyo", "100", 35.6762, 139.6503, Timezone::Tokyo),
+    city("Osaka", "Osaka", "530", 34.6937, 135.5023, Timezone::Tokyo),
+    city(
+        "Yokohama",
+        "Kanagawa",
+        "220",
+        35.4437,
+        139.638,
+        Timezone::Tokyo,
+    ),
+    city("Nagoya", "Aichi", "450", 35.1815, 136.9066, Timezone::Tokyo),
+    city(
+        "Sapporo",
+        "Hokkaido",
+        "060",
+        43.0618,
+        141.3545,
+        Timezone::Tokyo,
+    ),
+    city(
+        "Fukuoka",
+        "Fukuoka",
+        "810",
+        33.5904,
+        130.4017,
+        Timezone::Tokyo,
+    ),
+    city("Kyoto", "Kyoto", "600", 35.0116, 135.7681, Timezone::Tokyo),
+];
+
+const ENGLISH_STREET_NAMES: [&str; 12] = [
+    "Main",
+    "Oak",
+    "Maple",
+    "Cedar",
+    "Elm",
+    "Washington",
+    "Park",
+    "Lake",
+    "Hill",
+    "Pine",
+    "Church",
+    "Highland",
+];
+const ENGLISH_STREET_TYPES: [&str; 6] = ["Street", "Avenue", "Road", "Drive", "Lane", "Boulevard"];
+const GB_STREETS: [&str; 10] = [
+    "High Street",
+    "Station Road",
+    "Church Lane",
+    "Victoria Road",
+    "Mill Lane",
+    "Park Road",
+    "Queen Street",
+    "King Street",
+    "London Road",
+    "The Crescent",
+];
+const DE_STREETS: [&str; 10] = [
+    "Hauptstraße",
+    "Bahnhofstraße",
+    "Gartenstraße",
+    "Schulstraße",
+    "Dorfstraße",
+    "Bergstraße",
+    "Lindenstraße",
+    "Kirchstraße",
+    "Goethestraße",
+    "Schillerstraße",
+];
+const FR_STREETS: [&str; 10] = [
+    "rue de la Paix",
+    "rue Victor Hugo",
+    "rue de la République",
+    "boulevard Voltaire",
+    "rue Jean Jaurès",
+    "place de la Mairie",
+    "rue Pasteur",
+    "avenue Foch",
+    "rue du Moulin",
+    "avenue de la Gare",
+];
+const ES_STREETS: [&str; 8] = [
+    "Calle Mayor",
+    "Calle Real",
+    "Avenida de la Constitución",
+    "Calle del Sol",
+    "Plaza de España",
+    "Calle Nueva",
+    "Gran Vía",
+    "Calle de Alcalá",
+];
+const IT_STREETS: [&str; 8] = [
+    "Via Roma",
+    "Via Garibaldi",
+    "Via Mazzini",
+    "Corso Vittorio Emanuele",
+    "Via Dante",
+    "Via Verdi",
+    "Piazza della Repubblica",
+    "Via Cavour",
+];
+const NL_STREETS: [&str; 8] = [
+    "Kerkstraat",
+    "Dorpsstraat",
+    "Schoolstraat",
+    "Molenweg",
+    "Stationsweg",
+    "Julianastraat",
+    "Wilhelminastraat",
+    "Beatrixlaan",
+];
+/// Japanese addresses name a district instead of a street.
+const JP_DISTRICTS: [&str; 8] = [
+    "Chiyoda",
+    "Minato",
+    "Shibuya",
+    "Nakamachi",
+    "Honcho",
+    "Sakae",
+    "Midori",
+    "Higashi",
+];
+
+/// How far the coordinates of an address may be from the city center, in degrees of latitude and longitude.
+const CITY_RADIUS_DEGREES: f64 = 0.1;
+
+/// The letters British postcodes use for their last two characters.
+const GB_POSTCODE_LETTERS: &[u8] = b"ABDEFGHJLNPQRSTUWXYZ";
+/// The letters Canadian postal codes use.
+const CA_POSTAL_LETTERS: &[u8] = b"ABCEGHJKLMNPRSTVWXYZ";
+
+/// A postal address.
+/// ## Example
+/// ```rust
+/// use person::{Address, Country};
+/// let address = Address::random(Country::Canada);
+/// assert_eq!(address.country, Country::Canada);
+/// assert_eq!(address.postal_code.len(), 7);
+/// assert!(Country::Canada.bounding_box().contains(address.coordinates.unwrap()));
+/// assert!(person::is_valid_postal_code(Country::Canada, &address.postal_code));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address {
+    /// The house number, like "221" or "1-2-3" for the chōme, block and building of a Japanese address.
+    pub street_number: String,
+    /// The street, or the district of a Japanese address.
+    pub street_name: String,
+    pub city: String,
+    /// The state, province, county or prefecture.
+    pub region: String,
+    pub postal_code: String,
+    pub country: Country,
+    /// The location of the address, for testing maps and geofencing.
+    pub coordinates: Option<Coordinates>,
+}
+impl Address {
+    /// Creates a random address in one of the larger cities of the given country,
+    /// with coordinates within about 10 km of the city center.
+    pub fn random(country: Country) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`Address::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Self {
+        let city = cities(country).choose(rng).unwrap();
+        let (street_number, street_name) = match country {
+            Country::UnitedStates | Country::Canada | Country::Australia => (
+                rng.gen_range(1..10000).to_string(),
+                format!(
+                    "{} {}",
+                    ENGLISH_STREET_NAMES.choose(rng).unwrap(),
+                    ENGLISH_STREET_TYPES.choose(rng).unwrap()
+                ),
+            ),
+            Country::Japan => (
+                format!(
+                    "{}-{}-{}",
+                    rng.gen_range(1..6),
+                    rng.gen_range(1..30),
+                    rng.gen_range(1..20)
+                ),
+                JP_DISTRICTS.choose(rng).unwrap().to_string(),
+            ),
+            _ => {
+                let streets: &[&str] = match country {
+                    Country::UnitedKingdom => &GB_STREETS,
+                    Country::Germany => &DE_STREETS,
+                    Country::France => &FR_STREETS,
+                    Country::Spain => &ES_STREETS,
+                    Country::Italy => &IT_STREETS,
+                    _ => &NL_STREETS,
+                };
+                (
+                    rng.gen_range(1..300).to_string(),
+                    streets.choose(rng).unwrap().to_string(),
+                )
+            }
+        };
+        Self {
+            street_number,
+            street_name,
+            city: city.name.to_string(),
+            region: city.region.to_string(),
+            postal_code: postal_code(rng, country, city.postal_prefix),
+            country,
+            coordinates: Some(Coordinates::random_in_custom_rng(
+                rng,
+                &BoundingBox {
+                    min_latitude: city.latitude - CITY_RADIUS_DEGREES,
+                    max_latitude: city.latitude + CITY_RADIUS_DEGREES,
+                    min_longitude: city.longitude - CITY_RADIUS_DEGREES,
+                    max_longitude: city.longitude + CITY_RADIUS_DEGREES,
+                },
+            )),
+        }
+    }
+
+    /// Returns the time zone of the city, or of the country's capital for cities this crate doesn't know.
+    pub fn timezone(&self) -> Timezone {
+        cities(self.country)
+            .iter()
+            .find(|city| city.name == self.city)
+            .map_or(Timezone::for_country(self.country), |city| city.timezone)
+    }
+
+    /// Returns the lines of the address as written on mail sent from the given country.
+    /// The layout follows the conventions of the address' own country, like the state and ZIP code line
+    /// in the United States, the postcode on its own line in the United Kingdom, the postal code before
+    /// the city in Germany or the reversed order of Japan. Mail sent from another country gets
+    /// the country name as last line.
+    /// ## Example
+    /// ```rust
+    /// use person::{Address, Country};
+    /// let address = Address {
+    ///     street_number: "12".into(),
+    ///     street_name: "Hauptstraße".into(),
+    ///     city: "Berlin".into(),
+    ///     region: "Berlin".into(),
+    ///     postal_code: "10115".into(),
+    ///     country: Country::Germany,
+    ///     coordinates: None,
+    /// };
+    /// assert_eq!(address.format_for(Country::Germany), ["Hauptstraße 12", "10115 Berlin"]);
+    /// assert_eq!(
+    ///     address.format_for(Country::France),
+    ///     ["Hauptstraße 12", "10115 Berlin", "GERMANY"]
+    /// );
+    /// ```
+    pub fn format_for(&self, sender: Country) -> Vec<String> {
+        let Self {
+            street_number: number,
+            street_name: street,
+            city,
+            region,
+            postal_code: code,
+            country,
+            ..
+        } = self;
+        let mut lines = match country {
+            Country::UnitedStates => vec![
+                format!("{number} {street}"),
+                format!("{city}, {region} {code}"),
+            ],
+            Country::Canada => vec![
+                format!("{number} {street}"),
+                format!("{city} {region}  {code}"),
+            ],
+            Country::Australia => vec![
+                format!("{number} {street}"),
+                format!("{} {region} {code}", city.to_uppercase()),
+            ],
+            Country::UnitedKingdom => vec![
+                format!("{number} {street}"),
+                city.to_uppercase(),
+                code.clone(),
+            ],
+            Country::France => vec![
+                format!("{number} {street}"),
+                format!("{code} {}", city.to_uppercase()),
+            ],
+            Country::Germany | Country::Netherlands => {
+                vec![format!("{street} {number}"), format!("{code} {city}")]
+            }
+            Country::Spain => vec![format!("{street}, {number}"), format!("{code} {city}")],
+            Country::Italy => vec![
+                format!("{street} {number}"),
+                format!("{code} {city} {region}"),
+            ],
+            Country::Japan if region == city => {
+                vec![
+                    format!("〒{code}"),
+                    city.clone(),
+                    format!("{street} {number}"),
+                ]
+            }
+            Country::Japan => vec![
+                format!("〒{code}"),
+                format!("{region} {city}"),
+                format!("{street} {number}"),
+            ],
+        };
+        if sender != *country {
+            lines.push(country.name().to_uppercase());
+        }
+        lines
+    }
+}
+impl std::fmt::Display for Address {
+    /// Writes the address on a single line, like "221 Main Street, Boston, MA 02108, United States".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = self.format_for(self.country);
+        write!(f, "{}, {}", lines.join(", "), self.country)
+    }
+}
+
+/// Generates a postal code of a random city of the country.
+pub(crate) fn random_postal_code<R: Rng + ?Sized>(rng: &mut R, country: Country) -> String {
+    let city = cities(country).choose(rng).unwrap();
+    postal_code(rng, country, city.postal_prefix)
+}
+
+/// Picks the name of a random city of the country.
+pub(crate) fn random_city<R: Rng + ?Sized>(rng: &mut R, country: Country) -> &'static str {
+    cities(country).choose(rng).unwrap().name
+}
+
+fn cities(country: Country) -> &'static [City] {
+    match country {
+        Country::UnitedStates => &US_CITIES,
+        Country::Canada => &CA_CITIES,
+        Country::UnitedKingdom => &GB_CITIES,
+        Country::Germany => &DE_CITIES,
+        Country::France => &FR_CITIES,
+        Country::Spain => &ES_CITIES,
+        Country::Italy => &IT_CITIES,
+        Country::Netherlands => &NL_CITIES,
+        Country::Australia => &AU_CITIES,
+        Country::Japan => &JP_CITIES,
+    }
+}
+
+/// Generates a postal code of the country starting with the prefix.
+pub(crate) fn postal_code<R: Rng + ?Sized>(rng: &mut R, country: Country, prefix: &str) -> String {
+    let letter = |rng: &mut R, letters: &[u8]| char::from(*letters.choose(rng).unwrap());
+    match country {
+        Country::UnitedStates
+        | Country::Germany
+        | Country::France
+        | Country::Spain
+        | Country::Italy => format!("{prefix}{}", random_digits(rng, 5 - prefix.len())),
+        Country::Australia => format!("{prefix}{}", random_digits(rng, 4 - prefix.len())),
+        Country::Canada => format!(
+            "{prefix} {}{}{}",
+            rng.gen_range(0..10),
+            letter(rng, CA_POSTAL_LETTERS),
+            rng.gen_range(0..10)
+        ),
+        Country::UnitedKingdom => format!(
+            "{prefix} {}{}{}",
+            rng.gen_range(0..10),
+            letter(rng, GB_POSTCODE_LETTERS),
+            letter(rng, GB_POSTCODE_LETTERS)
+        ),
+        Country::Netherlands => {
+            // "SA", "SD" and "SS" are not used.
+            let letters = loop {
+                let letters: String = (0..2)
+                    .map(|_| char::from(rng.gen_range(b'A'..=b'Z')))
+                    .collect();
+                if !matches!(letters.as_str(), "SA" | "SD" | "SS") {
+                    break letters;
+                }
+            };
+            format!("{prefix}{} {letters}", random_digits(rng, 2))
+        }
+        Country::Japan => format!("{prefix}-{}", random_digits(rng, 4)),
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Months, Utc};
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+use crate::{random_date_between, PersonError};
+
+/// A range of ages (both inclusive) with a relative weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgeBracket {
+    pub min_age: u32,
+    pub max_age: u32,
+    pub weight: f64,
+}
+impl AgeBracket {
+    pub const fn new(min_age: u32, max_age: u32, weight: f64) -> Self {
+        Self {
+            min_age,
+            max_age,
+            weight,
+        }
+    }
+}
+
+/// Approximate US population in millions per age group, based on the 2020 census.
+const US_CENSUS_2020: [AgeBracket; 18] = [
+    AgeBracket::new(0, 4, 19.4),
+    AgeBracket::new(5, 9, 20.2),
+    AgeBracket::new(10, 14, 21.6),
+    AgeBracket::new(15, 19, 21.6),
+    AgeBracket::new(20, 24, 21.0),
+    AgeBracket::new(25, 29, 22.1),
+    AgeBracket::new(30, 34, 22.3),
+    AgeBracket::new(35, 39, 21.6),
+    AgeBracket::new(40, 44, 20.6),
+    AgeBracket::new(45, 49, 20.1),
+    AgeBracket::new(50, 54, 20.5),
+    AgeBracket::new(55, 59, 21.7),
+    AgeBracket::new(60, 64, 20.9),
+    AgeBracket::new(65, 69, 17.9),
+    AgeBracket::new(70, 74, 14.6),
+    AgeBracket::new(75, 79, 9.8),
+    AgeBracket::new(80, 84, 6.4),
+    AgeBracket::new(85, 100, 6.4),
+];
+
+/// How the ages of generated `Person`s are distributed.
+/// ## Example
+/// ```rust
+/// use person::{AgeBracket, AgeDistribution, PersonGenerator};
+/// let mut generator = PersonGenerator::new().with_age_distribution(AgeDistribution::UsCensus2020);
+/// let person = generator.generate();
+/// assert!(person.get_age() <= 100);
+///
+/// let working_age = AgeDistribution::Weighted(vec![
+///     AgeBracket::new(18, 30, 1.0),
+///     AgeBracket::new(31, 67, 2.0),
+/// ]);
+/// let person = PersonGenerator::new().with_age_distribution(working_age).generate();
+/// assert!((18..=67).contains(&person.get_age()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgeDistribution {
+    /// Every age between 0 and 100 is equally likely.
+    Uniform,
+    /// Ages follow the population pyramid of the 2020 US census.
+    UsCensus2020,
+    /// Ages are picked from the given brackets according to their weights.
+    Weighted(Vec<AgeBracket>),
+}
+impl AgeDistribution {
+    /// Returns the brackets ages are picked from.
+    pub fn brackets(&self) -> Vec<AgeBracket> {
+        match self {
+            Self::Uniform => vec![AgeBracket::new(0, 100, 1.0)],
+            Self::UsCensus2020 => US_CENSUS_2020.to_vec(),
+            Self::Weighted(brackets) => brackets.clone(),
+        }
+    }
+
+    /// Picks a random age, returns an error if the brackets or weights are invalid.
+    pub fn sample_age<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<u32, PersonError> {
+        let brackets = self.brackets();
+        if brackets.iter().any(|b| b.min_age > b.max_age) {
+            return Err(PersonError::InvalidAgeDistribution);
+        }
+        let index = WeightedIndex::new(brackets.iter().map(|b| b.weight))
+            .map_err(|_| PersonError::InvalidAgeDistribution)?;
+        let bracket = brackets[index.sample(rng)];
+        Ok(rng.gen_range(bracket.min_age..=bracket.max_age))
+    }
+
+    /// Picks a random date of birth, so that the `Person` has a random age at the given reference date.
+    /// Returns an error if the brackets or weights are invalid, or if the age is too high to be
+    /// represented as a date.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{AgeBracket, AgeDistribution, PersonError};
+    /// let ancient = AgeDistribution::Weighted(vec![AgeBracket::new(400_000_000, 400_000_000, 1.0)]);
+    /// assert_eq!(
+    ///     ancient.sample_date_of_birth(&mut rand::thread_rng(), Utc::now()),
+    ///     Err(PersonError::InvalidAgeDistribution)
+    /// );
+    /// ```
+    pub fn sample_date_of_birth<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        now: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, PersonError> {
+        let age = self.sample_age(rng)?;
+        let years_ago = |years: u32| {
+            years
+                .checked_mul(12)
+                .and_then(|months| now.checked_sub_months(Months::new(months)))
+                .ok_or(PersonError::InvalidAgeDistribution)
+        };
+        let latest = years_ago(age)?;
+        let earliest = years_ago(
+            age.checked_add(1)
+                .ok_or(PersonError::InvalidAgeDistribution)?,
+        )?;
+        // The earliest date is excluded, as the person would already be one year older.
+        let dob = random_date_between(rng, earliest, latest)?;
+        Ok(if dob == earliest { latest } else { dob })
+    }
+}
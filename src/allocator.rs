@@ -0,0 +1,61 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Person;
+
+/// Tracks issued usernames and appends a number to colliding ones, e.g. "jdoe", "jdoe2", "jdoe3",
+/// for seeding systems with unique username constraints.
+/// ## Example
+/// ```rust
+/// use chrono::Utc;
+/// use person::{Person, UsernameAllocator};
+/// let mut allocator = UsernameAllocator::new();
+/// let jane = Person::from_parts("Jane", None, "Doe", Utc::now());
+/// let john = Person::from_parts("John", None, "Doe", Utc::now());
+/// assert_eq!(allocator.allocate_for(&jane), "jdoe");
+/// assert_eq!(allocator.allocate_for(&john), "jdoe2");
+/// assert_eq!(allocator.allocate("jdoe"), "jdoe3");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsernameAllocator {
+    issued: HashSet<String>,
+    next_suffix: HashMap<String, usize>,
+}
+impl UsernameAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues the username, or the username followed by the lowest number from 2 on that makes it unused.
+    pub fn allocate(&mut self, username: &str) -> String {
+        if self.issued.insert(username.to_string()) {
+            return username.to_string();
+        }
+        let suffix = self.next_suffix.entry(username.to_string()).or_insert(2);
+        loop {
+            let candidate = format!("{username}{suffix}");
+            *suffix += 1;
+            if self.issued.insert(candidate.clone()) {
+                return candidate;
+            }
+        }
+    }
+
+    /// Issues the `Person`'s [canonical username](Person::get_canonical_username).
+    pub fn allocate_for(&mut self, person: &Person) -> String {
+        self.allocate(&person.get_canonical_username())
+    }
+
+    /// Returns whether the username has been issued.
+    pub fn contains(&self, username: &str) -> bool {
+        self.issued.contains(username)
+    }
+
+    /// Returns how many usernames have been issued.
+    pub fn len(&self) -> usize {
+        self.issued.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issued.is_empty()
+    }
+}
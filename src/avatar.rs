@@ -0,0 +1,54 @@
+use crate::{hash::sha256, Person, SlugCase};
+
+/// A service that renders avatars for [`Person::get_avatar_url`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AvatarProvider {
+    /// Gravatar identicons, e.g. `https://www.gravatar.com/avatar/<hash>?d=identicon`.
+    Gravatar,
+    /// DiceBear avatars of the given style, like "initials" or "avataaars",
+    /// e.g. `https://api.dicebear.com/9.x/initials/svg?seed=jane-doe`.
+    DiceBear(&'static str),
+    /// UI Avatars with the `Person`'s initials, e.g. `https://ui-avatars.com/api/?name=Jane+Doe`.
+    UiAvatars,
+}
+
+impl Person {
+    /// Returns a URL of an avatar derived from the `Person`'s name. The URL is stable,
+    /// so the same `Person` always gets the same picture.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{AvatarProvider, Person};
+    /// let person = Person::from_parts("Jane", None, "Doe", Utc::now());
+    /// assert_eq!(
+    ///     person.get_avatar_url(AvatarProvider::DiceBear("initials")),
+    ///     "https://api.dicebear.com/9.x/initials/svg?seed=jane-doe"
+    /// );
+    /// assert!(person
+    ///     .get_avatar_url(AvatarProvider::Gravatar)
+    ///     .starts_with("https://www.gravatar.com/avatar/2751e2c022b8c61ce7b3780cf77ecb9cc49a79032ca339ef02d080acbda894a0"));
+    /// ```
+    pub fn get_avatar_url(&self, provider: AvatarProvider) -> String {
+        match provider {
+            AvatarProvider::Gravatar => {
+                let hash: String = sha256(self.get_slug().as_bytes())
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect();
+                format!("https://www.gravatar.com/avatar/{hash}?d=identicon")
+            }
+            AvatarProvider::DiceBear(style) => {
+                format!(
+                    "https://api.dicebear.com/9.x/{style}/svg?seed={}",
+                    self.get_slug()
+                )
+            }
+            AvatarProvider::UiAvatars => {
+                format!(
+                    "https://ui-avatars.com/api/?name={}",
+                    self.get_slug_with("+", SlugCase::Preserve)
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{national_id, random_digits, Country, Person};
+
+/// Banks of the countries using IBANs, with their national bank code and BIC.
+const BANKS: [(Country, &str, &str); 18] = [
+    (Country::UnitedKingdom, "NWBK", "NWBKGB2L"),
+    (Country::UnitedKingdom, "BARC", "BARCGB22"),
+    (Country::UnitedKingdom, "MIDL", "MIDLGB22"),
+    (Country::Germany, "37040044", "COBADEFFXXX"),
+    (Country::Germany, "50010517", "INGDDEFFXXX"),
+    (Country::Germany, "10010010", "PBNKDEFFXXX"),
+    (Country::France, "30004", "BNPAFRPP"),
+    (Country::France, "30003", "SOGEFRPP"),
+    (Country::France, "20041", "PSSTFRPP"),
+    (Country::Spain, "2100", "CAIXESBB"),
+    (Country::Spain, "0049", "BSCHESMM"),
+    (Country::Spain, "0182", "BBVAESMM"),
+    (Country::Italy, "03069", "BCITITMM"),
+    (Country::Italy, "02008", "UNCRITMM"),
+    (Country::Italy, "05034", "BAPPIT22"),
+    (Country::Netherlands, "ABNA", "ABNANL2A"),
+    (Country::Netherlands, "INGB", "INGBNL2A"),
+    (Country::Netherlands, "RABO", "RABONL2U"),
+];
+/// The first two digits of routing numbers the Federal Reserve has not assigned to any institution.
+const UNASSIGNED_ROUTING_PREFIXES: std::ops::RangeInclusive<u32> = 50..=59;
+
+/// A bank account identified by an IBAN, with the BIC of its bank.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BankAccount {
+    pub holder: String,
+    pub country: Country,
+    /// The IBAN without spaces, like "DE89370400440532013000".
+    pub iban: String,
+    pub bic: String,
+}
+impl BankAccount {
+    /// Generates a random account in the country, held by "Account Holder".
+    /// Returns `None` if the country doesn't use IBANs.
+    pub fn random(country: Country) -> Option<Self> {
+        Self::random_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`BankAccount::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Option<Self> {
+        let banks: Vec<_> = BANKS.iter().filter(|bank| bank.0 == country).collect();
+        let (_, bank, bic) = banks.choose(rng)?;
+        let bban = match country {
+            Country::UnitedKingdom => {
+                format!("{bank}{}{}", random_digits(rng, 6), random_digits(rng, 8))
+            }
+            Country::Germany => format!("{bank}{}", random_digits(rng, 10)),
+            Country::France => {
+                let branch = random_digits(rng, 5);
+                let account = random_digits(rng, 11);
+                let key = rib_key(&format!("{bank}{branch}{account}"));
+                format!("{bank}{branch}{account}{key:02}")
+            }
+            Country::Spain => {
+                let branch = random_digits(rng, 4);
+                let account = random_digits(rng, 10);
+                let first = spanish_check_digit(&format!("00{bank}{branch}"));
+                let second = spanish_check_digit(&account);
+                format!("{bank}{branch}{first}{second}{account}")
+            }
+            Country::Italy => {
+                let rest = format!("{bank}{}{}", random_digits(rng, 5), random_digits(rng, 12));
+                format!("{}{rest}", national_id::italian_check_letter(&rest))
+            }
+            _ => format!("{bank}{}", random_digits(rng, 10)),
+        };
+        Some(Self {
+            holder: "Account Holder".to_string(),
+            country,
+            iban: format!(
+                "{}{:02}{bban}",
+                country.code(),
+                iban_check_digits(country.code(), &bban)
+            ),
+            bic: bic.to_string(),
+        })
+    }
+
+    /// Returns the IBAN in groups of four characters, like "DE89 3704 0044 0532 0130 00".
+    pub fn formatted_iban(&self) -> String {
+        self.iban
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| String::from_utf8_lossy(chunk))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The kind of a United States bank account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UsAccountKind {
+    Checking,
+    Savings,
+}
+impl std::fmt::Display for UsAccountKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Checking => write!(f, "checking"),
+            Self::Savings => write!(f, "savings"),
+        }
+    }
+}
+
+/// A United States bank account for ACH transfers and direct deposits, identified by an ABA routing
+/// number and an account number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UsBankAccount {
+    pub holder: String,
+    /// The routing number, which has a valid check digit but an unassigned prefix, so it can't reach
+    /// a real bank.
+    pub routing_number: String,
+    pub account_number: String,
+    pub kind: UsAccountKind,
+}
+impl UsBankAccount {
+    /// Generates a random account, held by "Account Holder".
+    pub fn random() -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`UsBankAccount::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let base = format!(
+            "{}{}",
+            rng.gen_range(UNASSIGNED_ROUTING_PREFIXES),
+            random_digits(rng, 6)
+        );
+        let check = (10 - routing_sum(&base) % 10) % 10;
+        let account_length = rng.gen_range(10..=12);
+        Self {
+            holder: "Account Holder".to_string(),
+            routing_number: format!("{base}{check}"),
+            account_number: random_digits(rng, account_length),
+            kind: if rng.gen_bool(0.7) {
+                UsAccountKind::Checking
+            } else {
+                UsAccountKind::Savings
+            },
+        }
+    }
+}
+
+impl Person {
+    /// Generates a bank account in the country in the `Person`'s name.
+    /// Returns `None` if the country doesn't use IBANs.
+    /// ## Example
+    /// ```rust
+    /// use person::{is_valid_iban, Country, Person};
+    /// let person = Person::random();
+    /// let account = person.get_random_bank_account(Country::Germany).unwrap();
+    /// assert!(account.iban.starts_with("DE"));
+    /// assert_eq!(account.iban.len(), 22);
+    /// assert!(is_valid_iban(&account.formatted_iban()));
+    /// assert_eq!(&account.bic[4..6], "DE");
+    /// assert!(person.get_random_bank_account(Country::UnitedStates).is_none());
+    /// ```
+    pub fn get_random_bank_account(&self, country: Country) -> Option<BankAccount> {
+        self.get_random_bank_account_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`Person::get_random_bank_account`], but uses the given random number generator.
+    pub fn get_random_bank_account_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        country: Country,
+    ) -> Option<BankAccount> {
+        BankAccount::random_custom_rng(rng, country).map(|account| BankAccount {
+            holder: self.get_full_name(),
+            ..account
+        })
+    }
+
+    /// Generates a United States bank account in the `Person`'s name.
+    /// ## Example
+    /// ```rust
+    /// use person::{is_valid_routing_number, Person};
+    /// let account = Person::random().get_random_us_bank_account();
+    /// assert!(is_valid_routing_number(&account.routing_number));
+    /// assert!((10..=12).contains(&account.account_number.len()));
+    /// ```
+    pub fn get_random_us_bank_account(&self) -> UsBankAccount {
+        self.get_random_us_bank_account_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_us_bank_account`], but uses the given random number generator.
+    pub fn get_random_us_bank_account_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> UsBankAccount {
+        UsBankAccount {
+            holder: self.get_full_name(),
+            ..UsBankAccount::random_custom_rng(rng)
+        }
+    }
+}
+
+/// Returns whether the IBAN has a valid length and check digits. Spaces are ignored, and the length
+/// is only checked for the countries the crate generates IBANs for.
+/// ## Example
+/// ```rust
+/// use person::is_valid_iban;
+/// assert!(is_valid_iban("GB82 WEST 1234 5698 7654 32"));
+/// assert!(is_valid_iban("DE89370400440532013000"));
+/// assert!(!is_valid_iban("GB82 WEST 1234 5698 7654 33"));
+/// ```
+pub fn is_valid_iban(iban: &str) -> bool {
+    let iban: String = iban.chars().filter(|&c| c != ' ').collect();
+    if !(15..=34).contains(&iban.len())
+        || !iban
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        return false;
+    }
+    let length = match &iban[..2] {
+        "GB" | "DE" => Some(22),
+        "FR" | "IT" => Some(27),
+        "ES" => Some(24),
+        "NL" => Some(18),
+        _ => None,
+    };
+    length.is_none_or(|length| iban.len() == length)
+        && iban[2..4].bytes().all(|b| b.is_ascii_digit())
+        && mod97(&format!("{}{}", &iban[4..], &iban[..4])) == 1
+}
+
+/// Returns whether the ABA routing number has nine digits and a valid check digit.
+/// The prefix isn't checked, so assigned and unassigned routing numbers are both valid.
+/// ## Example
+/// ```rust
+/// use person::is_valid_routing_number;
+/// assert!(is_valid_routing_number("011000015"));
+/// assert!(!is_valid_routing_number("011000016"));
+/// ```
+pub fn is_valid_routing_number(routing_number: &str) -> bool {
+    routing_number.len() == 9
+        && routing_number.bytes().all(|b| b.is_ascii_digit())
+        && routing_sum(routing_number).is_multiple_of(10)
+}
+
+/// Computes the weighted sum of the digits of a routing number, which is divisible by 10 when it
+/// includes a valid check digit.
+fn routing_sum(digits: &str) -> u32 {
+    digits
+        .chars()
+        .zip([3, 7, 1].into_iter().cycle())
+        .map(|(c, weight)| c.to_digit(10).unwrap() * weight)
+        .sum()
+}
+
+/// Computes the IBAN check digits of the country code and the basic bank account number.
+fn iban_check_digits(country_code: &str, bban: &str) -> u32 {
+    98 - mod97(&format!("{bban}{country_code}00"))
+}
+
+/// Computes the remainder of the number modulo 97, where letters stand for 10 to 35.
+fn mod97(number: &str) -> u32 {
+    number.chars().fold(0, |remainder, c| {
+        let value = c.to_digit(36).unwrap();
+        let shift = if value < 10 { 10 } else { 100 };
+        (remainder * shift + value) % 97
+    })
+}
+
+/// Computes the French RIB key of the bank code, branch code and numeric account number.
+fn rib_key(digits: &str) -> u32 {
+    97 - mod97(&format!("{digits}00"))
+}
+
+/// Computes a Spanish check digit of ten digits.
+fn spanish_check_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .zip([1, 2, 4, 8, 5, 10, 9, 7, 3, 6])
+        .map(|(c, weight)| c.to_digit(10).unwrap() * weight)
+        .sum();
+    match 11 - sum % 11 {
+        11 => 0,
+        10 => 1,
+        digit => digit,
+    }
+}
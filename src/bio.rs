@@ -0,0 +1,242 @@
+use crate::{DegreeLevel, Education, Person};
+
+/// How the profile text returned by [`Person::get_bio`](crate::Person::get_bio) is written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BioStyle {
+    /// "Jane Doe is a Registered Nurse based in Chicago. She enjoys hiking and chess.",
+    /// in the third person with the `Person`'s pronouns.
+    #[default]
+    Professional,
+    /// "Hi, I'm Jane! I work as a Registered Nurse and live in Chicago.", in the first person with
+    /// the nickname.
+    Casual,
+    /// "Registered Nurse | Healthcare | Chicago", a single line for headers.
+    Tagline,
+}
+
+impl Person {
+    /// Returns a short profile text about the `Person` in the given style, with at most `max_len`
+    /// characters. It mentions their name, occupation, city, interests, personality and education if
+    /// they are known, and drops the sentences that don't fit. A first sentence longer than `max_len`
+    /// is cut at a word and ends with "…".
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Address, BioStyle, Country, Gender, Industry, Interest, Occupation, Person, Seniority};
+    /// let mut person = Person::from_parts("Jane", None, "Doe", Utc::now()).with_gender(Some(Gender::Female));
+    /// person.set_occupation(Some(Occupation {
+    ///     industry: Industry::Healthcare,
+    ///     seniority: Seniority::Mid,
+    ///     title: "Registered Nurse".to_string(),
+    /// }));
+    /// let mut address = Address::random(Country::UnitedStates);
+    /// address.city = "Chicago".to_string();
+    /// person.set_address(Some(address));
+    /// assert_eq!(
+    ///     person.get_bio(BioStyle::Professional, 280),
+    ///     "Jane Doe is a Registered Nurse based in Chicago."
+    /// );
+    /// assert_eq!(
+    ///     person.get_bio(BioStyle::Casual, 280),
+    ///     "Hi, I'm Jane! I work as a Registered Nurse and live in Chicago."
+    /// );
+    /// assert_eq!(
+    ///     person.get_bio(BioStyle::Tagline, 280),
+    ///     "Registered Nurse | Healthcare | Chicago"
+    /// );
+    /// assert_eq!(person.get_bio(BioStyle::Professional, 20), "Jane Doe is a…");
+    ///
+    /// person.set_interests(vec![Interest::Hiking, Interest::Chess]);
+    /// assert_eq!(
+    ///     person.get_bio(BioStyle::Professional, 280),
+    ///     "Jane Doe is a Registered Nurse based in Chicago. She enjoys hiking and chess."
+    /// );
+    ///
+    /// person.set_occupation(None);
+    /// person.set_address(None);
+    /// assert_eq!(
+    ///     person.get_bio(BioStyle::Casual, 280),
+    ///     "Hi, I'm Jane! I love hiking and chess."
+    /// );
+    /// ```
+    pub fn get_bio(&self, style: BioStyle, max_len: usize) -> String {
+        let mut sentences = match style {
+            BioStyle::Professional => self.professional_bio(),
+            BioStyle::Casual => self.casual_bio(),
+            BioStyle::Tagline => vec![self.tagline()],
+        };
+        let mut bio = String::new();
+        for sentence in &sentences {
+            let separator = if bio.is_empty() { "" } else { " " };
+            if bio.chars().count() + separator.len() + sentence.chars().count() > max_len {
+                break;
+            }
+            bio.push_str(separator);
+            bio.push_str(sentence);
+        }
+        if bio.is_empty() {
+            bio = sentences.swap_remove(0);
+            truncate_at_word(&mut bio, max_len);
+        }
+        bio
+    }
+
+    fn professional_bio(&self) -> Vec<String> {
+        let name = self.get_full_name();
+        let deceased = !self.is_alive();
+        let (is, lives, based) = if deceased {
+            ("was", "lived", "who lived")
+        } else {
+            ("is", "lives", "based")
+        };
+        let city = self.address.as_ref().map(|address| &address.city);
+        let mut sentences = vec![match (&self.occupation, city) {
+            (Some(occupation), Some(city)) => {
+                let title = &occupation.title;
+                format!("{name} {is} {} {title} {based} in {city}.", article(title))
+            }
+            (Some(occupation), None) => {
+                let title = &occupation.title;
+                format!("{name} {is} {} {title}.", article(title))
+            }
+            (None, Some(city)) => format!("{name} {lives} in {city}."),
+            (None, None) => format!("Meet {name}."),
+        }];
+        if !self.interests.is_empty() {
+            let pronouns = self.get_pronouns();
+            // "They" takes the plural form of verbs, like "they enjoy".
+            let enjoys = match (deceased, pronouns.subject) {
+                (true, _) => "enjoyed",
+                (false, "they") => "enjoy",
+                (false, _) => "enjoys",
+            };
+            sentences.push(format!(
+                "{} {enjoys} {}.",
+                capitalize(pronouns.subject),
+                list(&self.interests)
+            ));
+        }
+        if let Some(adjectives) = self.personality_adjectives() {
+            let verb = if deceased { "described" } else { "describe" };
+            sentences.push(format!(
+                "Friends {verb} {} as {adjectives}.",
+                self.get_pronouns().object
+            ));
+        }
+        if let Some(education) = self.highest_degree() {
+            let field = education
+                .field
+                .map(|field| format!("{field} at "))
+                .unwrap_or_default();
+            sentences.push(format!(
+                "{} studied {field}{}.",
+                capitalize(self.get_pronouns().subject),
+                education.school
+            ));
+        }
+        sentences
+    }
+
+    fn casual_bio(&self) -> Vec<String> {
+        let mut sentences = vec![format!("Hi, I'm {}!", self.get_nickname())];
+        let city = self.address.as_ref().map(|address| &address.city);
+        sentences.extend(match (&self.occupation, city) {
+            (Some(occupation), Some(city)) => Some(format!(
+                "I work as {} {} and live in {city}.",
+                article(&occupation.title),
+                occupation.title
+            )),
+            (Some(occupation), None) => Some(format!(
+                "I work as {} {}.",
+                article(&occupation.title),
+                occupation.title
+            )),
+            (None, Some(city)) => Some(format!("I live in {city}.")),
+            (None, None) => None,
+        });
+        if !self.interests.is_empty() {
+            sentences.push(format!("I love {}.", list(&self.interests)));
+        }
+        if let Some(adjectives) = self.personality_adjectives() {
+            sentences.push(format!("People say I'm {adjectives}."));
+        }
+        if let Some(education) = self.highest_degree() {
+            sentences.push(format!("Proud {} alum.", education.school));
+        }
+        sentences
+    }
+
+    fn tagline(&self) -> String {
+        let mut parts = vec![];
+        if let Some(occupation) = &self.occupation {
+            parts.push(occupation.title.clone());
+            parts.push(occupation.industry.to_string());
+        }
+        if let Some(address) = &self.address {
+            parts.push(address.city.clone());
+        }
+        if parts.is_empty() {
+            parts.push(self.get_full_name());
+        }
+        parts.join(" | ")
+    }
+
+    /// Returns up to two adjectives of the personality's pronounced traits, like "curious and warm".
+    fn personality_adjectives(&self) -> Option<String> {
+        let adjectives = self.personality?.adjectives();
+        (!adjectives.is_empty()).then(|| list(&adjectives[..adjectives.len().min(2)]))
+    }
+
+    /// Returns the most recent degree above high school.
+    fn highest_degree(&self) -> Option<&Education> {
+        self.education
+            .iter()
+            .filter(|education| education.level > DegreeLevel::HighSchool)
+            .max_by_key(|education| (education.level, education.graduation_year))
+    }
+}
+
+/// Returns "an" for words starting with a vowel and "a" for the rest.
+fn article(word: &str) -> &'static str {
+    match word.chars().next().map(|c| c.to_ascii_lowercase()) {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => "an",
+        _ => "a",
+    }
+}
+
+/// Joins the items like "hiking, chess and golf".
+fn list<T: std::fmt::Display>(items: &[T]) -> String {
+    let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+    match items.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+/// Capitalizes the first letter.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Cuts the text at the last space that leaves room for "…" within `max_len` characters.
+fn truncate_at_word(text: &mut String, max_len: usize) {
+    if text.chars().count() <= max_len {
+        return;
+    }
+    let limit = max_len.saturating_sub(1);
+    let cut: String = text.chars().take(limit).collect();
+    let cut = match cut.rfind(' ') {
+        Some(space) if space > 0 => cut[..space].to_string(),
+        _ => cut,
+    };
+    *text = if max_len == 0 {
+        String::new()
+    } else {
+        format!("{cut}…")
+    };
+}
@@ -0,0 +1,36 @@
+use rand::Rng;
+
+use crate::{address, Country};
+
+/// The city and country a `Person` was born in.
+/// ## Example
+/// ```rust
+/// use person::{Country, PlaceOfBirth};
+/// let place_of_birth = PlaceOfBirth::random(Country::Italy);
+/// assert_eq!(place_of_birth.country, Country::Italy);
+/// assert!(place_of_birth.to_string().ends_with(", Italy"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PlaceOfBirth {
+    pub city: String,
+    pub country: Country,
+}
+impl PlaceOfBirth {
+    /// Creates a random place of birth in one of the larger cities of the given country.
+    pub fn random(country: Country) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`PlaceOfBirth::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Self {
+        Self {
+            city: address::random_city(rng, country).to_string(),
+            country,
+        }
+    }
+}
+impl std::fmt::Display for PlaceOfBirth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.city, self.country)
+    }
+}
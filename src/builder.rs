@@ -0,0 +1,827 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use rand::{distributions::Distribution, Rng};
+
+use crate::{
+    generator, leap_day, random_date_between, Address, Country, Education, FinancialProfile,
+    Gender, GenerationConfig, HealthRecord, Interest, LanguageProficiency, Locale, MaritalStatus,
+    Occupation, Origin, Person, PersonError, Personality, PhoneNumber, Physique, PlaceOfBirth,
+    Pronouns, Religion, SkillProficiency, Suffix, Title, DEFAULT_MAX_ATTEMPTS,
+};
+
+type Constraint = Arc<dyn Fn(&Person) -> bool + Send + Sync>;
+
+#[derive(Debug, Clone)]
+enum DateOfBirth {
+    Fixed(DateTime<Utc>),
+    Between(DateTime<Utc>, DateTime<Utc>),
+}
+
+/// A fluent builder for `Person`s.
+/// Every field that isn't explicitly set is randomized when calling [`PersonBuilder::build`].
+/// ## Example
+/// ```rust
+/// use chrono::{Duration, Utc};
+/// use person::PersonBuilder;
+/// let now = Utc::now();
+/// let person = PersonBuilder::new()
+///     .first_name("Ana")
+///     .random_last_name()
+///     .dob_between(now - Duration::days(366 * 30), now - Duration::days(366 * 20))
+///     .middle_name_probability(0.2)
+///     .build();
+/// assert_eq!(person.get_first_name(), "Ana");
+/// ```
+#[derive(Clone)]
+pub struct PersonBuilder {
+    first_name: Option<String>,
+    middle_names: Option<Vec<String>>,
+    last_name: Option<String>,
+    pronouns: Option<Pronouns>,
+    title: Option<Option<Title>>,
+    suffix: Option<Option<Suffix>>,
+    former_names: Option<Vec<String>>,
+    date_of_birth: Option<DateOfBirth>,
+    date_of_death: Option<Option<DateTime<Utc>>>,
+    leap_day: bool,
+    physique: Option<Option<Physique>>,
+    phone_number: Option<Option<PhoneNumber>>,
+    address: Option<Option<Address>>,
+    place_of_birth: Option<Option<PlaceOfBirth>>,
+    financial_profile: Option<Option<FinancialProfile>>,
+    health_record: Option<Option<HealthRecord>>,
+    occupation: Option<Option<Occupation>>,
+    education: Option<Vec<Education>>,
+    interests: Option<Vec<Interest>>,
+    skills: Option<Vec<SkillProficiency>>,
+    personality: Option<Option<Personality>>,
+    marital_status: Option<Option<MaritalStatus>>,
+    languages: Option<Vec<LanguageProficiency>>,
+    religion: Option<Option<Religion>>,
+    config: GenerationConfig,
+    constraints: Vec<Constraint>,
+    max_attempts: usize,
+}
+impl Default for PersonBuilder {
+    fn default() -> Self {
+        Self {
+            first_name: None,
+            middle_names: None,
+            last_name: None,
+            pronouns: None,
+            title: None,
+            suffix: None,
+            former_names: None,
+            date_of_birth: None,
+            date_of_death: None,
+            leap_day: false,
+            physique: None,
+            phone_number: None,
+            address: None,
+            place_of_birth: None,
+            financial_profile: None,
+            health_record: None,
+            occupation: None,
+            education: None,
+            interests: None,
+            skills: None,
+            personality: None,
+            marital_status: None,
+            languages: None,
+            religion: None,
+            config: GenerationConfig::default(),
+            constraints: vec![],
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+}
+impl std::fmt::Debug for PersonBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersonBuilder")
+            .field("first_name", &self.first_name)
+            .field("middle_names", &self.middle_names)
+            .field("last_name", &self.last_name)
+            .field("pronouns", &self.pronouns)
+            .field("title", &self.title)
+            .field("suffix", &self.suffix)
+            .field("former_names", &self.former_names)
+            .field("date_of_birth", &self.date_of_birth)
+            .field("date_of_death", &self.date_of_death)
+            .field("leap_day", &self.leap_day)
+            .field("physique", &self.physique)
+            .field("phone_number", &self.phone_number)
+            .field("address", &self.address)
+            .field("place_of_birth", &self.place_of_birth)
+            .field("financial_profile", &self.financial_profile)
+            .field("health_record", &self.health_record)
+            .field("occupation", &self.occupation)
+            .field("education", &self.education)
+            .field("interests", &self.interests)
+            .field("skills", &self.skills)
+            .field("personality", &self.personality)
+            .field("marital_status", &self.marital_status)
+            .field("languages", &self.languages)
+            .field("religion", &self.religion)
+            .field("config", &self.config)
+            .field("constraints", &self.constraints.len())
+            .field("max_attempts", &self.max_attempts)
+            .finish()
+    }
+}
+impl PersonBuilder {
+    /// Creates a new `PersonBuilder` where every field is random.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self
+    }
+
+    pub fn random_first_name(mut self) -> Self {
+        self.first_name = None;
+        self
+    }
+
+    /// Sets a single middle name. Use [`PersonBuilder::no_middle_name`] to build a `Person` without one.
+    pub fn middle_name(mut self, middle_name: impl Into<String>) -> Self {
+        self.middle_names = Some(vec![middle_name.into()]);
+        self
+    }
+
+    pub fn middle_names<S: Into<String>>(
+        mut self,
+        middle_names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.middle_names = Some(middle_names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn no_middle_name(mut self) -> Self {
+        self.middle_names = Some(vec![]);
+        self
+    }
+
+    /// Randomizes the middle names, see [`PersonBuilder::middle_name_probability`].
+    pub fn random_middle_name(mut self) -> Self {
+        self.middle_names = None;
+        self
+    }
+
+    /// Sets the chance (between 0.0 and 1.0) of a random middle name being generated. Defaults to 0.5.
+    pub fn middle_name_probability(mut self, probability: f64) -> Self {
+        self.config.middle_name_probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the config used to randomize fields. This overrides [`PersonBuilder::middle_name_probability`].
+    pub fn config(mut self, config: GenerationConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Builds a `Person` with only a first name and no last name. Random middle names, suffixes
+    /// and former names are left out, but ones that are set explicitly are kept.
+    /// ## Example
+    /// ```rust
+    /// use person::PersonBuilder;
+    /// let person = PersonBuilder::new()
+    ///     .first_name("Cher")
+    ///     .mononym()
+    ///     .middle_name_probability(1.0)
+    ///     .build();
+    /// assert!(person.is_mononym());
+    /// assert_eq!(person.get_full_name(), "Cher");
+    /// assert!(person.get_former_names().is_empty());
+    /// ```
+    pub fn mononym(mut self) -> Self {
+        self.last_name = Some(String::new());
+        self
+    }
+
+    pub fn random_last_name(mut self) -> Self {
+        self.last_name = None;
+        self
+    }
+
+    /// Sets the gender, random first and middle names are then picked from the matching list.
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.config.genders = vec![gender];
+        self
+    }
+
+    /// Sets the cultural origin, random first, middle and last names are then picked from its lists.
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.config.origins = vec![origin];
+        self
+    }
+
+    /// Sets the primary nationality, random names are then picked from the lists of its
+    /// [origin](Country::origin) unless an origin is set.
+    pub fn nationality(mut self, nationality: Country) -> Self {
+        self.config.nationalities = vec![nationality];
+        self
+    }
+
+    /// Sets the locale all country-specific attributes agree with, see [`GenerationConfig::locale`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.config.locale = Some(locale);
+        self
+    }
+
+    /// Only picks random first and middle names that don't imply a gender,
+    /// see [`GenerationConfig::gender_neutral_names`].
+    pub fn gender_neutral_names(mut self) -> Self {
+        self.config.gender_neutral_names = true;
+        self
+    }
+
+    /// Sets the pronouns instead of deriving them from the gender.
+    pub fn pronouns(mut self, pronouns: Pronouns) -> Self {
+        self.pronouns = Some(pronouns);
+        self
+    }
+
+    pub fn title(mut self, title: Title) -> Self {
+        self.title = Some(Some(title));
+        self
+    }
+
+    pub fn no_title(mut self) -> Self {
+        self.title = Some(None);
+        self
+    }
+
+    /// Randomizes the title, see [`GenerationConfig::title_probability`].
+    pub fn random_title(mut self) -> Self {
+        self.title = None;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: Suffix) -> Self {
+        self.suffix = Some(Some(suffix));
+        self
+    }
+
+    pub fn no_suffix(mut self) -> Self {
+        self.suffix = Some(None);
+        self
+    }
+
+    /// Randomizes the suffix, see [`GenerationConfig::suffix_probability`].
+    pub fn random_suffix(mut self) -> Self {
+        self.suffix = None;
+        self
+    }
+
+    /// Sets a single former last name, like a maiden name.
+    pub fn former_name(mut self, former_name: impl Into<String>) -> Self {
+        self.former_names = Some(vec![former_name.into()]);
+        self
+    }
+
+    /// Sets the former last names, oldest first.
+    pub fn former_names<S: Into<String>>(
+        mut self,
+        former_names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.former_names = Some(former_names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn no_former_names(mut self) -> Self {
+        self.former_names = Some(vec![]);
+        self
+    }
+
+    /// Randomizes the former names, see [`GenerationConfig::former_name_probability`].
+    pub fn random_former_names(mut self) -> Self {
+        self.former_names = None;
+        self
+    }
+
+    pub fn date_of_birth(mut self, date_of_birth: DateTime<Utc>) -> Self {
+        self.date_of_birth = Some(DateOfBirth::Fixed(date_of_birth));
+        self
+    }
+
+    /// Picks a random date of birth between `min` and `max`.
+    pub fn dob_between(mut self, min: DateTime<Utc>, max: DateTime<Utc>) -> Self {
+        self.date_of_birth = Some(DateOfBirth::Between(min, max));
+        self
+    }
+
+    /// Randomizes the date of birth, so the `Person` will be between 0 and 100 years old.
+    /// This also resets [`PersonBuilder::leap_day_date_of_birth`].
+    pub fn random_date_of_birth(mut self) -> Self {
+        self.date_of_birth = None;
+        self.leap_day = false;
+        self
+    }
+
+    /// Picks a random date of birth on February 29, within the range set by [`PersonBuilder::dob_between`]
+    /// or between 0 and 100 years ago. A fixed date of birth takes precedence.
+    pub fn leap_day_date_of_birth(mut self) -> Self {
+        self.leap_day = true;
+        self
+    }
+
+    /// Sets the date of death. Building fails with [`PersonError::InvalidDateOfDeath`] if it is
+    /// before the date of birth.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{PersonBuilder, PersonError};
+    /// let date_of_birth = Utc.with_ymd_and_hms(1985, 6, 1, 0, 0, 0).unwrap();
+    /// let date_of_death = Utc.with_ymd_and_hms(1980, 6, 1, 0, 0, 0).unwrap();
+    /// let result = PersonBuilder::new()
+    ///     .date_of_birth(date_of_birth)
+    ///     .date_of_death(date_of_death)
+    ///     .try_build();
+    /// assert_eq!(
+    ///     result,
+    ///     Err(PersonError::InvalidDateOfDeath {
+    ///         date_of_birth,
+    ///         date_of_death
+    ///     })
+    /// );
+    /// ```
+    pub fn date_of_death(mut self, date_of_death: DateTime<Utc>) -> Self {
+        self.date_of_death = Some(Some(date_of_death));
+        self
+    }
+
+    pub fn alive(mut self) -> Self {
+        self.date_of_death = Some(None);
+        self
+    }
+
+    /// Randomizes the date of death, see [`GenerationConfig::deceased_probability`].
+    pub fn random_date_of_death(mut self) -> Self {
+        self.date_of_death = None;
+        self
+    }
+
+    pub fn physique(mut self, physique: Physique) -> Self {
+        self.physique = Some(Some(physique));
+        self
+    }
+
+    pub fn no_physique(mut self) -> Self {
+        self.physique = Some(None);
+        self
+    }
+
+    /// Randomizes the physical attributes, see [`GenerationConfig::physique_probability`].
+    pub fn random_physique(mut self) -> Self {
+        self.physique = None;
+        self
+    }
+
+    pub fn phone_number(mut self, phone_number: PhoneNumber) -> Self {
+        self.phone_number = Some(Some(phone_number));
+        self
+    }
+
+    pub fn no_phone_number(mut self) -> Self {
+        self.phone_number = Some(None);
+        self
+    }
+
+    /// Randomizes the phone number, see [`GenerationConfig::phone_number_probability`].
+    pub fn random_phone_number(mut self) -> Self {
+        self.phone_number = None;
+        self
+    }
+
+    /// Sets the postal address.
+    /// ## Example
+    /// ```rust
+    /// use person::{Address, Country, PersonBuilder};
+    /// let address = Address::random(Country::Germany);
+    /// let person = PersonBuilder::new().address(address.clone()).build();
+    /// assert_eq!(person.get_address(), Some(address));
+    /// ```
+    pub fn address(mut self, address: Address) -> Self {
+        self.address = Some(Some(address));
+        self
+    }
+
+    pub fn no_address(mut self) -> Self {
+        self.address = Some(None);
+        self
+    }
+
+    /// Randomizes the postal address, see [`GenerationConfig::address_probability`].
+    pub fn random_address(mut self) -> Self {
+        self.address = None;
+        self
+    }
+
+    pub fn place_of_birth(mut self, place_of_birth: PlaceOfBirth) -> Self {
+        self.place_of_birth = Some(Some(place_of_birth));
+        self
+    }
+
+    pub fn no_place_of_birth(mut self) -> Self {
+        self.place_of_birth = Some(None);
+        self
+    }
+
+    /// Randomizes the place of birth, see [`GenerationConfig::place_of_birth_probability`].
+    pub fn random_place_of_birth(mut self) -> Self {
+        self.place_of_birth = None;
+        self
+    }
+
+    pub fn financial_profile(mut self, financial_profile: FinancialProfile) -> Self {
+        self.financial_profile = Some(Some(financial_profile));
+        self
+    }
+
+    pub fn no_financial_profile(mut self) -> Self {
+        self.financial_profile = Some(None);
+        self
+    }
+
+    /// Randomizes the financial profile, see [`GenerationConfig::financial_profile_probability`].
+    /// Its bank accounts are held by the name the builder sets.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{Duration, Utc};
+    /// use person::{GenerationConfig, Person};
+    /// let now = Utc::now();
+    /// let config = GenerationConfig {
+    ///     financial_profile_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::builder()
+    ///     .config(config)
+    ///     .first_name("Maria")
+    ///     .no_middle_name()
+    ///     .last_name("Rossi")
+    ///     .no_suffix()
+    ///     .dob_between(now - Duration::days(366 * 60), now - Duration::days(366 * 20))
+    ///     .random_financial_profile()
+    ///     .build();
+    /// let profile = person.get_financial_profile().unwrap();
+    /// let holder = match (profile.bank_account, profile.us_bank_account) {
+    ///     (Some(account), _) => account.holder,
+    ///     (_, Some(account)) => account.holder,
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(holder, "Maria Rossi");
+    /// ```
+    pub fn random_financial_profile(mut self) -> Self {
+        self.financial_profile = None;
+        self
+    }
+
+    pub fn health_record(mut self, health_record: HealthRecord) -> Self {
+        self.health_record = Some(Some(health_record));
+        self
+    }
+
+    pub fn no_health_record(mut self) -> Self {
+        self.health_record = Some(None);
+        self
+    }
+
+    /// Randomizes the health record, see [`GenerationConfig::health_record_probability`].
+    pub fn random_health_record(mut self) -> Self {
+        self.health_record = None;
+        self
+    }
+
+    pub fn occupation(mut self, occupation: Occupation) -> Self {
+        self.occupation = Some(Some(occupation));
+        self
+    }
+
+    pub fn no_occupation(mut self) -> Self {
+        self.occupation = Some(None);
+        self
+    }
+
+    /// Randomizes the occupation, see [`GenerationConfig::occupation_probability`].
+    /// Like every derived attribute, it fits the dates, names and address set on the builder.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     occupation_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::builder()
+    ///     .config(config)
+    ///     .date_of_birth(Utc.with_ymd_and_hms(1985, 6, 1, 0, 0, 0).unwrap())
+    ///     .date_of_death(Utc.with_ymd_and_hms(1990, 6, 1, 0, 0, 0).unwrap())
+    ///     .random_occupation()
+    ///     .build();
+    /// assert_eq!(person.get_occupation(), None);
+    /// ```
+    pub fn random_occupation(mut self) -> Self {
+        self.occupation = None;
+        self
+    }
+
+    /// Sets the completed education, lowest level first.
+    pub fn education(mut self, education: impl IntoIterator<Item = Education>) -> Self {
+        self.education = Some(education.into_iter().collect());
+        self
+    }
+
+    pub fn no_education(mut self) -> Self {
+        self.education = Some(vec![]);
+        self
+    }
+
+    /// Randomizes the education, see [`GenerationConfig::education_probability`].
+    pub fn random_education(mut self) -> Self {
+        self.education = None;
+        self
+    }
+
+    pub fn interests(mut self, interests: impl IntoIterator<Item = Interest>) -> Self {
+        self.interests = Some(interests.into_iter().collect());
+        self
+    }
+
+    pub fn no_interests(mut self) -> Self {
+        self.interests = Some(vec![]);
+        self
+    }
+
+    /// Randomizes the interests, see [`GenerationConfig::interests_probability`].
+    pub fn random_interests(mut self) -> Self {
+        self.interests = None;
+        self
+    }
+
+    pub fn skills(mut self, skills: impl IntoIterator<Item = SkillProficiency>) -> Self {
+        self.skills = Some(skills.into_iter().collect());
+        self
+    }
+
+    pub fn no_skills(mut self) -> Self {
+        self.skills = Some(vec![]);
+        self
+    }
+
+    /// Randomizes the skills, see [`GenerationConfig::interests_probability`].
+    pub fn random_skills(mut self) -> Self {
+        self.skills = None;
+        self
+    }
+
+    pub fn personality(mut self, personality: Personality) -> Self {
+        self.personality = Some(Some(personality));
+        self
+    }
+
+    pub fn no_personality(mut self) -> Self {
+        self.personality = Some(None);
+        self
+    }
+
+    /// Randomizes the personality, see [`GenerationConfig::personality_probability`].
+    pub fn random_personality(mut self) -> Self {
+        self.personality = None;
+        self
+    }
+
+    pub fn marital_status(mut self, marital_status: MaritalStatus) -> Self {
+        self.marital_status = Some(Some(marital_status));
+        self
+    }
+
+    pub fn no_marital_status(mut self) -> Self {
+        self.marital_status = Some(None);
+        self
+    }
+
+    /// Randomizes the marital status, see [`GenerationConfig::marital_status_probability`].
+    pub fn random_marital_status(mut self) -> Self {
+        self.marital_status = None;
+        self
+    }
+
+    pub fn languages(mut self, languages: Vec<LanguageProficiency>) -> Self {
+        self.languages = Some(languages);
+        self
+    }
+
+    pub fn no_languages(mut self) -> Self {
+        self.languages = Some(vec![]);
+        self
+    }
+
+    /// Randomizes the languages, see [`GenerationConfig::languages_probability`].
+    pub fn random_languages(mut self) -> Self {
+        self.languages = None;
+        self
+    }
+
+    pub fn religion(mut self, religion: Religion) -> Self {
+        self.religion = Some(Some(religion));
+        self
+    }
+
+    pub fn no_religion(mut self) -> Self {
+        self.religion = Some(None);
+        self
+    }
+
+    /// Randomizes the religion, see [`GenerationConfig::religion_probability`].
+    pub fn random_religion(mut self) -> Self {
+        self.religion = None;
+        self
+    }
+
+    /// Adds a constraint the built `Person` has to satisfy. Random fields are re-rolled until all
+    /// constraints are satisfied, up to [`PersonBuilder::max_attempts`] times.
+    /// ## Example
+    /// ```rust
+    /// use person::PersonBuilder;
+    /// let person = PersonBuilder::new()
+    ///     .last_name("Doe")
+    ///     .constraint(|p| p.get_age() >= 18)
+    ///     .constraint(|p| p.get_first_name().len() <= 5)
+    ///     .build();
+    /// assert!(person.get_age() >= 18);
+    /// ```
+    pub fn constraint<F: Fn(&Person) -> bool + Send + Sync + 'static>(
+        mut self,
+        predicate: F,
+    ) -> Self {
+        self.constraints.push(Arc::new(predicate));
+        self
+    }
+
+    /// Sets how many `Person`s are generated before giving up on satisfying the constraints.
+    /// Defaults to [`DEFAULT_MAX_ATTEMPTS`].
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Builds the `Person`, randomizing every field that wasn't set.
+    /// ## Panics
+    /// Panics if the date of birth range is invalid or the constraints couldn't be satisfied,
+    /// see [`PersonBuilder::try_build`].
+    pub fn build(&self) -> Person {
+        self.build_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`PersonBuilder::build`], but uses the given random number generator.
+    pub fn build_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Person {
+        self.try_build_custom_rng(rng).unwrap()
+    }
+
+    /// Same as [`PersonBuilder::build`], but returns an error instead of panicking.
+    pub fn try_build(&self) -> Result<Person, PersonError> {
+        self.try_build_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`PersonBuilder::try_build`], but uses the given random number generator.
+    pub fn try_build_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<Person, PersonError> {
+        for _ in 0..self.max_attempts {
+            let person = self.build_once(rng)?;
+            if self
+                .constraints
+                .iter()
+                .all(|constraint| constraint(&person))
+            {
+                return Ok(person);
+            }
+        }
+        Err(PersonError::ConstraintsNotSatisfied {
+            attempts: self.max_attempts,
+        })
+    }
+
+    fn build_once<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Person, PersonError> {
+        let now = Utc::now();
+        let (min, max) = match self.date_of_birth {
+            Some(DateOfBirth::Fixed(dob)) => (dob, dob),
+            Some(DateOfBirth::Between(min, max)) => (min, max),
+            None => (now - Duration::days(366 * 100), now),
+        };
+        let date_of_birth = match self.date_of_birth {
+            Some(DateOfBirth::Fixed(dob)) => dob,
+            _ if self.leap_day => leap_day::random_leap_day_between(rng, min, max)?,
+            _ => random_date_between(rng, min, max)?,
+        };
+        if let Some(Some(date_of_death)) = self.date_of_death {
+            if date_of_death < date_of_birth {
+                return Err(PersonError::InvalidDateOfDeath {
+                    date_of_birth,
+                    date_of_death,
+                });
+            }
+        }
+        let mut person = generator::generate_identity(rng, &self.config, date_of_birth, now)?;
+        if let Some(first_name) = &self.first_name {
+            person.first_name = first_name.clone();
+        }
+        if let Some(middle_names) = &self.middle_names {
+            person.middle_names = middle_names.clone();
+        }
+        if let Some(last_name) = &self.last_name {
+            person.last_name = last_name.clone();
+        }
+        // A mononym is the whole name, so generated name parts around it don't fit.
+        if person.last_name.is_empty() {
+            if self.middle_names.is_none() {
+                person.middle_names.clear();
+            }
+            person.suffix = None;
+            person.former_names.clear();
+        }
+        if let Some(pronouns) = self.pronouns {
+            person.pronouns = Some(pronouns);
+        }
+        if let Some(title) = self.title {
+            person.title = title;
+        }
+        if let Some(suffix) = self.suffix {
+            person.suffix = suffix;
+        }
+        if let Some(former_names) = &self.former_names {
+            person.former_names = former_names.clone();
+        }
+        if let Some(date_of_death) = self.date_of_death {
+            person.date_of_death = date_of_death;
+        }
+        if let Some(phone_number) = &self.phone_number {
+            person.phone_number = phone_number.clone();
+        }
+        if let Some(address) = &self.address {
+            person.address = address.clone();
+        }
+        // The remaining attributes are derived from the fields set above, so they agree with them.
+        generator::generate_details(rng, &self.config, &mut person, now);
+        if let Some(physique) = self.physique {
+            person.physique = physique;
+        }
+        if let Some(place_of_birth) = &self.place_of_birth {
+            person.place_of_birth = place_of_birth.clone();
+        }
+        if let Some(financial_profile) = &self.financial_profile {
+            person.financial_profile = financial_profile.clone();
+        }
+        if let Some(health_record) = &self.health_record {
+            person.health_record = health_record.clone();
+        }
+        if let Some(occupation) = &self.occupation {
+            person.occupation = occupation.clone();
+        }
+        if let Some(education) = &self.education {
+            person.education = education.clone();
+        }
+        if let Some(interests) = &self.interests {
+            person.interests = interests.clone();
+        }
+        if let Some(skills) = &self.skills {
+            person.skills = skills.clone();
+        }
+        if let Some(personality) = self.personality {
+            person.personality = personality;
+        }
+        if let Some(marital_status) = self.marital_status {
+            person.marital_status = marital_status;
+        }
+        if let Some(languages) = &self.languages {
+            person.languages = languages.clone();
+        }
+        if let Some(religion) = self.religion {
+            person.religion = religion;
+        }
+        Ok(person)
+    }
+}
+/// Samples `Person`s built from this configuration, panicking if it is invalid like [`PersonBuilder::build`].
+/// ## Example
+/// ```rust
+/// use person::PersonBuilder;
+/// use rand::Rng;
+/// let builder = PersonBuilder::new().last_name("Doe");
+/// let persons: Vec<_> = rand::thread_rng().sample_iter(&builder).take(10).collect();
+/// assert!(persons.iter().all(|p| p.get_last_name() == "Doe"));
+/// ```
+impl Distribution<Person> for PersonBuilder {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Person {
+        self.build_custom_rng(rng)
+    }
+}
@@ -0,0 +1,59 @@
+use rand::Rng;
+
+use crate::{generator, Address, Country, Person};
+
+/// The billing and shipping address of an order, for checkout flow test data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CheckoutAddresses {
+    pub billing: Address,
+    pub shipping: Address,
+}
+impl CheckoutAddresses {
+    /// Returns whether the order is shipped to the billing address.
+    pub fn is_same(&self) -> bool {
+        self.billing == self.shipping
+    }
+}
+
+impl Person {
+    /// Generates a billing and a shipping address, which are the same with the given chance
+    /// (between 0.0 and 1.0). The billing address is the `Person`'s address, or a random one in the
+    /// country of their nationality or else the United States. A different shipping address is
+    /// in the same country.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let person = Person::random();
+    /// assert!(person.get_checkout_addresses(1.0).is_same());
+    /// assert!(!person.get_checkout_addresses(0.0).is_same());
+    /// ```
+    pub fn get_checkout_addresses(&self, same_probability: f64) -> CheckoutAddresses {
+        self.get_checkout_addresses_custom_rng(&mut rand::thread_rng(), same_probability)
+    }
+
+    /// Same as [`Person::get_checkout_addresses`], but uses the given random number generator.
+    pub fn get_checkout_addresses_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        same_probability: f64,
+    ) -> CheckoutAddresses {
+        let billing = match &self.address {
+            Some(address) => address.clone(),
+            None => {
+                let country = self.get_nationality().unwrap_or(Country::UnitedStates);
+                Address::random_custom_rng(rng, country)
+            }
+        };
+        let shipping = if generator::chance(rng, same_probability) {
+            billing.clone()
+        } else {
+            loop {
+                let shipping = Address::random_custom_rng(rng, billing.country);
+                if shipping != billing {
+                    break shipping;
+                }
+            }
+        };
+        CheckoutAddresses { billing, shipping }
+    }
+}
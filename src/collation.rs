@@ -0,0 +1,14 @@
+use crate::Person;
+
+/// How [`Person::get_index_name`] builds a key for sorting `Person`s like an address book does.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Collation {
+    /// Sorts by the full last name, so "van der Berg" is sorted under "V".
+    #[default]
+    Literal,
+    /// Sorts by the last name without surname particles, so "van der Berg" is sorted under "B",
+    /// as is common in Dutch and German indexes.
+    IgnoreParticles,
+    /// Builds the key with the given function, e.g. to apply a locale's collation rules.
+    Custom(fn(&Person) -> String),
+}
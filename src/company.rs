@@ -0,0 +1,180 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{list, Country, Industry, Person};
+
+/// The first halves of made-up brand names, like "Summit" in "Summit Software".
+const BRAND_WORDS: [&str; 16] = [
+    "Apex",
+    "Blue",
+    "Bright",
+    "Cedar",
+    "Crest",
+    "Evergreen",
+    "Harbor",
+    "Keystone",
+    "Meridian",
+    "North",
+    "Oak",
+    "Pioneer",
+    "Silver",
+    "Summit",
+    "True",
+    "Vista",
+];
+/// The names of public employers, which have no legal suffix.
+const PUBLIC_EMPLOYERS: [&str; 6] = [
+    "Department of Revenue",
+    "Department of Transportation",
+    "Public Health Agency",
+    "Municipal Services",
+    "Office of Statistics",
+    "Environmental Protection Agency",
+];
+
+/// A company, as an employer or on invoices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Company {
+    /// The name without the legal suffix, like "Summit Software".
+    pub name: String,
+    /// The suffix of the legal form, like "LLC" or "GmbH", which public employers don't have.
+    pub legal_suffix: Option<String>,
+    pub industry: Industry,
+    /// The country the company is registered in.
+    pub country: Country,
+}
+impl Company {
+    /// Creates a random company of a random industry in the country.
+    pub fn random(country: Country) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`Company::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Self {
+        let industry = *Industry::ALL.choose(rng).unwrap();
+        Self::random_in_custom_rng(rng, industry, country)
+    }
+
+    /// Creates a random company of the industry in the country, named after a brand word or the
+    /// surnames of its founders, with a legal suffix that is common in the country.
+    /// Government employers are public agencies without a legal suffix.
+    /// ## Example
+    /// ```rust
+    /// use person::{Company, Country, Industry};
+    /// let company = Company::random_in(Industry::Technology, Country::Germany);
+    /// assert!(["GmbH", "AG", "UG"].contains(&company.legal_suffix.as_deref().unwrap()));
+    /// assert!(company.legal_name().ends_with(company.legal_suffix.as_deref().unwrap()));
+    ///
+    /// let agency = Company::random_in(Industry::Government, Country::Germany);
+    /// assert_eq!(agency.legal_suffix, None);
+    /// assert_eq!(agency.legal_name(), agency.name);
+    /// ```
+    pub fn random_in(industry: Industry, country: Country) -> Self {
+        Self::random_in_custom_rng(&mut rand::thread_rng(), industry, country)
+    }
+
+    /// Same as [`Company::random_in`], but uses the given random number generator.
+    pub fn random_in_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        industry: Industry,
+        country: Country,
+    ) -> Self {
+        if industry == Industry::Government {
+            return Self {
+                name: PUBLIC_EMPLOYERS.choose(rng).unwrap().to_string(),
+                legal_suffix: None,
+                industry,
+                country,
+            };
+        }
+        let surnames = country
+            .origin()
+            .map_or(&list::SURNAMES[..], |origin| origin.surnames());
+        let word = industry_words(industry).choose(rng).unwrap();
+        let name = match rng.gen_range(0..3) {
+            0 => format!("{} {word}", surnames.choose(rng).unwrap()),
+            1 => format!(
+                "{} & {}",
+                surnames.choose(rng).unwrap(),
+                surnames.choose(rng).unwrap()
+            ),
+            _ => format!("{} {word}", BRAND_WORDS.choose(rng).unwrap()),
+        };
+        Self {
+            name,
+            legal_suffix: Some(legal_suffixes(country).choose(rng).unwrap().to_string()),
+            industry,
+            country,
+        }
+    }
+
+    /// Returns the name with the legal suffix, like "Summit Software LLC".
+    pub fn legal_name(&self) -> String {
+        match &self.legal_suffix {
+            Some(suffix) => format!("{} {suffix}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+impl std::fmt::Display for Company {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.legal_name())
+    }
+}
+
+impl Person {
+    /// Generates an employer in the industry of the `Person`'s occupation, registered in the country
+    /// they are paid in. Returns `None` if the `Person` has no occupation.
+    /// ## Example
+    /// ```rust
+    /// use person::{Occupation, Person};
+    /// let mut person = Person::random_adult();
+    /// assert_eq!(person.get_random_employer(), None);
+    /// person.set_occupation(Occupation::random(40));
+    /// let employer = person.get_random_employer().unwrap();
+    /// assert_eq!(employer.industry, person.get_occupation().unwrap().industry);
+    /// ```
+    pub fn get_random_employer(&self) -> Option<Company> {
+        self.get_random_employer_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_employer`], but uses the given random number generator.
+    pub fn get_random_employer_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Company> {
+        let industry = self.occupation.as_ref()?.industry;
+        Some(Company::random_in_custom_rng(
+            rng,
+            industry,
+            self.financial_country(),
+        ))
+    }
+}
+
+/// Returns the words that end company names of the industry, like "Logistics".
+fn industry_words(industry: Industry) -> &'static [&'static str] {
+    match industry {
+        Industry::Technology => &["Software", "Systems", "Labs", "Technologies", "Digital"],
+        Industry::Healthcare => &["Health", "Medical", "Care", "Clinics"],
+        Industry::Finance => &["Capital", "Financial", "Partners", "Advisors"],
+        Industry::Education => &["Academy", "Learning", "Institute", "Tutoring"],
+        Industry::Retail => &["Market", "Goods", "Outfitters", "Stores"],
+        Industry::Hospitality => &["Kitchen", "Hospitality", "Hotels", "Bistro"],
+        Industry::Manufacturing => &["Industries", "Manufacturing", "Works", "Machining"],
+        Industry::Construction => &["Construction", "Builders", "Contracting", "Homes"],
+        Industry::Government => &["Agency"],
+    }
+}
+
+/// Returns the suffixes of the common legal forms of companies in the country.
+fn legal_suffixes(country: Country) -> &'static [&'static str] {
+    match country {
+        Country::UnitedStates => &["Inc.", "LLC", "Corp."],
+        Country::Canada => &["Inc.", "Ltd."],
+        Country::UnitedKingdom => &["Ltd", "PLC", "LLP"],
+        Country::Germany => &["GmbH", "AG", "UG"],
+        Country::France => &["SARL", "SAS", "SA"],
+        Country::Spain => &["S.L.", "S.A."],
+        Country::Italy => &["S.r.l.", "S.p.A."],
+        Country::Netherlands => &["B.V.", "N.V."],
+        Country::Australia => &["Pty Ltd"],
+        Country::Japan => &["K.K.", "G.K."],
+    }
+}
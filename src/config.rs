@@ -0,0 +1,339 @@
+use rand::Rng;
+
+use crate::{
+    generator, Country, DomainPool, EmailPattern, Gender, LeetMap, Locale, NameList, Origin,
+    Pronouns,
+};
+
+/// The maximum number of middle names a random `Person` can have.
+pub const MAX_MIDDLE_NAMES: usize = 3;
+
+/// The number appended to a generated username.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UsernameNumber {
+    /// No number is appended.
+    None,
+    /// A random number between 0 and 9999.
+    Random,
+    /// The `Person`'s age.
+    Age,
+    /// The `Person`'s year of birth.
+    BirthYear,
+}
+
+/// The separator placed between the names of a generated username.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UsernameSeparator {
+    /// The names are joined without a separator.
+    None,
+    Hyphen,
+    Underscore,
+    Dot,
+    /// The initial of the `Person`'s middle name, or a dot if the `Person` has no middle name.
+    MiddleInitial,
+}
+
+/// Tunable probabilities and choices used when generating `Person`s and their usernames.
+/// ## Example
+/// ```rust
+/// use person::{GenerationConfig, Person, UsernameNumber, UsernameSeparator};
+/// let config = GenerationConfig {
+///     middle_name_probability: 0.0,
+///     leet_probability: 0.0,
+///     number_styles: vec![UsernameNumber::None],
+///     separators: vec![UsernameSeparator::Dot],
+///     ..GenerationConfig::default()
+/// };
+/// let person = Person::random_with_config(&config);
+/// assert!(person.get_middle_name().is_none());
+/// assert!(person.get_random_username_with_config(&config).contains('.'));
+/// ```
+/// Probabilities out of range are clamped, and NaN counts as 0.0:
+/// ```rust
+/// use person::{GenerationConfig, Person};
+/// let config = GenerationConfig {
+///     middle_name_probability: f64::NAN,
+///     leet_probability: f64::NAN,
+///     address_probability: 2.0,
+///     ..GenerationConfig::default()
+/// };
+/// let person = Person::random_with_config(&config);
+/// assert!(person.get_middle_name().is_none());
+/// assert!(person.get_address().is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationConfig {
+    /// The chance (between 0.0 and 1.0) of a `Person` having a middle name. Defaults to 0.5.
+    pub middle_name_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` with a middle name getting each additional middle name,
+    /// up to [`MAX_MIDDLE_NAMES`] in total. Defaults to 0.1.
+    pub additional_middle_name_probability: f64,
+    /// The chance (between 0.0 and 1.0) of each character of a username being leetified,
+    /// except for the first one, 0.0 turns leetification off. Defaults to 0.25.
+    pub leet_probability: f64,
+    /// The substitutions leetified characters use. Defaults to the classic ones, see [`LeetMap`].
+    pub leet_map: LeetMap,
+    /// The number styles a username picks from. Defaults to all of them,
+    /// an empty list means no number is appended.
+    pub number_styles: Vec<UsernameNumber>,
+    /// The separators a username picks from. Defaults to all of them,
+    /// an empty list means the names are joined without a separator.
+    pub separators: Vec<UsernameSeparator>,
+    /// The list first and middle names are picked from. Defaults to the built-in list.
+    pub first_names: NameList,
+    /// The list last names are picked from. Defaults to the built-in list.
+    pub last_names: NameList,
+    /// The chance (between 0.0 and 1.0) of a `Person` only having a first name and no last name.
+    /// Mononyms never get middle names. Defaults to 0.0.
+    pub mononym_probability: f64,
+    /// The genders a `Person` picks from. A `Person` with a gender gets their first and middle names
+    /// from the matching gendered list instead of [`GenerationConfig::first_names`].
+    /// Defaults to an empty list, meaning no gender is assigned.
+    pub genders: Vec<Gender>,
+    /// The list female first and middle names are picked from. Defaults to the built-in list.
+    pub female_first_names: NameList,
+    /// The list male first and middle names are picked from. Defaults to the built-in list.
+    pub male_first_names: NameList,
+    /// The list gender-neutral first and middle names are picked from, used for non-binary `Person`s.
+    /// Defaults to the built-in list.
+    pub unisex_first_names: NameList,
+    /// The pronouns a `Person` picks from, independently of their gender.
+    /// Defaults to an empty list, meaning the pronouns are derived from the gender.
+    pub pronouns: Vec<Pronouns>,
+    /// The chance (between 0.0 and 1.0) of a `Person` having an honorific title matching their gender.
+    /// Defaults to 0.0.
+    pub title_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a generational suffix like "Jr.".
+    /// Mononyms never get a suffix. Defaults to 0.0.
+    pub suffix_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a username using a nickname instead of the first name,
+    /// e.g. "bill" for "William". Defaults to 0.0.
+    pub username_nickname_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a double-barrelled last name
+    /// made of two surnames, like "Smith-Jones". Defaults to 0.0.
+    pub hyphenated_last_name_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person`'s last name being prefixed with a particle
+    /// from [`GenerationConfig::surname_particles`], like "van der Berg". Defaults to 0.0.
+    pub surname_particle_probability: f64,
+    /// The list surname particles are picked from. Defaults to the built-in list.
+    pub surname_particles: NameList,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a former last name, like a maiden name.
+    /// Mononyms never get a former name. Defaults to 0.0.
+    pub former_name_probability: f64,
+    /// Whether first and middle names are always picked from [`GenerationConfig::unisex_first_names`],
+    /// so names don't imply a gender. Defaults to `false`.
+    pub gender_neutral_names: bool,
+    /// Whether first names are picked from the most popular names of the `Person`'s birth decade,
+    /// e.g. "Gertrude" in the 1930s or "Liam" in the 2010s, instead of the first name lists.
+    /// Middle names, non-binary `Person`s, `Person`s with an origin and [`GenerationConfig::gender_neutral_names`]
+    /// are not affected.
+    /// Defaults to `false`.
+    pub birth_decade_names: bool,
+    /// Whether accented letters in usernames are replaced by their closest ASCII equivalent,
+    /// e.g. "jose" for "José". Defaults to `true`.
+    pub ascii_usernames: bool,
+    /// The cultural origins a `Person` picks from. A `Person` with an origin gets their first, middle
+    /// and last names from the origin's lists, so they fit together, instead of the configured name lists.
+    /// [`GenerationConfig::gender_neutral_names`] still takes precedence for first and middle names.
+    /// Defaults to an empty list, meaning names are picked independently.
+    pub origins: Vec<Origin>,
+    /// The chance (between 0.0 and 1.0) of a `Person` being deceased, with a random date of death
+    /// between their date of birth and now. Defaults to 0.0.
+    pub deceased_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` getting physical attributes that fit their age
+    /// and gender, see [`Physique`](crate::Physique). Defaults to 0.0.
+    pub physique_probability: f64,
+    /// The patterns the part of an email address before the `@` picks from. Defaults to all of them.
+    pub email_patterns: Vec<EmailPattern>,
+    /// The domains an email address picks from. Defaults to [`DomainPool::Reserved`].
+    pub email_domains: DomainPool,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a phone number. Defaults to 0.0.
+    pub phone_number_probability: f64,
+    /// The countries a phone number picks from. Defaults to the United States,
+    /// an empty list means no phone number is generated.
+    pub phone_countries: Vec<Country>,
+    /// The top-level domains a personal website domain picks from, without the leading dot.
+    /// Defaults to "com", "dev", "me", "io" and "net", an empty list falls back to "com".
+    pub personal_domain_tlds: Vec<String>,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a postal address. Defaults to 0.0.
+    pub address_probability: f64,
+    /// The countries a postal address picks from. Defaults to the United States,
+    /// an empty list means no address is generated.
+    pub address_countries: Vec<Country>,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a place of birth. The country is the one
+    /// of the `Person`'s nationality, or else of their [origin](Origin::country), or else of their address,
+    /// or else of their phone number, falling back to the United States. Defaults to 0.0.
+    pub place_of_birth_probability: f64,
+    /// The countries a `Person`'s nationality picks from. Without [`GenerationConfig::origins`],
+    /// names are picked from the lists of the nationality's [origin](Country::origin).
+    /// Defaults to an empty list, meaning no nationality is assigned.
+    pub nationalities: Vec<Country>,
+    /// The chance (between 0.0 and 1.0) of a `Person` with a nationality being a citizen of a second,
+    /// random country. Defaults to 0.0.
+    pub dual_citizenship_probability: f64,
+    /// The locale all country-specific attributes agree with: names are picked from the lists of the
+    /// country's [origin](Country::origin), and the nationality, phone number, address, place of birth
+    /// and thus time zone are all of the country. It takes precedence over
+    /// [`GenerationConfig::origins`], [`GenerationConfig::nationalities`], [`GenerationConfig::phone_countries`]
+    /// and [`GenerationConfig::address_countries`]. Defaults to `None`.
+    pub locale: Option<Locale>,
+    /// The chance (between 0.0 and 1.0) of an adult `Person` having a financial profile, in the
+    /// country of their address, or else of their nationality, or else of their phone number, falling
+    /// back to the United States. Minors never get one. Defaults to 0.0.
+    pub financial_profile_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a health record with allergies and
+    /// conditions sampled for their age. Defaults to 0.0.
+    pub health_record_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` of working age having an occupation of
+    /// a seniority plausible for their age. Defaults to 0.0.
+    pub occupation_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having their completed education generated,
+    /// in schools of the country of their address, or else of their nationality. Defaults to 0.0.
+    pub education_probability: f64,
+    /// Whether graduations may happen earlier than usual, down to the
+    /// [accelerated ages](crate::DegreeLevel::accelerated_graduation_age) of exceptionally gifted
+    /// students, like a doctorate at 19. Defaults to `false`.
+    pub accelerated_education: bool,
+    /// The chance (between 0.0 and 1.0) of a `Person` having interests and skills, weighted by how
+    /// popular they are at the `Person`'s age. Defaults to 0.0.
+    pub interests_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a personality with Big Five scores
+    /// that are plausible for their age. Defaults to 0.0.
+    pub personality_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a marital status, which follows the
+    /// shares at their age, so children are always single. Defaults to 0.0.
+    pub marital_status_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a list of spoken languages: the ones
+    /// of their nationalities, origin and country of residence, and foreign ones commonly learned
+    /// there with CEFR levels that fit their age. Defaults to 0.0.
+    pub languages_probability: f64,
+    /// The chance (between 0.0 and 1.0) of a `Person` having a religion or belief, following the
+    /// shares in the country of their address, or else of their nationality, unless their origin
+    /// has shares of its own. This is sensitive data meant for demographic simulations, so it is
+    /// never generated unless enabled. Defaults to 0.0.
+    pub religion_probability: f64,
+}
+impl Default for GenerationConfig {
+    fn default() -> Self {
+        Self {
+            middle_name_probability: 0.5,
+            additional_middle_name_probability: 0.1,
+            leet_probability: 0.25,
+            leet_map: LeetMap::default(),
+            number_styles: vec![
+                UsernameNumber::Random,
+                UsernameNumber::None,
+                UsernameNumber::Age,
+                UsernameNumber::BirthYear,
+            ],
+            separators: vec![
+                UsernameSeparator::None,
+                UsernameSeparator::Hyphen,
+                UsernameSeparator::Underscore,
+                UsernameSeparator::Dot,
+                UsernameSeparator::MiddleInitial,
+            ],
+            first_names: NameList::first_names(),
+            last_names: NameList::last_names(),
+            mononym_probability: 0.0,
+            genders: vec![],
+            female_first_names: NameList::female_first_names(),
+            male_first_names: NameList::male_first_names(),
+            unisex_first_names: NameList::unisex_first_names(),
+            pronouns: vec![],
+            title_probability: 0.0,
+            suffix_probability: 0.0,
+            username_nickname_probability: 0.0,
+            hyphenated_last_name_probability: 0.0,
+            surname_particle_probability: 0.0,
+            surname_particles: NameList::surname_particles(),
+            former_name_probability: 0.0,
+            gender_neutral_names: false,
+            birth_decade_names: false,
+            ascii_usernames: true,
+            origins: vec![],
+            deceased_probability: 0.0,
+            physique_probability: 0.0,
+            email_patterns: EmailPattern::ALL.to_vec(),
+            email_domains: DomainPool::Reserved,
+            phone_number_probability: 0.0,
+            phone_countries: vec![Country::UnitedStates],
+            personal_domain_tlds: ["com", "dev", "me", "io", "net"].map(String::from).to_vec(),
+            address_probability: 0.0,
+            address_countries: vec![Country::UnitedStates],
+            place_of_birth_probability: 0.0,
+            nationalities: vec![],
+            dual_citizenship_probability: 0.0,
+            locale: None,
+            financial_profile_probability: 0.0,
+            health_record_probability: 0.0,
+            occupation_probability: 0.0,
+            education_probability: 0.0,
+            accelerated_education: false,
+            interests_probability: 0.0,
+            personality_probability: 0.0,
+            marital_status_probability: 0.0,
+            languages_probability: 0.0,
+            religion_probability: 0.0,
+        }
+    }
+}
+impl GenerationConfig {
+    /// A configuration that generates clean usernames: no leetification and no numbers.
+    pub fn clean() -> Self {
+        Self {
+            leet_probability: 0.0,
+            number_styles: vec![UsernameNumber::None],
+            ..Self::default()
+        }
+    }
+
+    /// A configuration that only picks gender-neutral first and middle names.
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, NameList, Person};
+    /// let person = Person::random_with_config(&GenerationConfig::gender_neutral());
+    /// assert!(NameList::unisex_first_names().contains(&person.get_first_name()));
+    /// ```
+    pub fn gender_neutral() -> Self {
+        Self {
+            gender_neutral_names: true,
+            ..Self::default()
+        }
+    }
+
+    /// A configuration for complete `Person`s of the locale, who always have a phone number,
+    /// an address and a place of birth.
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, GenerationConfig, Locale, Person, Timezone};
+    /// let person = Person::random_with_config(&GenerationConfig::for_locale(Locale::DeDE));
+    /// assert_eq!(person.get_nationality(), Some(Country::Germany));
+    /// assert!(person.get_phone_number().unwrap().e164().starts_with("+49"));
+    /// assert_eq!(person.get_address().unwrap().country, Country::Germany);
+    /// assert_eq!(person.get_timezone(), Some(Timezone::Berlin));
+    /// ```
+    pub fn for_locale(locale: Locale) -> Self {
+        Self {
+            locale: Some(locale),
+            phone_number_probability: 1.0,
+            address_probability: 1.0,
+            place_of_birth_probability: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Picks how many middle names a random `Person` gets.
+    pub(crate) fn middle_name_count<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        if !generator::chance(rng, self.middle_name_probability) {
+            return 0;
+        }
+        let mut count = 1;
+        while count < MAX_MIDDLE_NAMES
+            && generator::chance(rng, self.additional_middle_name_probability)
+        {
+            count += 1;
+        }
+        count
+    }
+}
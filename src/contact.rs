@@ -0,0 +1,74 @@
+use rand::Rng;
+
+use crate::{Address, Person, PhoneNumber};
+
+/// A `Person` bundled with generated contact details, for when a whole persona is needed
+/// instead of just a name.
+/// ## Example
+/// ```rust
+/// use person::Person;
+/// let profile = Person::with_contact_profile();
+/// assert!(profile.email.contains('@'));
+/// assert_eq!(Some(profile.phone_number), profile.person.get_phone_number());
+/// assert_eq!(Some(profile.address), profile.person.get_address());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContactProfile {
+    pub person: Person,
+    pub email: String,
+    pub phone_number: PhoneNumber,
+    pub username: String,
+    pub address: Address,
+}
+impl ContactProfile {
+    /// Generates contact details for the `Person`. A `Person` without a phone number or address gets
+    /// a random one, which is also stored on the `Person`. It is in the country of their address,
+    /// or else of their nationality, or else of their phone number, falling back to the United States.
+    /// ## Example
+    /// ```rust
+    /// use person::{ContactProfile, Country, Locale, PersonBuilder};
+    /// let person = PersonBuilder::new().locale(Locale::DeDE).no_address().build();
+    /// let profile = ContactProfile::for_person(person);
+    /// assert_eq!(profile.address.country, Country::Germany);
+    /// ```
+    pub fn for_person(person: Person) -> Self {
+        Self::for_person_custom_rng(&mut rand::thread_rng(), person)
+    }
+
+    /// Same as [`ContactProfile::for_person`], but uses the given random number generator.
+    pub fn for_person_custom_rng<R: Rng + ?Sized>(rng: &mut R, mut person: Person) -> Self {
+        let email = person.get_random_email_custom_rng(rng);
+        let username = person.get_random_username_custom_rng(rng);
+        let country = person.financial_country();
+        let phone_number = match person.get_phone_number() {
+            Some(phone_number) => phone_number,
+            None => PhoneNumber::random_custom_rng(rng, country),
+        };
+        let address = match person.get_address() {
+            Some(address) => address,
+            None => Address::random_custom_rng(rng, country),
+        };
+        person.set_phone_number(Some(phone_number.clone()));
+        person.set_address(Some(address.clone()));
+        Self {
+            person,
+            email,
+            phone_number,
+            username,
+            address,
+        }
+    }
+}
+
+impl Person {
+    /// Generates a random `Person` with contact details, see [`ContactProfile::for_person`].
+    pub fn with_contact_profile() -> ContactProfile {
+        Self::with_contact_profile_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::with_contact_profile`], but uses the given random number generator.
+    pub fn with_contact_profile_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> ContactProfile {
+        let person = Person::random_custom_rng(rng);
+        ContactProfile::for_person_custom_rng(rng, person)
+    }
+}
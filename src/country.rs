@@ -0,0 +1,123 @@
+use crate::Origin;
+
+/// A country, used for country-specific formats like phone numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Country {
+    UnitedStates,
+    Canada,
+    UnitedKingdom,
+    Germany,
+    France,
+    Spain,
+    Italy,
+    Netherlands,
+    Australia,
+    Japan,
+}
+impl Country {
+    /// All countries.
+    pub const ALL: [Country; 10] = [
+        Country::UnitedStates,
+        Country::Canada,
+        Country::UnitedKingdom,
+        Country::Germany,
+        Country::France,
+        Country::Spain,
+        Country::Italy,
+        Country::Netherlands,
+        Country::Australia,
+        Country::Japan,
+    ];
+
+    /// Returns the ISO 3166-1 alpha-2 code, like "US".
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::UnitedStates => "US",
+            Self::Canada => "CA",
+            Self::UnitedKingdom => "GB",
+            Self::Germany => "DE",
+            Self::France => "FR",
+            Self::Spain => "ES",
+            Self::Italy => "IT",
+            Self::Netherlands => "NL",
+            Self::Australia => "AU",
+            Self::Japan => "JP",
+        }
+    }
+
+    /// Returns the ISO 3166-1 alpha-3 code, like "USA", as used on passports.
+    pub fn code3(self) -> &'static str {
+        match self {
+            Self::UnitedStates => "USA",
+            Self::Canada => "CAN",
+            Self::UnitedKingdom => "GBR",
+            Self::Germany => "DEU",
+            Self::France => "FRA",
+            Self::Spain => "ESP",
+            Self::Italy => "ITA",
+            Self::Netherlands => "NLD",
+            Self::Australia => "AUS",
+            Self::Japan => "JPN",
+        }
+    }
+
+    /// Returns the origin of the names most common in the country. Countries with many cultural origins,
+    /// like the United States, have none and use the configured name lists.
+    pub fn origin(self) -> Option<Origin> {
+        match self {
+            Self::UnitedKingdom => Some(Origin::English),
+            Self::Germany => Some(Origin::German),
+            Self::France => Some(Origin::French),
+            Self::Spain => Some(Origin::Spanish),
+            Self::Italy => Some(Origin::Italian),
+            Self::Netherlands => Some(Origin::Dutch),
+            Self::Japan => Some(Origin::Japanese),
+            Self::UnitedStates | Self::Canada | Self::Australia => None,
+        }
+    }
+
+    /// Returns the country with the given ISO 3166-1 alpha-2 code, ignoring case.
+    /// "UK" is accepted for the United Kingdom as well.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_ascii_uppercase().as_str() {
+            "UK" => Some(Self::UnitedKingdom),
+            code => Self::ALL.into_iter().find(|country| country.code() == code),
+        }
+    }
+
+    /// Returns the English name of the country.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::UnitedStates => "United States",
+            Self::Canada => "Canada",
+            Self::UnitedKingdom => "United Kingdom",
+            Self::Germany => "Germany",
+            Self::France => "France",
+            Self::Spain => "Spain",
+            Self::Italy => "Italy",
+            Self::Netherlands => "Netherlands",
+            Self::Australia => "Australia",
+            Self::Japan => "Japan",
+        }
+    }
+
+    /// Returns the international calling code without the leading "+", like "1" or "44".
+    pub fn calling_code(self) -> &'static str {
+        match self {
+            Self::UnitedStates | Self::Canada => "1",
+            Self::UnitedKingdom => "44",
+            Self::Germany => "49",
+            Self::France => "33",
+            Self::Spain => "34",
+            Self::Italy => "39",
+            Self::Netherlands => "31",
+            Self::Australia => "61",
+            Self::Japan => "81",
+        }
+    }
+}
+impl std::fmt::Display for Country {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
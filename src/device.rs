@@ -0,0 +1,135 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Person;
+
+/// The kind of a [`Device`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DeviceKind {
+    Desktop,
+    Mobile,
+    Tablet,
+}
+
+/// A MAC address, displayed like "02:1a:2b:3c:4d:5e".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MacAddress(pub [u8; 6]);
+impl MacAddress {
+    /// Creates a random locally administered unicast MAC address, which never clashes with real hardware.
+    pub fn random() -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`MacAddress::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes: [u8; 6] = rng.gen();
+        bytes[0] = (bytes[0] | 0b10) & !0b1;
+        Self(bytes)
+    }
+}
+impl std::fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+const DESKTOP_USER_AGENTS: [&str; 4] = [
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{major}.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{major}.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:{major}.0) Gecko/20100101 Firefox/{major}.0",
+    "Mozilla/5.0 (X11; Linux x86_64; rv:{major}.0) Gecko/20100101 Firefox/{major}.0",
+];
+const MOBILE_USER_AGENTS: [&str; 2] = [
+    "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+    "Mozilla/5.0 (Linux; Android 14; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{major}.0.0.0 Mobile Safari/537.36",
+];
+const TABLET_USER_AGENTS: [&str; 2] = [
+    "Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+    "Mozilla/5.0 (Linux; Android 14; SM-X710) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{major}.0.0.0 Safari/537.36",
+];
+
+/// A device a `Person` uses, for generating correlated user and device data like logs or analytics events.
+/// The IP addresses are picked from the ranges reserved for documentation,
+/// so they never point to real hosts.
+/// ## Example
+/// ```rust
+/// use person::Person;
+/// let device = Person::random().get_random_device();
+/// assert_eq!(device.ipv4.octets()[..2], [198, 51]);
+/// assert!(device.ipv6.to_string().starts_with("2001:db8:"));
+/// assert!(device.user_agent.starts_with("Mozilla/5.0"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Device {
+    pub kind: DeviceKind,
+    /// An address in 198.51.100.0/24.
+    pub ipv4: Ipv4Addr,
+    /// An address in 2001:db8::/32.
+    pub ipv6: Ipv6Addr,
+    pub mac: MacAddress,
+    pub user_agent: String,
+}
+impl Device {
+    /// Creates a random device of the given kind.
+    pub fn random(kind: DeviceKind) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), kind)
+    }
+
+    /// Same as [`Device::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, kind: DeviceKind) -> Self {
+        let ipv4 = Ipv4Addr::new(198, 51, 100, rng.gen_range(1..255));
+        let ipv6 = Ipv6Addr::new(
+            0x2001,
+            0x0db8,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        );
+        let user_agents: &[&str] = match kind {
+            DeviceKind::Desktop => &DESKTOP_USER_AGENTS,
+            DeviceKind::Mobile => &MOBILE_USER_AGENTS,
+            DeviceKind::Tablet => &TABLET_USER_AGENTS,
+        };
+        let user_agent = user_agents
+            .choose(rng)
+            .unwrap()
+            .replace("{major}", &rng.gen_range(120..130).to_string());
+        Self {
+            kind,
+            ipv4,
+            ipv6,
+            mac: MacAddress::random_custom_rng(rng),
+            user_agent,
+        }
+    }
+}
+
+impl Person {
+    /// Generates a random device for the `Person`. Younger `Person`s are more likely to use a phone,
+    /// older ones a desktop computer.
+    pub fn get_random_device(&self) -> Device {
+        self.get_random_device_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_device`], but uses the given random number generator.
+    pub fn get_random_device_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Device {
+        let mobile_share = match self.get_age() {
+            0..=29 => 0.7,
+            30..=54 => 0.5,
+            _ => 0.3,
+        };
+        let kind = if rng.gen_bool(mobile_share) {
+            DeviceKind::Mobile
+        } else if rng.gen_bool(0.2) {
+            DeviceKind::Tablet
+        } else {
+            DeviceKind::Desktop
+        };
+        Device::random_custom_rng(rng, kind)
+    }
+}
@@ -0,0 +1,66 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{slug, GenerationConfig, Person, SlugCase};
+
+impl Person {
+    /// Generates a plausible personal website domain derived from the name, like "janedoe.dev",
+    /// "jane-doe.com" or "jqdoe.me", using a top-level domain out of
+    /// [`GenerationConfig::personal_domain_tlds`].
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{GenerationConfig, Person};
+    /// let person = Person::from_parts("Jane", Some("Quinn"), "Doe", Utc::now());
+    /// let config = GenerationConfig {
+    ///     personal_domain_tlds: vec!["dev".into()],
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let domain = person.get_personal_domain_with_config(&config);
+    /// assert!(["janedoe.dev", "jane-doe.dev", "jqdoe.dev", "doe.dev"].contains(&domain.as_str()));
+    /// ```
+    pub fn get_personal_domain(&self) -> String {
+        self.get_personal_domain_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_personal_domain`], but uses the given random number generator.
+    pub fn get_personal_domain_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.get_personal_domain_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::get_personal_domain`], but uses the top-level domains of the given config.
+    pub fn get_personal_domain_with_config(&self, config: &GenerationConfig) -> String {
+        self.get_personal_domain_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::get_personal_domain_with_config`], but uses the given random number generator.
+    pub fn get_personal_domain_with_config_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> String {
+        let first = slug::slugify(&self.first_name, "", SlugCase::Lower);
+        let last = slug::slugify(&self.last_name, "", SlugCase::Lower);
+        let label = if last.is_empty() {
+            first
+        } else {
+            let initials: String = std::iter::once(&self.first_name)
+                .chain(&self.middle_names)
+                .filter_map(|name| slug::slugify(name, "", SlugCase::Lower).chars().next())
+                .collect();
+            match rng.gen_range(0..10) {
+                0..=3 => format!("{first}{last}"),
+                4..=6 => format!("{first}-{last}"),
+                7..=8 => format!("{initials}{last}"),
+                _ => last,
+            }
+        };
+        // Domain labels can't be longer than 63 characters or end with a hyphen.
+        let label: String = label.chars().take(63).collect();
+        let label = label.trim_end_matches('-');
+        let tld = config
+            .personal_domain_tlds
+            .choose(rng)
+            .map_or("com", String::as_str);
+        format!("{label}.{}", tld.trim_start_matches('.'))
+    }
+}
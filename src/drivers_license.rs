@@ -0,0 +1,305 @@
+use chrono::{DateTime, Datelike, Duration, Months, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, soundex, Country, Gender, Person};
+
+/// The youngest age at which a `Person` can hold a driver's license.
+const MIN_DRIVING_AGE: u32 = 16;
+/// The chance of a generated license being a commercial or motorcycle license.
+const SPECIAL_CLASS_PROBABILITY: f64 = 0.1;
+
+/// A United States state, of the cities addresses are generated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UsState {
+    Arizona,
+    California,
+    Colorado,
+    Florida,
+    Georgia,
+    Illinois,
+    Massachusetts,
+    NewYork,
+    Texas,
+    Washington,
+}
+impl UsState {
+    /// All states.
+    pub const ALL: [UsState; 10] = [
+        UsState::Arizona,
+        UsState::California,
+        UsState::Colorado,
+        UsState::Florida,
+        UsState::Georgia,
+        UsState::Illinois,
+        UsState::Massachusetts,
+        UsState::NewYork,
+        UsState::Texas,
+        UsState::Washington,
+    ];
+
+    /// Returns the two-letter postal abbreviation, like "NY".
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Arizona => "AZ",
+            Self::California => "CA",
+            Self::Colorado => "CO",
+            Self::Florida => "FL",
+            Self::Georgia => "GA",
+            Self::Illinois => "IL",
+            Self::Massachusetts => "MA",
+            Self::NewYork => "NY",
+            Self::Texas => "TX",
+            Self::Washington => "WA",
+        }
+    }
+
+    /// Returns the state with the given two-letter postal abbreviation, ignoring case.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|state| state.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Returns the number of years a driver's license is valid for.
+    pub fn license_validity_years(self) -> u32 {
+        match self {
+            Self::Arizona => 12,
+            Self::California | Self::Colorado | Self::Massachusetts => 5,
+            Self::Illinois => 4,
+            Self::Florida | Self::Georgia | Self::NewYork | Self::Texas | Self::Washington => 8,
+        }
+    }
+
+    /// Returns the class of a regular, non-commercial driver's license.
+    pub fn regular_license_class(self) -> LicenseClass {
+        match self {
+            Self::California | Self::Texas => LicenseClass::C,
+            _ => LicenseClass::D,
+        }
+    }
+}
+impl std::fmt::Display for UsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Arizona => "Arizona",
+            Self::California => "California",
+            Self::Colorado => "Colorado",
+            Self::Florida => "Florida",
+            Self::Georgia => "Georgia",
+            Self::Illinois => "Illinois",
+            Self::Massachusetts => "Massachusetts",
+            Self::NewYork => "New York",
+            Self::Texas => "Texas",
+            Self::Washington => "Washington",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The class of a driver's license, i.e. the vehicles it allows driving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LicenseClass {
+    /// Commercial, for combination vehicles like tractor-trailers.
+    A,
+    /// Commercial, for heavy single vehicles like buses.
+    B,
+    /// Regular passenger vehicles in some states, light commercial vehicles in others.
+    C,
+    /// Regular passenger vehicles.
+    D,
+    /// Motorcycles.
+    M,
+}
+impl std::fmt::Display for LicenseClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A United States driver's license.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DriversLicense {
+    /// The license number in the format of the state, like "D500-123-90-677-0" in Florida.
+    pub number: String,
+    pub state: UsState,
+    pub class: LicenseClass,
+    pub issued: DateTime<Utc>,
+    /// The expiry date, on the holder's birthday.
+    pub expires: DateTime<Utc>,
+}
+impl DriversLicense {
+    /// Returns whether the license is expired at the given time.
+    pub fn is_expired_at(&self, reference: DateTime<Utc>) -> bool {
+        self.expires <= reference
+    }
+}
+
+impl Person {
+    /// Generates a driver's license of the state of the `Person`'s address, or a random state if they
+    /// don't live in one of the [`UsState`]s. Returns `None` if the `Person` is younger than 16.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let person = Person::random_adult();
+    /// let license = person.get_random_drivers_license().unwrap();
+    /// assert!(license.issued < license.expires);
+    /// ```
+    pub fn get_random_drivers_license(&self) -> Option<DriversLicense> {
+        self.get_random_drivers_license_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_drivers_license`], but uses the given random number generator.
+    pub fn get_random_drivers_license_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Option<DriversLicense> {
+        let state = self
+            .address
+            .as_ref()
+            .filter(|address| address.country == Country::UnitedStates)
+            .and_then(|address| UsState::from_code(&address.region))
+            .unwrap_or_else(|| *UsState::ALL.choose(rng).unwrap());
+        self.get_random_drivers_license_in_custom_rng(rng, state)
+    }
+
+    /// Generates a driver's license of the given state, valid today, or on the date of death of a
+    /// deceased `Person`. Florida and Illinois numbers encode the name and date of birth,
+    /// and Arizona and California numbers start with the initial of the last name.
+    /// Returns `None` if the `Person` is younger than 16.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{Person, UsState};
+    /// let dob = Utc.with_ymd_and_hms(1990, 5, 17, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("Jane", None, "Doe", dob);
+    /// let license = person.get_random_drivers_license_in(UsState::Florida).unwrap();
+    /// assert!(license.number.starts_with("D000-"));
+    /// assert!(license.number.contains("-90-"));
+    /// assert!(!license.is_expired_at(Utc::now()));
+    ///
+    /// let child = Person::from_parts("John", None, "Doe", Utc::now());
+    /// assert_eq!(child.get_random_drivers_license_in(UsState::Texas), None);
+    /// ```
+    pub fn get_random_drivers_license_in(&self, state: UsState) -> Option<DriversLicense> {
+        self.get_random_drivers_license_in_custom_rng(&mut rand::thread_rng(), state)
+    }
+
+    /// Same as [`Person::get_random_drivers_license_in`], but uses the given random number generator.
+    pub fn get_random_drivers_license_in_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        state: UsState,
+    ) -> Option<DriversLicense> {
+        let end = self.date_of_death.unwrap_or_else(Utc::now);
+        let eligible = self
+            .date_of_birth
+            .checked_add_months(Months::new(12 * MIN_DRIVING_AGE))
+            .filter(|&eligible| eligible <= end)?;
+
+        // Licenses expire on a birthday, so the last renewal was within the validity period
+        // before the birthday following the end.
+        let validity = state.license_validity_years();
+        let birthday_after = (1..)
+            .filter_map(|years| {
+                self.date_of_birth
+                    .checked_add_months(Months::new(12 * years))
+            })
+            .find(|&birthday| birthday > end)?;
+        let start = (birthday_after - Months::new(12 * validity)).max(eligible);
+        let range_millis = (end - start).num_milliseconds().max(0);
+        let issued = start + Duration::milliseconds(rng.gen_range(0..=range_millis));
+        let last_birthday = (0..)
+            .filter_map(|years| {
+                self.date_of_birth
+                    .checked_add_months(Months::new(12 * years))
+            })
+            .take_while(|&birthday| birthday <= issued)
+            .last()
+            .unwrap_or(self.date_of_birth);
+        let expires = last_birthday + Months::new(12 * validity);
+
+        let class = if rng.gen_bool(SPECIAL_CLASS_PROBABILITY) {
+            *[LicenseClass::A, LicenseClass::B, LicenseClass::M]
+                .choose(rng)
+                .unwrap()
+        } else {
+            state.regular_license_class()
+        };
+
+        Some(DriversLicense {
+            number: self.drivers_license_number(rng, state),
+            state,
+            class,
+            issued,
+            expires,
+        })
+    }
+
+    /// Generates a license number in the format of the state.
+    fn drivers_license_number<R: Rng + ?Sized>(&self, rng: &mut R, state: UsState) -> String {
+        let last_initial = soundex(&self.last_name).chars().next().unwrap_or('X');
+        match state {
+            UsState::Arizona => format!("{last_initial}{}", random_digits(rng, 8)),
+            UsState::California => format!("{last_initial}{}", random_digits(rng, 7)),
+            UsState::Colorado => {
+                let number = random_digits(rng, 9);
+                format!("{}-{}-{}", &number[..2], &number[2..5], &number[5..])
+            }
+            UsState::Florida => {
+                // The Soundex code of the last name, a code of the first name, the year of birth and
+                // the day of birth, with 500 added for women.
+                let day = self.birth_day_code(40, 500);
+                format!(
+                    "{}-{}-{:02}-{day:03}-{}",
+                    self.soundex_or_placeholder(),
+                    random_digits(rng, 3),
+                    self.date_of_birth.year() % 100,
+                    rng.gen_range(0..=9)
+                )
+            }
+            UsState::Illinois => {
+                // Like Florida, but with 31 days per month and 600 added for women.
+                let year = format!("{:02}", self.date_of_birth.year() % 100);
+                let day = self.birth_day_code(31, 600);
+                format!(
+                    "{}-{}{}-{}{day:03}",
+                    self.soundex_or_placeholder(),
+                    random_digits(rng, 3),
+                    &year[..1],
+                    &year[1..]
+                )
+            }
+            UsState::Massachusetts => format!("S{}", random_digits(rng, 8)),
+            UsState::Georgia | UsState::NewYork => random_digits(rng, 9),
+            UsState::Texas => random_digits(rng, 8),
+            UsState::Washington => {
+                // Current numbers are "WDL" and nine random characters.
+                const CHARACTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ0123456789";
+                let suffix: String = (0..9)
+                    .map(|_| *CHARACTERS.choose(rng).unwrap() as char)
+                    .collect();
+                format!("WDL{suffix}")
+            }
+        }
+    }
+
+    /// Returns the Soundex code of the last name, or "X000" if it has no letters.
+    fn soundex_or_placeholder(&self) -> String {
+        let code = soundex(&self.last_name);
+        if code.is_empty() {
+            "X000".to_string()
+        } else {
+            code
+        }
+    }
+
+    /// Encodes the day of birth as `(month - 1) * days_per_month + day`, plus an offset for women.
+    fn birth_day_code(&self, days_per_month: u32, female_offset: u32) -> u32 {
+        let day = self.date_of_birth.month0() * days_per_month + self.date_of_birth.day();
+        if self.gender == Some(Gender::Female) {
+            day + female_offset
+        } else {
+            day
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use chrono::{DateTime, Datelike, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Country;
+
+/// The chance of a `Person` finishing high school.
+const HIGH_SCHOOL_PROBABILITY: f64 = 0.9;
+/// The chance of a high school graduate in the United States getting an associate degree first.
+const ASSOCIATE_PROBABILITY: f64 = 0.15;
+/// The chance of a high school graduate getting a bachelor's degree.
+const BACHELOR_PROBABILITY: f64 = 0.4;
+/// The chance of a bachelor getting a master's degree.
+const MASTER_PROBABILITY: f64 = 0.35;
+/// The chance of a master getting a doctorate.
+const DOCTORATE_PROBABILITY: f64 = 0.15;
+
+/// The level of a completed education, from the lowest to the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DegreeLevel {
+    HighSchool,
+    Associate,
+    Bachelor,
+    Master,
+    Doctorate,
+}
+impl DegreeLevel {
+    /// All levels, from the lowest to the highest.
+    pub const ALL: [DegreeLevel; 5] = [
+        DegreeLevel::HighSchool,
+        DegreeLevel::Associate,
+        DegreeLevel::Bachelor,
+        DegreeLevel::Master,
+        DegreeLevel::Doctorate,
+    ];
+
+    /// Returns the youngest usual age at graduation, like 22 for a bachelor's degree.
+    pub fn graduation_age(self) -> u32 {
+        match self {
+            Self::HighSchool => 18,
+            Self::Associate => 20,
+            Self::Bachelor => 22,
+            Self::Master => 24,
+            Self::Doctorate => 28,
+        }
+    }
+
+    /// Returns the youngest age at graduation of exceptionally gifted students,
+    /// see [`GenerationConfig::accelerated_education`](crate::GenerationConfig::accelerated_education).
+    pub fn accelerated_graduation_age(self) -> u32 {
+        match self {
+            Self::HighSchool => 14,
+            Self::Associate => 15,
+            Self::Bachelor => 16,
+            Self::Master => 17,
+            Self::Doctorate => 19,
+        }
+    }
+}
+impl std::fmt::Display for DegreeLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::HighSchool => "high school diploma",
+            Self::Associate => "associate degree",
+            Self::Bachelor => "bachelor's degree",
+            Self::Master => "master's degree",
+            Self::Doctorate => "doctorate",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The field of study of a degree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FieldOfStudy {
+    Biology,
+    Business,
+    ComputerScience,
+    Economics,
+    Education,
+    Engineering,
+    FineArts,
+    History,
+    Law,
+    Mathematics,
+    Nursing,
+    Psychology,
+}
+impl FieldOfStudy {
+    /// All fields of study.
+    pub const ALL: [FieldOfStudy; 12] = [
+        FieldOfStudy::Biology,
+        FieldOfStudy::Business,
+        FieldOfStudy::ComputerScience,
+        FieldOfStudy::Economics,
+        FieldOfStudy::Education,
+        FieldOfStudy::Engineering,
+        FieldOfStudy::FineArts,
+        FieldOfStudy::History,
+        FieldOfStudy::Law,
+        FieldOfStudy::Mathematics,
+        FieldOfStudy::Nursing,
+        FieldOfStudy::Psychology,
+    ];
+}
+impl std::fmt::Display for FieldOfStudy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Biology => "Biology",
+            Self::Business => "Business Administration",
+            Self::ComputerScience => "Computer Science",
+            Self::Economics => "Economics",
+            Self::Education => "Education",
+            Self::Engineering => "Engineering",
+            Self::FineArts => "Fine Arts",
+            Self::History => "History",
+            Self::Law => "Law",
+            Self::Mathematics => "Mathematics",
+            Self::Nursing => "Nursing",
+            Self::Psychology => "Psychology",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A completed education of a `Person`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Education {
+    /// The name of the school or university, like "University of Michigan".
+    pub school: String,
+    pub level: DegreeLevel,
+    /// The field of study, which high school diplomas don't have.
+    pub field: Option<FieldOfStudy>,
+    pub graduation_year: i32,
+}
+
+/// Generates the education of a `Person` born at the date of birth, lowest level first, with
+/// the graduations of the years before the one of the reference, in schools of the country.
+/// Later degrees keep the field of study of the first one.
+pub(crate) fn random_education_history<R: Rng + ?Sized>(
+    rng: &mut R,
+    date_of_birth: DateTime<Utc>,
+    reference: DateTime<Utc>,
+    country: Country,
+    accelerated: bool,
+) -> Vec<Education> {
+    let mut levels = vec![];
+    if rng.gen_bool(HIGH_SCHOOL_PROBABILITY) {
+        levels.push(DegreeLevel::HighSchool);
+        if country == Country::UnitedStates && rng.gen_bool(ASSOCIATE_PROBABILITY) {
+            levels.push(DegreeLevel::Associate);
+        }
+        if rng.gen_bool(BACHELOR_PROBABILITY) {
+            levels.push(DegreeLevel::Bachelor);
+            if rng.gen_bool(MASTER_PROBABILITY) {
+                levels.push(DegreeLevel::Master);
+                if rng.gen_bool(DOCTORATE_PROBABILITY) {
+                    levels.push(DegreeLevel::Doctorate);
+                }
+            }
+        }
+    }
+
+    let field = *FieldOfStudy::ALL.choose(rng).unwrap();
+    let mut history: Vec<Education> = vec![];
+    for level in levels {
+        let latest_age = level.graduation_age() + 2;
+        let age = if accelerated {
+            rng.gen_range(level.accelerated_graduation_age()..=latest_age)
+        } else {
+            rng.gen_range(level.graduation_age()..=latest_age)
+        };
+        // Every degree takes at least a year after the previous one.
+        let year = (date_of_birth.year() + age as i32).max(
+            history
+                .last()
+                .map_or(i32::MIN, |last| last.graduation_year + 1),
+        );
+        if year >= reference.year() {
+            break;
+        }
+        history.push(Education {
+            school: random_school(rng, level, country),
+            level,
+            field: (level != DegreeLevel::HighSchool).then_some(field),
+            graduation_year: year,
+        });
+    }
+    history
+}
+
+/// Picks a school of the level in the country.
+fn random_school<R: Rng + ?Sized>(rng: &mut R, level: DegreeLevel, country: Country) -> String {
+    let schools: &[&str] = match (level, country) {
+        (DegreeLevel::HighSchool, Country::UnitedStates) => &[
+            "Lincoln High School",
+            "Washington High School",
+            "Roosevelt High School",
+            "Central High School",
+        ],
+        (DegreeLevel::HighSchool, Country::Canada) => &[
+            "Northern Secondary School",
+            "Lord Byng Secondary School",
+            "Westmount High School",
+        ],
+        (DegreeLevel::HighSchool, Country::UnitedKingdom) => &[
+            "Manchester Grammar School",
+            "Leeds City Academy",
+            "St Mary's Catholic High School",
+        ],
+        (DegreeLevel::HighSchool, Country::Germany) => &[
+            "Goethe-Gymnasium",
+            "Schiller-Gymnasium",
+            "Humboldt-Gymnasium",
+        ],
+        (DegreeLevel::HighSchool, Country::France) => &[
+            "Lycée Victor Hugo",
+            "Lycée Jean Moulin",
+            "Lycée Louis-le-Grand",
+        ],
+        (DegreeLevel::HighSchool, Country::Spain) => {
+            &["IES Cervantes", "IES Ramiro de Maeztu", "IES Lope de Vega"]
+        }
+        (DegreeLevel::HighSchool, Country::Italy) => &[
+            "Liceo Scientifico Galileo Galilei",
+            "Liceo Classico Dante Alighieri",
+        ],
+        (DegreeLevel::HighSchool, Country::Netherlands) => &[
+            "Stedelijk Gymnasium",
+            "Barlaeus Gymnasium",
+            "Erasmus College",
+        ],
+        (DegreeLevel::HighSchool, Country::Australia) => &[
+            "Sydney Boys High School",
+            "Melbourne High School",
+            "Brisbane State High School",
+        ],
+        (DegreeLevel::HighSchool, Country::Japan) => &[
+            "Tokyo Metropolitan Hibiya High School",
+            "Osaka Prefectural Kitano High School",
+        ],
+        (DegreeLevel::Associate, _) => &[
+            "Santa Monica College",
+            "Miami Dade College",
+            "Austin Community College",
+            "Valencia College",
+        ],
+        (_, Country::UnitedStates) => &[
+            "University of Michigan",
+            "Ohio State University",
+            "University of Texas at Austin",
+            "Arizona State University",
+            "University of Washington",
+            "Boston University",
+        ],
+        (_, Country::Canada) => &[
+            "University of Toronto",
+            "McGill University",
+            "University of British Columbia",
+        ],
+        (_, Country::UnitedKingdom) => &[
+            "University of Manchester",
+            "University of Leeds",
+            "University of Edinburgh",
+            "King's College London",
+        ],
+        (_, Country::Germany) => &[
+            "Ludwig-Maximilians-Universität München",
+            "Technische Universität Berlin",
+            "Universität Hamburg",
+            "Universität zu Köln",
+        ],
+        (_, Country::France) => &[
+            "Sorbonne Université",
+            "Université Lyon 1",
+            "Université de Bordeaux",
+        ],
+        (_, Country::Spain) => &[
+            "Universidad Complutense de Madrid",
+            "Universitat de Barcelona",
+            "Universidad de Sevilla",
+        ],
+        (_, Country::Italy) => &[
+            "Università di Bologna",
+            "Sapienza Università di Roma",
+            "Politecnico di Milano",
+        ],
+        (_, Country::Netherlands) => &[
+            "Universiteit van Amsterdam",
+            "Universiteit Utrecht",
+            "Technische Universiteit Delft",
+        ],
+        (_, Country::Australia) => &[
+            "University of Sydney",
+            "University of Melbourne",
+            "Monash University",
+        ],
+        (_, Country::Japan) => &[
+            "University of Tokyo",
+            "Kyoto University",
+            "Osaka University",
+        ],
+    };
+    schools.choose(rng).unwrap().to_string()
+}
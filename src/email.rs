@@ -0,0 +1,344 @@
+use chrono::{DateTime, Datelike, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, transliterate, GenerationConfig, Person, UsernameNumber};
+
+/// The salt of the stable local part of `Person`s whose names can't be used in email addresses.
+const EMAIL_SALT: u64 = 0x4d41_494c;
+/// The number of digits after "user" in such a local part.
+const FALLBACK_DIGITS: usize = 6;
+
+/// The layout of the part before the `@` of a generated email address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmailPattern {
+    /// "jane.doe".
+    FirstDotLast,
+    /// "janedoe".
+    FirstLast,
+    /// "jdoe".
+    InitialLast,
+    /// "jane.doe84", using the last two digits of the year of birth.
+    FirstDotLastYear,
+    /// A random username without leetification, see [`Person::get_random_username`].
+    Username,
+}
+impl EmailPattern {
+    /// All patterns.
+    pub const ALL: [EmailPattern; 5] = [
+        EmailPattern::FirstDotLast,
+        EmailPattern::FirstLast,
+        EmailPattern::InitialLast,
+        EmailPattern::FirstDotLastYear,
+        EmailPattern::Username,
+    ];
+}
+
+/// The domains reserved for documentation and testing by RFC 2606.
+const RESERVED_DOMAINS: [&str; 3] = ["example.com", "example.org", "example.net"];
+
+/// Popular free email providers.
+const FREEMAIL_DOMAINS: [&str; 10] = [
+    "gmail.com",
+    "yahoo.com",
+    "outlook.com",
+    "hotmail.com",
+    "icloud.com",
+    "aol.com",
+    "proton.me",
+    "gmx.net",
+    "mail.com",
+    "yandex.com",
+];
+
+/// The domains generated email addresses pick from.
+/// ## Example
+/// ```rust
+/// use person::{DomainPool, GenerationConfig, Person};
+/// let config = GenerationConfig {
+///     email_domains: DomainPool::from_iter(["acme.test"]),
+///     ..GenerationConfig::default()
+/// };
+/// let email = Person::random().get_random_email_with_config(&config);
+/// assert!(email.ends_with("@acme.test"));
+/// assert!(DomainPool::Reserved.domains().contains(&"example.com".to_string()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DomainPool {
+    /// The domains reserved for documentation by RFC 2606, which can never belong to a real mailbox.
+    #[default]
+    Reserved,
+    /// Popular free email providers like "gmail.com". Generated addresses may belong to real people,
+    /// so never send emails to them.
+    Freemail,
+    /// A user supplied list, like company or test domains. An empty list falls back to "example.com".
+    Custom(Vec<String>),
+}
+impl DomainPool {
+    /// Returns the domains of the pool.
+    pub fn domains(&self) -> Vec<String> {
+        match self {
+            Self::Reserved => RESERVED_DOMAINS.map(String::from).to_vec(),
+            Self::Freemail => FREEMAIL_DOMAINS.map(String::from).to_vec(),
+            Self::Custom(domains) => domains.clone(),
+        }
+    }
+
+    /// Picks a random domain from the pool.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> &str {
+        match self {
+            Self::Reserved => RESERVED_DOMAINS.choose(rng).unwrap(),
+            Self::Freemail => FREEMAIL_DOMAINS.choose(rng).unwrap(),
+            Self::Custom(domains) => domains
+                .choose(rng)
+                .map_or(RESERVED_DOMAINS[0], String::as_str),
+        }
+    }
+}
+impl<S: Into<String>> FromIterator<S> for DomainPool {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::Custom(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Person {
+    /// Generates a random email address matching the `Person`'s name, like "jane.doe84@example.com".
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{DomainPool, EmailPattern, GenerationConfig, Person};
+    /// let dob = Utc.with_ymd_and_hms(1984, 5, 1, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("José", None, "van der Berg", dob);
+    /// assert!(person.get_random_email().contains('@'));
+    /// let config = GenerationConfig {
+    ///     email_patterns: vec![EmailPattern::FirstDotLastYear],
+    ///     email_domains: DomainPool::from_iter(["example.org"]),
+    ///     ..GenerationConfig::default()
+    /// };
+    /// assert_eq!(person.get_random_email_with_config(&config), "jose.vanderberg84@example.org");
+    /// // Names without Latin letters fall back to a stable "user" address.
+    /// let person = Person::from_parts("李", None, "王", dob);
+    /// let email = person.get_random_email();
+    /// assert!(email.starts_with("user") && email.contains('@'));
+    /// assert!(person.get_mailbox().starts_with(r#""王, 李" <user"#));
+    /// let person = Person::from_parts("", None, "Doe", dob);
+    /// assert_eq!(person.get_random_email_with_config(&config), "doe84@example.org");
+    /// ```
+    pub fn get_random_email(&self) -> String {
+        self.get_random_email_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_email`], but uses the given random number generator.
+    pub fn get_random_email_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.get_random_email_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::get_random_email`], but uses the email patterns and domains of the given config.
+    pub fn get_random_email_with_config(&self, config: &GenerationConfig) -> String {
+        self.get_random_email_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::get_random_email_with_config`], but uses the given random number generator.
+    pub fn get_random_email_with_config_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> String {
+        self.random_email_at(rng, config, Utc::now())
+    }
+
+    /// Generates a random email address, using the age at the given reference date for username patterns.
+    pub(crate) fn random_email_at<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        config: &GenerationConfig,
+        now: DateTime<Utc>,
+    ) -> String {
+        let first = email_part(&self.first_name);
+        let last = email_part(&self.last_name);
+        if first.is_empty() && last.is_empty() {
+            return format!(
+                "{}@{}",
+                self.fallback_local_part(),
+                config.email_domains.choose(rng)
+            );
+        }
+        let local_part = match config.email_patterns.choose(rng) {
+            Some(EmailPattern::FirstDotLast) | None => self.name_local_part("."),
+            Some(EmailPattern::FirstLast) => self.name_local_part(""),
+            Some(EmailPattern::InitialLast) if last.is_empty() => first,
+            Some(EmailPattern::InitialLast) => format!("{}{last}", first.get(..1).unwrap_or("")),
+            Some(EmailPattern::FirstDotLastYear) => {
+                format!(
+                    "{}{:02}",
+                    self.name_local_part("."),
+                    self.date_of_birth.year().rem_euclid(100)
+                )
+            }
+            Some(EmailPattern::Username) => {
+                let username_config = GenerationConfig {
+                    leet_probability: 0.0,
+                    number_styles: vec![UsernameNumber::None, UsernameNumber::BirthYear],
+                    ascii_usernames: true,
+                    ..config.clone()
+                };
+                let username = email_part(&self.random_username_at(rng, &username_config, now));
+                match username.trim_matches(['.', '-', '_']) {
+                    "" => self.fallback_local_part(),
+                    username => username.to_string(),
+                }
+            }
+        };
+        format!("{local_part}@{}", config.email_domains.choose(rng))
+    }
+
+    /// Returns an RFC 5322 mailbox with the [sortable name](Person::get_sortable_name) as display name
+    /// and a "first.last@example.com" address, like `"Doe, Jane Q." <jane.doe@example.com>`.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("Jane", Some("Quinn"), "Doe", Utc::now());
+    /// assert_eq!(person.get_mailbox(), r#""Doe, Jane Q." <jane.doe@example.com>"#);
+    /// let person = Person::from_parts("Jane", None, "\"JD\" Doe", Utc::now());
+    /// assert_eq!(
+    ///     person.get_mailbox_with("jd@example.org"),
+    ///     r#""\"JD\" Doe, Jane" <jd@example.org>"#
+    /// );
+    /// ```
+    pub fn get_mailbox(&self) -> String {
+        let local_part = self.name_local_part(".");
+        self.get_mailbox_with(&format!("{local_part}@{}", RESERVED_DOMAINS[0]))
+    }
+
+    /// Joins the email parts of the first and last name with the separator, leaving out empty
+    /// ones, or returns the fallback if both are empty.
+    fn name_local_part(&self, separator: &str) -> String {
+        let parts: Vec<String> = [email_part(&self.first_name), email_part(&self.last_name)]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect();
+        if parts.is_empty() {
+            self.fallback_local_part()
+        } else {
+            parts.join(separator)
+        }
+    }
+
+    /// Returns a stable local part like "user482913" for `Person`s whose names have no Latin
+    /// letters or digits.
+    fn fallback_local_part(&self) -> String {
+        let digits = random_digits(&mut self.identity_rng(EMAIL_SALT), FALLBACK_DIGITS);
+        format!("user{digits}")
+    }
+
+    /// Same as [`Person::get_mailbox`], but uses the given address.
+    pub fn get_mailbox_with(&self, address: &str) -> String {
+        let display_name = self.get_sortable_name();
+        // Display names made of atoms can stay unquoted, anything else becomes a quoted string.
+        let is_atom_text = |c: char| {
+            c.is_alphanumeric() || c == ' ' || !c.is_ascii() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+        };
+        if display_name.chars().all(is_atom_text) {
+            format!("{display_name} <{address}>")
+        } else {
+            let escaped = display_name.replace('\\', "\\\\").replace('"', "\\\"");
+            format!("\"{escaped}\" <{address}>")
+        }
+    }
+}
+
+/// Converts a name to lowercase ASCII letters, digits, dots, hyphens and underscores.
+fn email_part(name: &str) -> String {
+    transliterate::to_ascii(name)
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Groups of domains that deliver to the same mailbox.
+const ALIAS_DOMAINS: [&[&str]; 4] = [
+    &["gmail.com", "googlemail.com"],
+    &["outlook.com", "hotmail.com", "live.com"],
+    &["icloud.com", "me.com", "mac.com"],
+    &["proton.me", "protonmail.com", "pm.me"],
+];
+
+/// Adds a sub-address tag to an email address, like "jane.doe+news@example.com".
+/// An existing tag is replaced, and anything without an `@` is returned unchanged.
+/// ## Example
+/// ```rust
+/// assert_eq!(person::email_with_tag("jane.doe@example.com", "news"), "jane.doe+news@example.com");
+/// assert_eq!(person::email_with_tag("jane.doe+old@example.com", "new"), "jane.doe+new@example.com");
+/// ```
+pub fn email_with_tag(email: &str, tag: &str) -> String {
+    match email.rsplit_once('@') {
+        Some((local_part, domain)) => {
+            let local_part = local_part.split('+').next().unwrap();
+            format!("{local_part}+{tag}@{domain}")
+        }
+        None => email.to_string(),
+    }
+}
+
+/// Returns up to `limit` variants of an email address with dots placed differently in the part before
+/// the `@`, like "j.anedoe@gmail.com", which providers like Gmail deliver to the same mailbox.
+/// The address itself is not included.
+/// ## Example
+/// ```rust
+/// let variants = person::email_dot_variants("jdoe@gmail.com", 10);
+/// assert_eq!(variants.len(), 7);
+/// assert!(variants.contains(&"j.d.o.e@gmail.com".to_string()));
+/// ```
+pub fn email_dot_variants(email: &str, limit: usize) -> Vec<String> {
+    let Some((local_part, domain)) = email.rsplit_once('@') else {
+        return vec![];
+    };
+    let (name, tag) = match local_part.split_once('+') {
+        Some((name, tag)) => (name, format!("+{tag}")),
+        None => (local_part, String::new()),
+    };
+    let letters: Vec<char> = name.chars().filter(|&c| c != '.').collect();
+    let gaps = letters
+        .len()
+        .saturating_sub(1)
+        .min(usize::BITS as usize - 1);
+    (0..1usize << gaps)
+        .map(|dots| {
+            let mut variant = String::new();
+            for (i, c) in letters.iter().enumerate() {
+                if i > 0 && dots & (1 << (i - 1)) != 0 {
+                    variant.push('.');
+                }
+                variant.push(*c);
+            }
+            format!("{variant}{tag}@{domain}")
+        })
+        .filter(|variant| variant != email)
+        .take(limit)
+        .collect()
+}
+
+/// Returns the email address at the other domains that deliver to the same mailbox,
+/// like "jane.doe@googlemail.com" for "jane.doe@gmail.com". Unknown domains have no aliases.
+/// ## Example
+/// ```rust
+/// assert_eq!(person::email_alias_domains("jane@hotmail.com"), ["jane@outlook.com", "jane@live.com"]);
+/// assert!(person::email_alias_domains("jane@example.com").is_empty());
+/// ```
+pub fn email_alias_domains(email: &str) -> Vec<String> {
+    let Some((local_part, domain)) = email.rsplit_once('@') else {
+        return vec![];
+    };
+    let domain = domain.to_ascii_lowercase();
+    ALIAS_DOMAINS
+        .iter()
+        .find(|group| group.contains(&domain.as_str()))
+        .map_or(vec![], |group| {
+            group
+                .iter()
+                .filter(|&&alias| alias != domain)
+                .map(|alias| format!("{local_part}@{alias}"))
+                .collect()
+        })
+}
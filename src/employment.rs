@@ -0,0 +1,148 @@
+use chrono::{DateTime, Months, TimeZone, Utc};
+use rand::Rng;
+
+use crate::{occupation::RETIREMENT_AGE, random_date_between, Company, Occupation, Person};
+
+/// The youngest age at which a `Person` starts their first job after school.
+const CAREER_START_AGE: u32 = 18;
+/// The oldest age at which a `Person` starts their first job, after university.
+const LATEST_CAREER_START_AGE: u32 = 25;
+/// The shortest and longest time a `Person` stays in a job, in months.
+const TENURE_MONTHS: (u32, u32) = (6, 96);
+/// The longest time between two jobs, in months.
+const MAX_GAP_MONTHS: u32 = 6;
+/// The chance of a job in the same industry being a promotion at the same employer.
+const PROMOTION_PROBABILITY: f64 = 0.3;
+
+/// A job in the employment history of a `Person`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Job {
+    pub occupation: Occupation,
+    pub employer: Company,
+    pub start: DateTime<Utc>,
+    /// The end date, or `None` if the `Person` still has the job.
+    pub end: Option<DateTime<Utc>>,
+}
+impl Job {
+    /// Returns whether the `Person` still has the job.
+    pub fn is_current(&self) -> bool {
+        self.end.is_none()
+    }
+}
+
+impl Person {
+    /// Generates the jobs of the `Person` from their first one at 18 to 25, and not before their
+    /// last graduation if they have an [education](Person::get_education), until today, their date
+    /// of death or their retirement at 67, oldest first. Jobs never overlap and never start before the
+    /// previous one ended. Later jobs are at least as senior and are often in the same industry.
+    /// The current job, if any, is the `Person`'s [occupation](Person::get_occupation) if they have one,
+    /// and no earlier job is more senior than it.
+    /// Returns an empty list for `Person`s too young to have worked.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{DegreeLevel, Education, FieldOfStudy, Person};
+    /// let person = Person::random_adult();
+    /// let history = person.get_random_employment_history();
+    /// for (job, next) in history.iter().zip(history.iter().skip(1)) {
+    ///     assert!(job.end.unwrap() <= next.start);
+    ///     assert!(job.occupation.seniority <= next.occupation.seniority);
+    /// }
+    /// assert!(history.iter().all(|job| job.end.unwrap_or(job.start) <= Utc::now()));
+    /// assert!(history.iter().rev().skip(1).all(|job| !job.is_current()));
+    ///
+    /// let dob = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+    /// let mut graduate = Person::from_parts("Jane", None, "Doe", dob);
+    /// graduate.set_education(vec![Education {
+    ///     school: "University of Michigan".to_string(),
+    ///     level: DegreeLevel::Doctorate,
+    ///     field: Some(FieldOfStudy::Biology),
+    ///     graduation_year: 2010,
+    /// }]);
+    /// let history = graduate.get_random_employment_history();
+    /// assert!(history[0].start >= Utc.with_ymd_and_hms(2010, 7, 1, 0, 0, 0).unwrap());
+    /// ```
+    pub fn get_random_employment_history(&self) -> Vec<Job> {
+        self.get_random_employment_history_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_employment_history`], but uses the given random number generator.
+    pub fn get_random_employment_history_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Vec<Job> {
+        let now = Utc::now();
+        let retirement = self.date_of_birth + Months::new(12 * RETIREMENT_AGE);
+        let end_of_career = self.date_of_death.unwrap_or(now).min(retirement);
+        let still_working = self.date_of_death.is_none() && now < retirement;
+        let country = self.financial_country();
+
+        let career_start_age = rng.gen_range(CAREER_START_AGE..=LATEST_CAREER_START_AGE);
+        let mut start =
+            self.date_of_birth + Months::new(12 * career_start_age + rng.gen_range(0..12));
+        // Graduates start working in the months after their last graduation in the summer.
+        let graduation = self
+            .education
+            .iter()
+            .map(|education| education.graduation_year)
+            .max()
+            .and_then(|year| Utc.with_ymd_and_hms(year, 7, 1, 0, 0, 0).single());
+        if let Some(graduation) = graduation {
+            start = start.max(graduation + Months::new(rng.gen_range(0..=MAX_GAP_MONTHS)));
+        }
+        let mut history: Vec<Job> = vec![];
+        while start < end_of_career {
+            let previous = history.last();
+            let Some(occupation) = Occupation::random_after_custom_rng(
+                rng,
+                self.age_at(start),
+                previous.map(|job| &job.occupation),
+                self.occupation
+                    .as_ref()
+                    .map(|occupation| occupation.seniority),
+            ) else {
+                break;
+            };
+            let employer = match previous {
+                Some(job)
+                    if job.occupation.industry == occupation.industry
+                        && rng.gen_bool(PROMOTION_PROBABILITY) =>
+                {
+                    job.employer.clone()
+                }
+                _ => Company::random_in_custom_rng(rng, occupation.industry, country),
+            };
+            let (min_tenure, max_tenure) = TENURE_MONTHS;
+            let end = start + Months::new(rng.gen_range(min_tenure..=max_tenure));
+            let end = if end < end_of_career {
+                Some(end)
+            } else if still_working {
+                None
+            } else {
+                Some(end_of_career)
+            };
+            history.push(Job {
+                occupation,
+                employer,
+                start,
+                end,
+            });
+            let Some(end) = end else {
+                break;
+            };
+            let gap_end = (end + Months::new(MAX_GAP_MONTHS)).min(end_of_career);
+            start = random_date_between(rng, end, gap_end).unwrap_or(end_of_career);
+        }
+
+        if let (Some(current), Some(occupation)) = (history.last_mut(), &self.occupation) {
+            if current.is_current() {
+                if current.employer.industry != occupation.industry {
+                    current.employer =
+                        Company::random_in_custom_rng(rng, occupation.industry, country);
+                }
+                current.occupation = occupation.clone();
+            }
+        }
+        history
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+
+/// The error type for fallible `Person` generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PersonError {
+    /// The minimum date of birth is not before the maximum date of birth.
+    InvalidDobRange {
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+    },
+    /// The list to pick first names from is empty.
+    EmptyFirstNames,
+    /// The list to pick last names from is empty.
+    EmptyLastNames,
+    /// No generated `Person` satisfied the constraints within the given number of attempts.
+    ConstraintsNotSatisfied { attempts: usize },
+    /// No unused name could be generated anymore, after emitting the given number of unique `Person`s.
+    UniqueNamesExhausted { unique: usize },
+    /// The age distribution has no brackets, a bracket with `min_age > max_age` or invalid weights.
+    InvalidAgeDistribution,
+    /// The date of death is before the date of birth.
+    InvalidDateOfDeath {
+        date_of_birth: DateTime<Utc>,
+        date_of_death: DateTime<Utc>,
+    },
+}
+impl std::fmt::Display for PersonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDobRange { min, max } => {
+                write!(f, "invalid date of birth range: {min} must be before {max}")
+            }
+            Self::EmptyFirstNames => write!(f, "the list of first names is empty"),
+            Self::EmptyLastNames => write!(f, "the list of last names is empty"),
+            Self::ConstraintsNotSatisfied { attempts } => {
+                write!(
+                    f,
+                    "no person satisfied the constraints after {attempts} attempts"
+                )
+            }
+            Self::UniqueNamesExhausted { unique } => {
+                write!(
+                    f,
+                    "the name space is exhausted after {unique} unique persons"
+                )
+            }
+            Self::InvalidAgeDistribution => write!(f, "the age distribution is invalid"),
+            Self::InvalidDateOfDeath {
+                date_of_birth,
+                date_of_death,
+            } => {
+                write!(
+                    f,
+                    "invalid date of death: {date_of_death} is before the date of birth {date_of_birth}"
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for PersonError {}
+
+/// The error type for parsing a `Person` from a full name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNameError {
+    /// The input is empty or only contains whitespace.
+    Empty,
+    /// The input has no first name, e.g. `"Doe,"`.
+    MissingFirstName,
+    /// The input has no last name before the comma, e.g. `", Jane"`.
+    MissingLastName,
+    /// The input contains more than one comma.
+    TooManyCommas,
+}
+impl std::fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the name is empty"),
+            Self::MissingFirstName => write!(f, "the name has no first name"),
+            Self::MissingLastName => write!(f, "the name has no last name"),
+            Self::TooManyCommas => write!(f, "the name contains more than one comma"),
+        }
+    }
+}
+impl std::error::Error for ParseNameError {}
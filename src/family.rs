@@ -0,0 +1,236 @@
+use chrono::{Duration, Months, Utc};
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+use crate::{
+    generator, random_date_between, sample_normal, GenerationConfig, MaritalStatus, Person,
+    PersonError, MARRIAGE_AGE,
+};
+
+/// The youngest and oldest age of the first partner of a generated `Family`.
+const PARTNER_AGES: (u32, u32) = (20, 80);
+/// The standard deviation of the age difference between the partners, in years.
+const PARTNER_AGE_GAP_STD_DEV: f64 = 4.0;
+/// The youngest and oldest age of both partners when a child is born.
+const PARENT_AGES: (u32, u32) = (18, 45);
+/// The relative weights of having no child, one child, two children and so on.
+const CHILD_COUNT_WEIGHTS: [f64; 5] = [20.0, 20.0, 35.0, 17.0, 8.0];
+/// The shortest time between the births of two siblings.
+const MIN_SIBLING_GAP_DAYS: i64 = 300;
+
+/// How the last names of a `Family` are chosen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SurnamePolicy {
+    /// Everyone has the last name of the first partner. The second partner keeps theirs as a
+    /// [former name](Person::get_former_names).
+    #[default]
+    Shared,
+    /// The partners keep their last names and the children have the one of the first partner.
+    Separate,
+    /// The partners keep their last names and the children have both, joined like "Doe-Smith".
+    Hyphenated,
+}
+
+/// A household of two partners and their children, who share an address and whose ages fit
+/// together: the children were born while both partners were between 18 and 45 years old.
+/// ## Example
+/// ```rust
+/// use person::Family;
+/// let family = Family::random();
+/// let [first, second] = &family.partners;
+/// assert_eq!(first.get_last_name(), second.get_last_name());
+/// for child in &family.children {
+///     assert!(child.get_date_of_birth() > first.get_date_of_birth());
+///     assert!(child.get_date_of_birth() > second.get_date_of_birth());
+///     assert_eq!(child.get_last_name(), first.get_last_name());
+/// }
+/// assert_eq!(family.members().count(), 2 + family.children.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Family {
+    pub partners: [Person; 2],
+    /// The children, oldest first.
+    pub children: Vec<Person>,
+}
+impl Family {
+    /// Creates a random `Family` with a shared last name.
+    pub fn random() -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Family::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_with_config_custom_rng(
+            rng,
+            &GenerationConfig::default(),
+            SurnamePolicy::Shared,
+        )
+    }
+
+    /// Same as [`Family::random`], but uses the probabilities of the given config for every
+    /// member and the given surname policy.
+    /// The second partner and the children live at the first partner's address. Children have
+    /// the nationalities of both partners, and partners with a marital status are married, or
+    /// widowed if the other one died first.
+    /// ## Example
+    /// ```rust
+    /// use person::{Family, GenerationConfig, SurnamePolicy};
+    /// let config = GenerationConfig {
+    ///     address_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let family = Family::random_with_config(&config, SurnamePolicy::Hyphenated);
+    /// let [first, second] = &family.partners;
+    /// for child in &family.children {
+    ///     assert_eq!(child.get_address(), first.get_address());
+    ///     assert!(child.get_last_name().starts_with(&first.get_last_name()));
+    ///     assert!(child.get_last_name().ends_with(&second.get_last_name()));
+    /// }
+    /// ```
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Family::try_random_with_config`].
+    pub fn random_with_config(config: &GenerationConfig, surnames: SurnamePolicy) -> Self {
+        Self::random_with_config_custom_rng(&mut rand::thread_rng(), config, surnames)
+    }
+
+    /// Same as [`Family::random_with_config`], but uses the given random number generator.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Family::try_random_with_config_custom_rng`].
+    pub fn random_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GenerationConfig,
+        surnames: SurnamePolicy,
+    ) -> Self {
+        Self::try_random_with_config_custom_rng(rng, config, surnames).unwrap()
+    }
+
+    /// Same as [`Family::random_with_config`], but returns an error instead of panicking.
+    /// ## Example
+    /// ```rust
+    /// use person::{Family, GenerationConfig, NameList, PersonError, SurnamePolicy};
+    /// let config = GenerationConfig {
+    ///     first_names: NameList::Custom(vec![]),
+    ///     ..GenerationConfig::default()
+    /// };
+    /// assert_eq!(
+    ///     Family::try_random_with_config(&config, SurnamePolicy::Shared),
+    ///     Err(PersonError::EmptyFirstNames)
+    /// );
+    /// ```
+    pub fn try_random_with_config(
+        config: &GenerationConfig,
+        surnames: SurnamePolicy,
+    ) -> Result<Self, PersonError> {
+        Self::try_random_with_config_custom_rng(&mut rand::thread_rng(), config, surnames)
+    }
+
+    /// Same as [`Family::try_random_with_config`], but uses the given random number generator.
+    pub fn try_random_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GenerationConfig,
+        surnames: SurnamePolicy,
+    ) -> Result<Self, PersonError> {
+        let now = Utc::now();
+        let (min_age, max_age) = PARTNER_AGES;
+        let date_of_birth = random_date_between(
+            rng,
+            now - Months::new(12 * max_age),
+            now - Months::new(12 * min_age),
+        )?;
+        let mut first = generator::generate_person(rng, config, date_of_birth, now)?;
+        let gap = sample_normal(rng, 0.0, PARTNER_AGE_GAP_STD_DEV * 365.25).round() as i64;
+        let date_of_birth =
+            (date_of_birth + Duration::days(gap)).min(now - Months::new(12 * MARRIAGE_AGE));
+        let mut second = generator::generate_person(rng, config, date_of_birth, now)?;
+        second.address = first.address.clone();
+        let outlived =
+            |person: &Person, other: &Person| match (person.date_of_death, other.date_of_death) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(death), Some(other_death)) => death > other_death,
+            };
+        let widowed = [outlived(&first, &second), outlived(&second, &first)];
+        for (partner, widowed) in [&mut first, &mut second].into_iter().zip(widowed) {
+            if partner.marital_status.is_some() {
+                partner.marital_status = Some(if widowed {
+                    MaritalStatus::Widowed
+                } else {
+                    MaritalStatus::Married
+                });
+            }
+        }
+
+        let family_name = first.last_name.clone();
+        let children_name = match surnames {
+            SurnamePolicy::Shared => {
+                if second.last_name != family_name {
+                    let own_name = second.last_name.clone();
+                    rename(&mut second, &family_name);
+                    second.former_names.push(own_name);
+                }
+                family_name
+            }
+            SurnamePolicy::Separate => family_name,
+            SurnamePolicy::Hyphenated if second.last_name == family_name => family_name,
+            SurnamePolicy::Hyphenated => format!("{family_name}-{}", second.last_name),
+        };
+
+        // Both partners are old enough and still young enough, and alive.
+        let (min_parent_age, max_parent_age) = PARENT_AGES;
+        let older = first.date_of_birth.min(second.date_of_birth);
+        let younger = first.date_of_birth.max(second.date_of_birth);
+        let earliest = younger + Months::new(12 * min_parent_age);
+        let latest = [
+            Some(older + Months::new(12 * max_parent_age)),
+            Some(now),
+            first.date_of_death,
+            second.date_of_death,
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap();
+        let count = WeightedIndex::new(CHILD_COUNT_WEIGHTS).unwrap().sample(rng);
+        let mut dates_of_birth = vec![];
+        for _ in 0..count {
+            match random_date_between(rng, earliest, latest) {
+                Ok(date_of_birth) => dates_of_birth.push(date_of_birth),
+                Err(_) => break,
+            }
+        }
+        dates_of_birth.sort();
+        dates_of_birth
+            .dedup_by(|later, earlier| *later - *earlier < Duration::days(MIN_SIBLING_GAP_DAYS));
+
+        let mut nationalities = first.nationalities.clone();
+        nationalities.extend(
+            second
+                .nationalities
+                .iter()
+                .filter(|country| !first.nationalities.contains(country)),
+        );
+        let mut children = vec![];
+        for date_of_birth in dates_of_birth {
+            let mut child = generator::generate_person(rng, config, date_of_birth, now)?;
+            rename(&mut child, &children_name);
+            child.former_names.clear();
+            child.address = first.address.clone();
+            child.nationalities = nationalities.clone();
+            children.push(child);
+        }
+        Ok(Self {
+            partners: [first, second],
+            children,
+        })
+    }
+
+    /// Returns all members of the `Family`, the partners first and then the children, oldest first.
+    pub fn members(&self) -> impl Iterator<Item = &Person> {
+        self.partners.iter().chain(&self.children)
+    }
+}
+
+/// Changes the last name of the `Person`, including in the holder names of their bank accounts.
+fn rename(person: &mut Person, last_name: &str) {
+    person.last_name = last_name.to_string();
+    person.update_account_holders();
+}
@@ -0,0 +1,179 @@
+use rand::Rng;
+
+use crate::{sample_normal, BankAccount, Country, Person, PhoneNumber, UsBankAccount};
+
+/// The lowest FICO credit score.
+const MIN_CREDIT_SCORE: u16 = 300;
+/// The highest FICO credit score.
+const MAX_CREDIT_SCORE: u16 = 850;
+
+/// A range of annual income in US dollars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IncomeBracket {
+    Under25k,
+    From25kTo50k,
+    From50kTo75k,
+    From75kTo100k,
+    From100kTo150k,
+    Over150k,
+}
+impl IncomeBracket {
+    /// All brackets, from the lowest to the highest.
+    pub const ALL: [IncomeBracket; 6] = [
+        IncomeBracket::Under25k,
+        IncomeBracket::From25kTo50k,
+        IncomeBracket::From50kTo75k,
+        IncomeBracket::From75kTo100k,
+        IncomeBracket::From100kTo150k,
+        IncomeBracket::Over150k,
+    ];
+
+    /// Returns the bracket of the annual income in US dollars.
+    pub fn for_income(income_usd: u32) -> Self {
+        match income_usd {
+            0..25_000 => Self::Under25k,
+            25_000..50_000 => Self::From25kTo50k,
+            50_000..75_000 => Self::From50kTo75k,
+            75_000..100_000 => Self::From75kTo100k,
+            100_000..150_000 => Self::From100kTo150k,
+            _ => Self::Over150k,
+        }
+    }
+}
+impl std::fmt::Display for IncomeBracket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let range = match self {
+            Self::Under25k => "under $25,000",
+            Self::From25kTo50k => "$25,000 to $49,999",
+            Self::From50kTo75k => "$50,000 to $74,999",
+            Self::From75kTo100k => "$75,000 to $99,999",
+            Self::From100kTo150k => "$100,000 to $149,999",
+            Self::Over150k => "$150,000 or more",
+        };
+        write!(f, "{range}")
+    }
+}
+
+/// The finances of an adult `Person`. Amounts are whole units of the [currency](Country::currency)
+/// of the country, and older `Person`s tend to have higher incomes, savings and credit scores.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FinancialProfile {
+    pub country: Country,
+    /// The FICO credit score, between 300 and 850.
+    pub credit_score: u16,
+    pub annual_income: u32,
+    pub checking_balance: i64,
+    pub savings_balance: i64,
+    /// The account in countries using IBANs.
+    pub bank_account: Option<BankAccount>,
+    /// The account in the United States.
+    pub us_bank_account: Option<UsBankAccount>,
+}
+impl FinancialProfile {
+    /// Creates a random `FinancialProfile` that is plausible for the given age and country.
+    /// The bank accounts are held by "Account Holder".
+    pub fn random(age: u32, country: Country) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), age, country)
+    }
+
+    /// Same as [`FinancialProfile::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, age: u32, country: Country) -> Self {
+        // Incomes peak in the fifties and are log-normally distributed around the median.
+        let median_income_usd = match age {
+            0..25 => 30_000.0,
+            25..35 => 50_000.0,
+            35..55 => 65_000.0,
+            55..65 => 60_000.0,
+            _ => 40_000.0,
+        };
+        let income_usd = sample_normal(rng, f64::ln(median_income_usd), 0.5).exp();
+        let years_of_credit = age.saturating_sub(18).min(50) as f64;
+        let credit_score = sample_normal(rng, 600.0 + 3.0 * years_of_credit, 60.0)
+            .round()
+            .clamp(MIN_CREDIT_SCORE as f64, MAX_CREDIT_SCORE as f64);
+        let checking_usd = income_usd / 12.0 * sample_normal(rng, 0.0, 0.8).exp();
+        let savings_usd = if rng.gen_bool(0.25) {
+            0.0
+        } else {
+            income_usd * years_of_credit / 25.0 * sample_normal(rng, 0.0, 1.0).exp()
+        };
+
+        let rate = country.usd_exchange_rate();
+        let bank_account = BankAccount::random_custom_rng(rng, country);
+        let us_bank_account =
+            (country == Country::UnitedStates).then(|| UsBankAccount::random_custom_rng(rng));
+        Self {
+            country,
+            credit_score: credit_score as u16,
+            annual_income: (income_usd * rate).round() as u32,
+            checking_balance: (checking_usd * rate).round() as i64,
+            savings_balance: (savings_usd * rate).round() as i64,
+            bank_account,
+            us_bank_account,
+        }
+    }
+
+    /// Returns the bracket of the annual income, converted to US dollars.
+    pub fn income_bracket(&self) -> IncomeBracket {
+        IncomeBracket::for_income(
+            (self.annual_income as f64 / self.country.usd_exchange_rate()).round() as u32,
+        )
+    }
+
+    /// Returns the sum of the account balances.
+    pub fn net_balance(&self) -> i64 {
+        self.checking_balance + self.savings_balance
+    }
+}
+
+impl Country {
+    /// Returns the ISO 4217 code of the currency, like "USD".
+    pub fn currency(self) -> &'static str {
+        match self {
+            Self::UnitedStates => "USD",
+            Self::Canada => "CAD",
+            Self::UnitedKingdom => "GBP",
+            Self::Germany | Self::France | Self::Spain | Self::Italy | Self::Netherlands => "EUR",
+            Self::Australia => "AUD",
+            Self::Japan => "JPY",
+        }
+    }
+
+    /// Returns the approximate number of units of the currency a US dollar buys.
+    pub(crate) fn usd_exchange_rate(self) -> f64 {
+        match self {
+            Self::UnitedStates => 1.0,
+            Self::Canada => 1.35,
+            Self::UnitedKingdom => 0.8,
+            Self::Germany | Self::France | Self::Spain | Self::Italy | Self::Netherlands => 0.9,
+            Self::Australia => 1.5,
+            Self::Japan => 150.0,
+        }
+    }
+}
+
+impl Person {
+    /// Returns the country whose currency the `Person` is paid in: the one of their address, or else
+    /// of their nationality, or else of their phone number, falling back to the United States.
+    pub(crate) fn financial_country(&self) -> Country {
+        self.address
+            .as_ref()
+            .map(|address| address.country)
+            .or(self.get_nationality())
+            .or(self.phone_number.as_ref().map(PhoneNumber::country))
+            .unwrap_or(Country::UnitedStates)
+    }
+
+    /// Sets the holder of the bank accounts to the `Person`'s full name, after it changed.
+    pub(crate) fn update_account_holders(&mut self) {
+        let holder = self.get_full_name();
+        if let Some(profile) = &mut self.financial_profile {
+            if let Some(account) = &mut profile.bank_account {
+                account.holder = holder.clone();
+            }
+            if let Some(account) = &mut profile.us_bank_account {
+                account.holder = holder;
+            }
+        }
+    }
+}
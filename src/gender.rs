@@ -0,0 +1,20 @@
+/// The gender of a `Person`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Gender {
+    Female,
+    Male,
+    NonBinary,
+}
+impl Gender {
+    /// All genders.
+    pub const ALL: [Gender; 3] = [Gender::Female, Gender::Male, Gender::NonBinary];
+}
+impl std::fmt::Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Female => write!(f, "female"),
+            Self::Male => write!(f, "male"),
+            Self::NonBinary => write!(f, "non-binary"),
+        }
+    }
+}
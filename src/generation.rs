@@ -0,0 +1,81 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+/// A generational cohort, defined by birth year as used by the Pew Research Center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Generation {
+    /// Born 1901 to 1927.
+    Greatest,
+    /// Born 1928 to 1945.
+    Silent,
+    /// Born 1946 to 1964.
+    Boomer,
+    /// Born 1965 to 1980.
+    GenX,
+    /// Born 1981 to 1996.
+    Millennial,
+    /// Born 1997 to 2012.
+    GenZ,
+    /// Born 2013 or later.
+    GenAlpha,
+}
+impl Generation {
+    /// All generations, oldest first.
+    pub const ALL: [Generation; 7] = [
+        Generation::Greatest,
+        Generation::Silent,
+        Generation::Boomer,
+        Generation::GenX,
+        Generation::Millennial,
+        Generation::GenZ,
+        Generation::GenAlpha,
+    ];
+
+    /// Returns the first and last birth year of the generation. The last year of [`Generation::GenAlpha`]
+    /// is `None`, since it is still being born.
+    pub fn birth_years(self) -> (i32, Option<i32>) {
+        match self {
+            Self::Greatest => (1901, Some(1927)),
+            Self::Silent => (1928, Some(1945)),
+            Self::Boomer => (1946, Some(1964)),
+            Self::GenX => (1965, Some(1980)),
+            Self::Millennial => (1981, Some(1996)),
+            Self::GenZ => (1997, Some(2012)),
+            Self::GenAlpha => (2013, None),
+        }
+    }
+
+    /// Returns the generation of someone born in the given year, or `None` for years before 1901.
+    /// ## Example
+    /// ```rust
+    /// use person::Generation;
+    /// assert_eq!(Generation::from_birth_year(1990), Some(Generation::Millennial));
+    /// assert_eq!(Generation::from_birth_year(1800), None);
+    /// ```
+    pub fn from_birth_year(year: i32) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|generation| generation.birth_years().0 <= year)
+    }
+
+    /// Returns the range of dates of birth in this generation, ending at `now` at the latest.
+    pub(crate) fn dob_range(self, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        let (first, last) = self.birth_years();
+        let start_of_year = |year| Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+        let end = last.map_or(now, |last| start_of_year(last + 1).min(now));
+        (start_of_year(first), end)
+    }
+}
+impl std::fmt::Display for Generation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Greatest => write!(f, "Greatest Generation"),
+            Self::Silent => write!(f, "Silent Generation"),
+            Self::Boomer => write!(f, "Baby Boomers"),
+            Self::GenX => write!(f, "Generation X"),
+            Self::Millennial => write!(f, "Millennials"),
+            Self::GenZ => write!(f, "Generation Z"),
+            Self::GenAlpha => write!(f, "Generation Alpha"),
+        }
+    }
+}
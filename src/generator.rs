@@ -0,0 +1,510 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, Duration, Months, Utc};
+use rand::{rngs::ThreadRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    education, interests, language, list, popularity, random_date_between, religion, Address,
+    AgeDistribution, Clock, Country, DomainPool, FinancialProfile, Gender, GenerationConfig,
+    HealthRecord, Locale, MaritalStatus, Occupation, Origin, Person, PersonError, Personality,
+    PhoneNumber, Physique, PlaceOfBirth, SeededRng, Suffix, SystemClock, Title,
+};
+
+/// A list of names to pick from when generating a `Person`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameList {
+    /// One of the lists shipped with this crate.
+    Builtin(&'static [&'static str]),
+    /// A user supplied list.
+    Custom(Vec<String>),
+}
+impl NameList {
+    /// The built-in list of first names.
+    pub fn first_names() -> Self {
+        Self::Builtin(&list::NAMES)
+    }
+
+    /// The built-in list of female first names.
+    pub fn female_first_names() -> Self {
+        Self::Builtin(&list::FEMALE_NAMES)
+    }
+
+    /// The built-in list of male first names.
+    pub fn male_first_names() -> Self {
+        Self::Builtin(&list::MALE_NAMES)
+    }
+
+    /// The built-in list of gender-neutral first names.
+    pub fn unisex_first_names() -> Self {
+        Self::Builtin(&list::UNISEX_NAMES)
+    }
+
+    /// The built-in list of surnames.
+    pub fn last_names() -> Self {
+        Self::Builtin(&list::SURNAMES)
+    }
+
+    /// The built-in list of surname particles, like "van der" or "de la".
+    pub fn surname_particles() -> Self {
+        Self::Builtin(&list::SURNAME_PARTICLES)
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Builtin(names) => names.len(),
+            Self::Custom(names) => names.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the list contains the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::Builtin(names) => names.contains(&name),
+            Self::Custom(names) => names.iter().any(|n| n == name),
+        }
+    }
+
+    /// Picks a random name from the list, returns `None` if the list is empty.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&str> {
+        match self {
+            Self::Builtin(names) => names.choose(rng).copied(),
+            Self::Custom(names) => names.choose(rng).map(String::as_str),
+        }
+    }
+}
+impl<S: Into<String>> FromIterator<S> for NameList {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::Custom(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A reusable generator that owns its random number generator and configuration.
+/// ## Example
+/// ```rust
+/// use person::{NameList, PersonGenerator};
+/// let mut generator = PersonGenerator::from_seed(1)
+///     .with_middle_name_probability(0.0)
+///     .with_last_names(NameList::from_iter(["Doe", "Roe"]));
+/// let persons = generator.generate_n(1000);
+/// assert_eq!(persons.len(), 1000);
+/// assert!(persons.iter().all(|p| p.get_middle_name().is_none()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PersonGenerator<R = ThreadRng> {
+    rng: R,
+    dob_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    age_distribution: Option<AgeDistribution>,
+    config: GenerationConfig,
+    clock: Arc<dyn Clock>,
+}
+impl PersonGenerator<ThreadRng> {
+    /// Creates a new `PersonGenerator` using the thread-local random number generator.
+    pub fn new() -> Self {
+        Self::with_rng(rand::thread_rng())
+    }
+}
+impl Default for PersonGenerator<ThreadRng> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PersonGenerator<SeededRng> {
+    /// Creates a new `PersonGenerator` that always generates the same `Person`s for the same seed.
+    pub fn from_seed(seed: u64) -> Self {
+        Self::with_rng(SeededRng::seed_from_u64(seed))
+    }
+}
+impl<R: Rng> PersonGenerator<R> {
+    /// Creates a new `PersonGenerator` using the given random number generator.
+    /// By default, `Person`s are between 0 and 100 years old and there is a 50% chance of a middle name.
+    pub fn with_rng(rng: R) -> Self {
+        Self {
+            rng,
+            dob_range: None,
+            age_distribution: None,
+            config: GenerationConfig::default(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the date of birth range, replacing any age distribution.
+    pub fn with_dob_range(mut self, min: DateTime<Utc>, max: DateTime<Utc>) -> Self {
+        self.dob_range = Some((min, max));
+        self.age_distribution = None;
+        self
+    }
+
+    /// Picks ages from the given distribution instead of a uniform date of birth range,
+    /// see [`AgeDistribution`]. This replaces any date of birth range.
+    pub fn with_age_distribution(mut self, distribution: AgeDistribution) -> Self {
+        self.age_distribution = Some(distribution);
+        self.dob_range = None;
+        self
+    }
+
+    /// Sets the chance (between 0.0 and 1.0) of a `Person` having a middle name.
+    pub fn with_middle_name_probability(mut self, probability: f64) -> Self {
+        self.config.middle_name_probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the config used for generating `Person`s and their usernames.
+    pub fn with_config(mut self, config: GenerationConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the list first and middle names are picked from.
+    pub fn with_first_names(mut self, names: NameList) -> Self {
+        self.config.first_names = names;
+        self
+    }
+
+    pub fn with_last_names(mut self, names: NameList) -> Self {
+        self.config.last_names = names;
+        self
+    }
+
+    /// Sets the domains used by [`PersonGenerator::generate_email`].
+    pub fn with_email_domains(mut self, domains: DomainPool) -> Self {
+        self.config.email_domains = domains;
+        self
+    }
+
+    /// Sets the clock used as the reference for the default date of birth range and ages,
+    /// e.g. a [`FixedClock`](crate::FixedClock) to freeze time in tests.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{FixedClock, PersonGenerator};
+    /// let now = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    /// let mut generator = PersonGenerator::from_seed(3).with_clock(FixedClock(now));
+    /// let person = generator.generate();
+    /// assert!(person.get_date_of_birth() <= now);
+    /// assert!(generator.age_of(&person) <= 100);
+    /// ```
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Returns the current time according to this generator's clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// Returns the age of the given `Person` according to this generator's clock.
+    pub fn age_of(&self, person: &Person) -> u32 {
+        person.age_at(self.clock.now())
+    }
+
+    /// Gives access to the underlying random number generator, e.g. to generate usernames.
+    pub fn rng(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Generates a random username for the given `Person` using this generator's config and random number generator.
+    pub fn generate_username(&mut self, person: &Person) -> String {
+        person.random_username_at(&mut self.rng, &self.config, self.clock.now())
+    }
+
+    /// Generates a random email address for the given `Person` using this generator's config and random number generator.
+    pub fn generate_email(&mut self, person: &Person) -> String {
+        person.random_email_at(&mut self.rng, &self.config, self.clock.now())
+    }
+
+    /// Generates a single `Person`.
+    /// ## Panics
+    /// Panics if the configuration is invalid, see [`PersonGenerator::try_generate`].
+    pub fn generate(&mut self) -> Person {
+        self.try_generate().unwrap()
+    }
+
+    /// Same as [`PersonGenerator::generate`], but returns an error if the date of birth range is invalid
+    /// or a name list is empty.
+    pub fn try_generate(&mut self) -> Result<Person, PersonError> {
+        let date_of_birth = match &self.age_distribution {
+            Some(distribution) => {
+                distribution.sample_date_of_birth(&mut self.rng, self.clock.now())?
+            }
+            None => {
+                let (min, max) = self.dob_range.unwrap_or_else(|| {
+                    let now = self.clock.now();
+                    (now - Duration::days(366 * 100), now)
+                });
+                random_date_between(&mut self.rng, min, max)?
+            }
+        };
+        generate_person(&mut self.rng, &self.config, date_of_birth, self.clock.now())
+    }
+
+    /// Generates `n` `Person`s.
+    pub fn generate_n(&mut self, n: usize) -> Vec<Person> {
+        self.try_generate_n(n).unwrap()
+    }
+
+    /// Same as [`PersonGenerator::generate_n`], but returns an error instead of panicking.
+    pub fn try_generate_n(&mut self, n: usize) -> Result<Vec<Person>, PersonError> {
+        let mut persons = Vec::with_capacity(n);
+        for _ in 0..n {
+            persons.push(self.try_generate()?);
+        }
+        Ok(persons)
+    }
+}
+
+/// Returns `true` with the probability, which is clamped to between 0 and 1. Never uses the
+/// random number generator for probabilities of 0 or less, or NaN.
+pub(crate) fn chance<R: Rng + ?Sized>(rng: &mut R, probability: f64) -> bool {
+    probability > 0.0 && rng.gen_bool(probability.min(1.0))
+}
+
+/// The oldest age a deceased `Person` can die at.
+const MAX_LIFESPAN_YEARS: u32 = 110;
+
+/// Generates a random `Person` with the given date of birth, following the given config.
+/// `now` is the latest possible date of death.
+pub(crate) fn generate_person<R: Rng + ?Sized>(
+    rng: &mut R,
+    config: &GenerationConfig,
+    date_of_birth: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<Person, PersonError> {
+    let mut person = generate_identity(rng, config, date_of_birth, now)?;
+    generate_details(rng, config, &mut person, now);
+    Ok(person)
+}
+
+/// Generates the names, dates, contact details and nationalities of a random `Person` with the
+/// given date of birth, following the given config, without the attributes derived from them.
+/// `now` is the latest possible date of death.
+pub(crate) fn generate_identity<R: Rng + ?Sized>(
+    rng: &mut R,
+    config: &GenerationConfig,
+    date_of_birth: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<Person, PersonError> {
+    let gender = config.genders.choose(rng).copied();
+    let locale_country = config.locale.map(Locale::country);
+    let nationality = locale_country.or_else(|| config.nationalities.choose(rng).copied());
+    let origin = match locale_country {
+        Some(country) => country.origin(),
+        None => config
+            .origins
+            .choose(rng)
+            .copied()
+            .or_else(|| nationality.and_then(Country::origin)),
+    };
+    let origin_names = origin.map(|origin| {
+        (
+            NameList::Builtin(origin.first_names(rng, gender)),
+            NameList::Builtin(origin.surnames()),
+        )
+    });
+    let last_names = match &origin_names {
+        Some((_, surnames)) => surnames,
+        None => &config.last_names,
+    };
+    if last_names.is_empty() {
+        return Err(PersonError::EmptyLastNames);
+    }
+    let first_names = match (gender, &origin_names) {
+        _ if config.gender_neutral_names => &config.unisex_first_names,
+        (_, Some((first_names, _))) => first_names,
+        (Some(Gender::Female), None) => &config.female_first_names,
+        (Some(Gender::Male), None) => &config.male_first_names,
+        (Some(Gender::NonBinary), None) => &config.unisex_first_names,
+        (None, None) => &config.first_names,
+    };
+    if first_names.is_empty() {
+        return Err(PersonError::EmptyFirstNames);
+    }
+    let first_name = if config.birth_decade_names
+        && origin.is_none()
+        && !config.gender_neutral_names
+        && gender != Some(Gender::NonBinary)
+    {
+        popularity::choose_first_name(rng, date_of_birth.year(), gender).to_string()
+    } else {
+        first_names.choose(rng).unwrap().to_string()
+    };
+    let mononym = chance(rng, config.mononym_probability);
+    // Mononyms are a single name, so they never get middle names.
+    let middle_names = if mononym {
+        vec![]
+    } else {
+        (0..config.middle_name_count(rng))
+            .map(|_| first_names.choose(rng).unwrap().to_string())
+            .collect()
+    };
+    let last_name = if mononym {
+        String::new()
+    } else {
+        let mut last_name = last_names.choose(rng).unwrap().to_string();
+        if chance(rng, config.hyphenated_last_name_probability) {
+            last_name = format!("{last_name}-{}", last_names.choose(rng).unwrap());
+        }
+        if chance(rng, config.surname_particle_probability) {
+            if let Some(particle) = config.surname_particles.choose(rng) {
+                last_name = format!("{particle} {last_name}");
+            }
+        }
+        last_name
+    };
+    let title = if chance(rng, config.title_probability) {
+        Title::for_gender(gender).choose(rng).copied()
+    } else {
+        None
+    };
+    let suffix = if !mononym && chance(rng, config.suffix_probability) {
+        Suffix::ALL.choose(rng).copied()
+    } else {
+        None
+    };
+    let former_names = if !mononym && chance(rng, config.former_name_probability) {
+        vec![last_names.choose(rng).unwrap().to_string()]
+    } else {
+        vec![]
+    };
+    let date_of_death = if chance(rng, config.deceased_probability) {
+        let latest = date_of_birth
+            .checked_add_months(Months::new(12 * MAX_LIFESPAN_YEARS))
+            .map_or(now, |latest| latest.min(now));
+        random_date_between(rng, date_of_birth, latest).ok()
+    } else {
+        None
+    };
+    let phone_number = if chance(rng, config.phone_number_probability) {
+        locale_country
+            .or_else(|| config.phone_countries.choose(rng).copied())
+            .map(|country| PhoneNumber::random_custom_rng(rng, country))
+    } else {
+        None
+    };
+    let address = if chance(rng, config.address_probability) {
+        locale_country
+            .or_else(|| config.address_countries.choose(rng).copied())
+            .map(|country| Address::random_custom_rng(rng, country))
+    } else {
+        None
+    };
+    let mut nationalities: Vec<Country> = nationality.into_iter().collect();
+    if nationality.is_some() && chance(rng, config.dual_citizenship_probability) {
+        let others: Vec<Country> = Country::ALL
+            .into_iter()
+            .filter(|&country| Some(country) != nationality)
+            .collect();
+        nationalities.extend(others.choose(rng));
+    }
+    Ok(Person {
+        date_of_birth,
+        first_name,
+        middle_names,
+        last_name,
+        gender,
+        pronouns: config.pronouns.choose(rng).copied(),
+        title,
+        suffix,
+        former_names,
+        origin,
+        date_of_death,
+        physique: None,
+        phone_number,
+        address,
+        place_of_birth: None,
+        nationalities,
+        financial_profile: None,
+        health_record: None,
+        occupation: None,
+        education: vec![],
+        interests: vec![],
+        skills: vec![],
+        personality: None,
+        marital_status: None,
+        languages: vec![],
+        religion: None,
+    })
+}
+
+/// Generates the attributes of the `Person` that depend on their dates, names, address or
+/// nationalities, following the given config. `now` is the reference date for their age.
+pub(crate) fn generate_details<R: Rng + ?Sized>(
+    rng: &mut R,
+    config: &GenerationConfig,
+    person: &mut Person,
+    now: DateTime<Utc>,
+) {
+    if chance(rng, config.physique_probability) {
+        person.physique = Some(Physique::random_custom_rng(
+            rng,
+            person.age_at(now),
+            person.gender,
+        ));
+    }
+    if chance(rng, config.place_of_birth_probability) {
+        let country = person
+            .get_nationality()
+            .or(person.origin.and_then(Origin::country))
+            .or(person.address.as_ref().map(|address| address.country))
+            .or(person.phone_number.as_ref().map(PhoneNumber::country))
+            .unwrap_or(Country::UnitedStates);
+        person.place_of_birth = Some(PlaceOfBirth::random_custom_rng(rng, country));
+    }
+    if person.age_at(now) >= 18 && chance(rng, config.financial_profile_probability) {
+        let country = person.financial_country();
+        person.financial_profile = Some(FinancialProfile::random_custom_rng(
+            rng,
+            person.age_at(now),
+            country,
+        ));
+        person.update_account_holders();
+    }
+    if chance(rng, config.health_record_probability) {
+        person.health_record = Some(HealthRecord::random_custom_rng(rng, person.age_at(now)));
+    }
+    if chance(rng, config.occupation_probability) {
+        person.occupation = Occupation::random_custom_rng(rng, person.age_at(now));
+    }
+    if chance(rng, config.education_probability) {
+        person.education = education::random_education_history(
+            rng,
+            person.date_of_birth,
+            person.date_of_death.unwrap_or(now),
+            person.financial_country(),
+            config.accelerated_education,
+        );
+    }
+    if chance(rng, config.interests_probability) {
+        (person.interests, person.skills) =
+            interests::random_interests_and_skills(rng, person.age_at(now));
+    }
+    if chance(rng, config.personality_probability) {
+        person.personality = Some(Personality::random_custom_rng(rng, person.age_at(now)));
+    }
+    if chance(rng, config.marital_status_probability) {
+        person.marital_status = Some(MaritalStatus::random_for_age_custom_rng(
+            rng,
+            person.age_at(now),
+        ));
+    }
+    if chance(rng, config.languages_probability) {
+        person.languages = language::random_languages(
+            rng,
+            person.age_at(now),
+            &person.nationalities,
+            person.origin,
+            person.financial_country(),
+        );
+    }
+    if chance(rng, config.religion_probability) {
+        person.religion = Some(religion::random_religion(
+            rng,
+            person.financial_country(),
+            person.origin,
+        ));
+    }
+}
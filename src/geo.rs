@@ -0,0 +1,118 @@
+use rand::Rng;
+
+use crate::Country;
+
+/// The mean radius of the earth in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// A point on earth, stored with a precision of a millionth of a degree (about 10 cm),
+/// so it can be compared and hashed.
+/// ## Example
+/// ```rust
+/// use person::Coordinates;
+/// let berlin = Coordinates::new(52.52, 13.405);
+/// let paris = Coordinates::new(48.8566, 2.3522);
+/// assert_eq!(berlin.latitude(), 52.52);
+/// assert!((berlin.distance_km(paris) - 878.0).abs() < 5.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coordinates {
+    latitude_e6: i32,
+    longitude_e6: i32,
+}
+impl Coordinates {
+    /// Creates coordinates from degrees, clamping the latitude to ±90 and the longitude to ±180.
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude_e6: (latitude.clamp(-90.0, 90.0) * 1e6).round() as i32,
+            longitude_e6: (longitude.clamp(-180.0, 180.0) * 1e6).round() as i32,
+        }
+    }
+
+    /// Creates random coordinates within the bounding box.
+    pub fn random_in(bounds: &BoundingBox) -> Self {
+        Self::random_in_custom_rng(&mut rand::thread_rng(), bounds)
+    }
+
+    /// Same as [`Coordinates::random_in`], but uses the given random number generator.
+    pub fn random_in_custom_rng<R: Rng + ?Sized>(rng: &mut R, bounds: &BoundingBox) -> Self {
+        let latitude = rng.gen_range(bounds.min_latitude..=bounds.max_latitude);
+        let longitude = rng.gen_range(bounds.min_longitude..=bounds.max_longitude);
+        Self::new(latitude, longitude)
+    }
+
+    /// Creates random coordinates within the [bounding box](Country::bounding_box) of the country.
+    pub fn random(country: Country) -> Self {
+        Self::random_in(&country.bounding_box())
+    }
+
+    /// Same as [`Coordinates::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Self {
+        Self::random_in_custom_rng(rng, &country.bounding_box())
+    }
+
+    /// Returns the latitude in degrees, positive north of the equator.
+    pub fn latitude(self) -> f64 {
+        self.latitude_e6 as f64 / 1e6
+    }
+
+    /// Returns the longitude in degrees, positive east of Greenwich.
+    pub fn longitude(self) -> f64 {
+        self.longitude_e6 as f64 / 1e6
+    }
+
+    /// Returns the great-circle distance to the other coordinates in kilometers.
+    pub fn distance_km(self, other: Coordinates) -> f64 {
+        let (lat1, lat2) = (self.latitude().to_radians(), other.latitude().to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude() - self.longitude()).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+impl std::fmt::Display for Coordinates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.6}, {:.6}", self.latitude(), self.longitude())
+    }
+}
+
+/// An area between two latitudes and two longitudes, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    pub min_longitude: f64,
+    pub max_longitude: f64,
+}
+impl BoundingBox {
+    /// Returns whether the coordinates lie within the bounding box.
+    pub fn contains(&self, coordinates: Coordinates) -> bool {
+        (self.min_latitude..=self.max_latitude).contains(&coordinates.latitude())
+            && (self.min_longitude..=self.max_longitude).contains(&coordinates.longitude())
+    }
+}
+
+impl Country {
+    /// Returns a bounding box around the country's main territory, leaving out overseas areas
+    /// like Alaska, Hawaii or the French overseas departments.
+    pub fn bounding_box(self) -> BoundingBox {
+        let (min_latitude, max_latitude, min_longitude, max_longitude) = match self {
+            Self::UnitedStates => (24.5, 49.4, -124.8, -66.9),
+            Self::Canada => (41.7, 83.1, -141.0, -52.6),
+            Self::UnitedKingdom => (49.9, 58.7, -8.2, 1.8),
+            Self::Germany => (47.3, 55.1, 5.9, 15.0),
+            Self::France => (41.3, 51.1, -5.1, 9.6),
+            Self::Spain => (36.0, 43.8, -9.3, 4.3),
+            Self::Italy => (36.6, 47.1, 6.6, 18.5),
+            Self::Netherlands => (50.8, 53.6, 3.4, 7.2),
+            Self::Australia => (-43.6, -10.7, 113.3, 153.6),
+            Self::Japan => (24.4, 45.5, 122.9, 145.8),
+        };
+        BoundingBox {
+            min_latitude,
+            max_latitude,
+            min_longitude,
+            max_longitude,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use chrono::{Months, Utc};
+use rand::Rng;
+
+use crate::{random_digits, Person, Title};
+
+/// The youngest and oldest age of a generated primary physician.
+const PHYSICIAN_AGES: (u32, u32) = (30, 70);
+
+/// An allergy recorded in a `HealthRecord`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Allergy {
+    Penicillin,
+    Sulfonamides,
+    Peanuts,
+    TreeNuts,
+    Shellfish,
+    Eggs,
+    Milk,
+    Latex,
+    Pollen,
+    DustMites,
+    BeeStings,
+}
+impl Allergy {
+    /// All allergies.
+    pub const ALL: [Allergy; 11] = [
+        Allergy::Penicillin,
+        Allergy::Sulfonamides,
+        Allergy::Peanuts,
+        Allergy::TreeNuts,
+        Allergy::Shellfish,
+        Allergy::Eggs,
+        Allergy::Milk,
+        Allergy::Latex,
+        Allergy::Pollen,
+        Allergy::DustMites,
+        Allergy::BeeStings,
+    ];
+
+    /// Returns the approximate share of people of the given age with the allergy.
+    /// Children often outgrow egg and milk allergies.
+    pub fn prevalence(self, age: u32) -> f64 {
+        match self {
+            Self::Penicillin => 0.08,
+            Self::Sulfonamides => 0.03,
+            Self::Peanuts | Self::Shellfish => 0.02,
+            Self::TreeNuts | Self::Latex | Self::BeeStings => 0.01,
+            Self::Eggs | Self::Milk if age < 6 => 0.02,
+            Self::Eggs | Self::Milk => 0.003,
+            Self::Pollen => 0.15,
+            Self::DustMites => 0.1,
+        }
+    }
+}
+impl std::fmt::Display for Allergy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Penicillin => "penicillin",
+            Self::Sulfonamides => "sulfonamides",
+            Self::Peanuts => "peanuts",
+            Self::TreeNuts => "tree nuts",
+            Self::Shellfish => "shellfish",
+            Self::Eggs => "eggs",
+            Self::Milk => "milk",
+            Self::Latex => "latex",
+            Self::Pollen => "pollen",
+            Self::DustMites => "dust mites",
+            Self::BeeStings => "bee stings",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A chronic medical condition recorded in a `HealthRecord`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Condition {
+    Asthma,
+    Eczema,
+    Adhd,
+    Anxiety,
+    Depression,
+    Migraine,
+    Hypertension,
+    HighCholesterol,
+    Type2Diabetes,
+    Hypothyroidism,
+    Arthritis,
+    Osteoporosis,
+    Copd,
+    CoronaryArteryDisease,
+}
+impl Condition {
+    /// All conditions.
+    pub const ALL: [Condition; 14] = [
+        Condition::Asthma,
+        Condition::Eczema,
+        Condition::Adhd,
+        Condition::Anxiety,
+        Condition::Depression,
+        Condition::Migraine,
+        Condition::Hypertension,
+        Condition::HighCholesterol,
+        Condition::Type2Diabetes,
+        Condition::Hypothyroidism,
+        Condition::Arthritis,
+        Condition::Osteoporosis,
+        Condition::Copd,
+        Condition::CoronaryArteryDisease,
+    ];
+
+    /// Returns the approximate share of people of the given age diagnosed with the condition.
+    /// Conditions of old age are never diagnosed in children, and become more common every year.
+    pub fn prevalence(self, age: u32) -> f64 {
+        let rising = |from: u32, per_year: f64, max: f64| {
+            (age.saturating_sub(from) as f64 * per_year).min(max)
+        };
+        match self {
+            Self::Asthma => 0.08,
+            Self::Eczema if age < 12 => 0.12,
+            Self::Eczema => 0.03,
+            Self::Adhd if (5..=40).contains(&age) => 0.06,
+            Self::Adhd => 0.0,
+            Self::Anxiety if age >= 12 => 0.1,
+            Self::Depression if age >= 14 => 0.08,
+            Self::Migraine if age >= 10 => 0.1,
+            Self::Anxiety | Self::Depression | Self::Migraine => 0.0,
+            Self::Hypertension => rising(30, 0.012, 0.65),
+            Self::HighCholesterol => rising(35, 0.01, 0.5),
+            Self::Type2Diabetes => rising(35, 0.006, 0.27),
+            Self::Hypothyroidism if age >= 30 => 0.05,
+            Self::Hypothyroidism => 0.0,
+            Self::Arthritis => rising(45, 0.012, 0.5),
+            Self::Osteoporosis => rising(55, 0.006, 0.2),
+            Self::Copd => rising(45, 0.003, 0.12),
+            Self::CoronaryArteryDisease => rising(50, 0.005, 0.2),
+        }
+    }
+}
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Asthma => "asthma",
+            Self::Eczema => "eczema",
+            Self::Adhd => "ADHD",
+            Self::Anxiety => "anxiety disorder",
+            Self::Depression => "depression",
+            Self::Migraine => "migraine",
+            Self::Hypertension => "hypertension",
+            Self::HighCholesterol => "high cholesterol",
+            Self::Type2Diabetes => "type 2 diabetes",
+            Self::Hypothyroidism => "hypothyroidism",
+            Self::Arthritis => "arthritis",
+            Self::Osteoporosis => "osteoporosis",
+            Self::Copd => "COPD",
+            Self::CoronaryArteryDisease => "coronary artery disease",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A minimal medical record of a `Person`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HealthRecord {
+    /// The medical record number, eight digits like "04127735".
+    pub medical_record_number: String,
+    /// A random adult doctor with the title "Dr.".
+    pub primary_physician: Box<Person>,
+    pub allergies: Vec<Allergy>,
+    pub conditions: Vec<Condition>,
+}
+impl HealthRecord {
+    /// Creates a random `HealthRecord` with allergies and conditions that are plausible for the age.
+    pub fn random(age: u32) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), age)
+    }
+
+    /// Same as [`HealthRecord::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, age: u32) -> Self {
+        let now = Utc::now();
+        let (min_age, max_age) = PHYSICIAN_AGES;
+        let mut physician = Person::random_with_dob_range_custom_rng(
+            rng,
+            now - Months::new(12 * (max_age + 1)),
+            now - Months::new(12 * min_age),
+        );
+        physician.set_title(Some(Title::Dr));
+
+        let allergies = Allergy::ALL
+            .into_iter()
+            .filter(|allergy| rng.gen_bool(allergy.prevalence(age)))
+            .collect();
+        let conditions = Condition::ALL
+            .into_iter()
+            .filter(|condition| rng.gen_bool(condition.prevalence(age)))
+            .collect();
+        Self {
+            medical_record_number: random_digits(rng, 8),
+            primary_physician: Box::new(physician),
+            allergies,
+            conditions,
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Datelike, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, Person};
+
+/// The age at which `Person`s in the United States become eligible for Medicare.
+const MEDICARE_AGE: u32 = 65;
+/// The letters used in Medicare Beneficiary Identifiers, which leave out S, L, O, I, B and Z.
+const MBI_LETTERS: &[u8] = b"ACDEFGHJKMNPQRTUVWXY";
+/// The letters of Blue Cross Blue Shield member ID prefixes.
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The format of a health insurance member ID, named after the carrier using it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MemberIdFormat {
+    /// A three-letter prefix and nine digits, like "XDP123456789".
+    BlueCross,
+    /// "W" and nine digits, like "W123456789".
+    Aetna,
+    /// Nine digits and a two-digit member suffix, like "12345678900".
+    UnitedHealthcare,
+    /// "U", eight digits and a two-digit member suffix, like "U1234567801".
+    Cigna,
+    /// A Medicare Beneficiary Identifier, like "1EG4TE5MK73".
+    Medicare,
+}
+impl MemberIdFormat {
+    /// All formats.
+    pub const ALL: [MemberIdFormat; 5] = [
+        MemberIdFormat::BlueCross,
+        MemberIdFormat::Aetna,
+        MemberIdFormat::UnitedHealthcare,
+        MemberIdFormat::Cigna,
+        MemberIdFormat::Medicare,
+    ];
+
+    /// The formats of private carriers, i.e. all except Medicare.
+    pub const COMMERCIAL: [MemberIdFormat; 4] = [
+        MemberIdFormat::BlueCross,
+        MemberIdFormat::Aetna,
+        MemberIdFormat::UnitedHealthcare,
+        MemberIdFormat::Cigna,
+    ];
+
+    /// Generates a random member ID of the format for the subscriber of a plan.
+    pub fn random_member_id(self) -> String {
+        self.random_member_id_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`MemberIdFormat::random_member_id`], but uses the given random number generator.
+    pub fn random_member_id_custom_rng<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+        match self {
+            Self::BlueCross => {
+                let prefix: String = (0..3)
+                    .map(|_| *LETTERS.choose(rng).unwrap() as char)
+                    .collect();
+                format!("{prefix}{}", random_digits(rng, 9))
+            }
+            Self::Aetna => format!("W{}", random_digits(rng, 9)),
+            Self::UnitedHealthcare => format!("{}00", random_digits(rng, 9)),
+            Self::Cigna => format!("U{}01", random_digits(rng, 8)),
+            Self::Medicare => {
+                // Positions alternate between digits, letters and either, in a fixed pattern.
+                "NAXNAXNAANN"
+                    .chars()
+                    .enumerate()
+                    .map(|(i, kind)| match kind {
+                        'N' if i == 0 => rng.gen_range(b'1'..=b'9') as char,
+                        'N' => rng.gen_range(b'0'..=b'9') as char,
+                        'A' => *MBI_LETTERS.choose(rng).unwrap() as char,
+                        _ if rng.gen_bool(0.5) => rng.gen_range(b'0'..=b'9') as char,
+                        _ => *MBI_LETTERS.choose(rng).unwrap() as char,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the name of the carrier using the format.
+    pub fn carrier(self) -> &'static str {
+        match self {
+            Self::BlueCross => "Blue Cross Blue Shield",
+            Self::Aetna => "Aetna",
+            Self::UnitedHealthcare => "UnitedHealthcare",
+            Self::Cigna => "Cigna",
+            Self::Medicare => "Medicare",
+        }
+    }
+}
+impl std::fmt::Display for MemberIdFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.carrier())
+    }
+}
+
+/// A health insurance card of a `Person`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InsuranceCard {
+    pub format: MemberIdFormat,
+    pub member_name: String,
+    pub member_date_of_birth: DateTime<Utc>,
+    pub member_id: String,
+    /// The number of the employer's group plan, which Medicare cards don't have.
+    pub group_number: Option<String>,
+    /// The number of the policy with the year it started in, like "PLC-2024-0012345".
+    pub policy_number: String,
+}
+
+impl Person {
+    /// Generates a health insurance card for the `Person`: a Medicare card if they are at least 65,
+    /// or else a card of a random private carrier.
+    /// ## Example
+    /// ```rust
+    /// use person::{MemberIdFormat, Person};
+    /// let person = Person::random_adult();
+    /// let card = person.get_random_insurance_card();
+    /// assert_eq!(card.member_name, person.get_full_name());
+    /// assert_eq!(card.format == MemberIdFormat::Medicare, person.get_age() >= 65);
+    /// ```
+    pub fn get_random_insurance_card(&self) -> InsuranceCard {
+        self.get_random_insurance_card_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_insurance_card`], but uses the given random number generator.
+    pub fn get_random_insurance_card_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> InsuranceCard {
+        let format = if self.get_age() >= MEDICARE_AGE {
+            MemberIdFormat::Medicare
+        } else {
+            *MemberIdFormat::COMMERCIAL.choose(rng).unwrap()
+        };
+        self.get_random_insurance_card_for_custom_rng(rng, format)
+    }
+
+    /// Generates a health insurance card with a member ID of the given format for the `Person`.
+    /// ## Example
+    /// ```rust
+    /// use person::{MemberIdFormat, Person};
+    /// let card = Person::random().get_random_insurance_card_for(MemberIdFormat::Aetna);
+    /// assert!(card.member_id.starts_with('W'));
+    /// assert_eq!(card.member_id.len(), 10);
+    /// assert!(card.group_number.is_some());
+    /// ```
+    pub fn get_random_insurance_card_for(&self, format: MemberIdFormat) -> InsuranceCard {
+        self.get_random_insurance_card_for_custom_rng(&mut rand::thread_rng(), format)
+    }
+
+    /// Same as [`Person::get_random_insurance_card_for`], but uses the given random number generator.
+    pub fn get_random_insurance_card_for_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        format: MemberIdFormat,
+    ) -> InsuranceCard {
+        let member_id = format.random_member_id_custom_rng(rng);
+        let group_number = (format != MemberIdFormat::Medicare).then(|| {
+            let length = rng.gen_range(5..=8);
+            random_digits(rng, length)
+        });
+        let this_year = Utc::now().year();
+        let first_year = (this_year - 10)
+            .max(self.date_of_birth.year())
+            .min(this_year);
+        let year = rng.gen_range(first_year..=this_year);
+        InsuranceCard {
+            format,
+            member_name: self.get_full_name(),
+            member_date_of_birth: self.date_of_birth,
+            member_id,
+            group_number,
+            policy_number: format!("PLC-{year}-{}", random_digits(rng, 7)),
+        }
+    }
+}
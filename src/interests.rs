@@ -0,0 +1,326 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, seq::SliceRandom, Rng};
+
+/// The fewest and most interests a generated `Person` has.
+const INTEREST_COUNT: (usize, usize) = (2, 5);
+/// The fewest and most skills a generated `Person` has.
+const SKILL_COUNT: (usize, usize) = (1, 4);
+
+/// A hobby or interest of a `Person`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Interest {
+    Birdwatching,
+    Chess,
+    Cooking,
+    Cycling,
+    Dancing,
+    Fishing,
+    Gaming,
+    Gardening,
+    Golf,
+    Hiking,
+    Knitting,
+    Music,
+    Painting,
+    Photography,
+    Reading,
+    Running,
+    Skateboarding,
+    Travel,
+    Volunteering,
+    Yoga,
+}
+impl Interest {
+    /// All interests.
+    pub const ALL: [Interest; 20] = [
+        Interest::Birdwatching,
+        Interest::Chess,
+        Interest::Cooking,
+        Interest::Cycling,
+        Interest::Dancing,
+        Interest::Fishing,
+        Interest::Gaming,
+        Interest::Gardening,
+        Interest::Golf,
+        Interest::Hiking,
+        Interest::Knitting,
+        Interest::Music,
+        Interest::Painting,
+        Interest::Photography,
+        Interest::Reading,
+        Interest::Running,
+        Interest::Skateboarding,
+        Interest::Travel,
+        Interest::Volunteering,
+        Interest::Yoga,
+    ];
+
+    /// Returns how popular the interest is at the given age, relative to the others.
+    pub fn weight(self, age: u32) -> f64 {
+        match self {
+            Self::Gaming if age < 30 => 3.0,
+            Self::Gaming if age < 50 => 1.5,
+            Self::Gaming => 0.5,
+            Self::Skateboarding if (10..30).contains(&age) => 1.5,
+            Self::Skateboarding => 0.1,
+            Self::Golf if age < 16 => 0.2,
+            Self::Golf if age < 40 => 0.8,
+            Self::Golf => 2.0,
+            Self::Gardening if age < 30 => 0.3,
+            Self::Gardening if age < 55 => 1.0,
+            Self::Gardening => 2.5,
+            Self::Birdwatching if age < 40 => 0.2,
+            Self::Birdwatching if age < 60 => 1.0,
+            Self::Birdwatching => 1.5,
+            Self::Knitting if age < 50 => 0.5,
+            Self::Knitting => 1.5,
+            Self::Travel | Self::Yoga | Self::Volunteering if age < 14 => 0.2,
+            Self::Travel => 1.5,
+            Self::Running if age >= 60 => 0.4,
+            Self::Running | Self::Yoga => 1.2,
+            _ => 1.0,
+        }
+    }
+
+    /// Picks `count` different interests, weighted by their popularity at the age.
+    /// ## Example
+    /// ```rust
+    /// use person::Interest;
+    /// let interests = Interest::random_for_age(25, 3);
+    /// assert_eq!(interests.len(), 3);
+    /// assert!(interests.iter().all(|interest| interests.iter().filter(|&i| i == interest).count() == 1));
+    /// ```
+    pub fn random_for_age(age: u32, count: usize) -> Vec<Interest> {
+        Self::random_for_age_custom_rng(&mut rand::thread_rng(), age, count)
+    }
+
+    /// Same as [`Interest::random_for_age`], but uses the given random number generator.
+    pub fn random_for_age_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        age: u32,
+        count: usize,
+    ) -> Vec<Interest> {
+        let mut remaining = Self::ALL.to_vec();
+        let mut interests = vec![];
+        while interests.len() < count && !remaining.is_empty() {
+            let weights =
+                WeightedIndex::new(remaining.iter().map(|interest| interest.weight(age))).unwrap();
+            interests.push(remaining.swap_remove(weights.sample(rng)));
+        }
+        interests
+    }
+}
+impl std::fmt::Display for Interest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Birdwatching => "birdwatching",
+            Self::Chess => "chess",
+            Self::Cooking => "cooking",
+            Self::Cycling => "cycling",
+            Self::Dancing => "dancing",
+            Self::Fishing => "fishing",
+            Self::Gaming => "video games",
+            Self::Gardening => "gardening",
+            Self::Golf => "golf",
+            Self::Hiking => "hiking",
+            Self::Knitting => "knitting",
+            Self::Music => "playing music",
+            Self::Painting => "painting",
+            Self::Photography => "photography",
+            Self::Reading => "reading",
+            Self::Running => "running",
+            Self::Skateboarding => "skateboarding",
+            Self::Travel => "traveling",
+            Self::Volunteering => "volunteering",
+            Self::Yoga => "yoga",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A skill of a `Person`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Skill {
+    Accounting,
+    Carpentry,
+    DataAnalysis,
+    FirstAid,
+    GraphicDesign,
+    Guitar,
+    Negotiation,
+    Photography,
+    Piano,
+    Plumbing,
+    Programming,
+    ProjectManagement,
+    PublicSpeaking,
+    Swimming,
+    VideoEditing,
+    Writing,
+}
+impl Skill {
+    /// All skills.
+    pub const ALL: [Skill; 16] = [
+        Skill::Accounting,
+        Skill::Carpentry,
+        Skill::DataAnalysis,
+        Skill::FirstAid,
+        Skill::GraphicDesign,
+        Skill::Guitar,
+        Skill::Negotiation,
+        Skill::Photography,
+        Skill::Piano,
+        Skill::Plumbing,
+        Skill::Programming,
+        Skill::ProjectManagement,
+        Skill::PublicSpeaking,
+        Skill::Swimming,
+        Skill::VideoEditing,
+        Skill::Writing,
+    ];
+
+    /// Returns the youngest age at which `Person`s usually start learning the skill.
+    pub fn min_age(self) -> u32 {
+        match self {
+            Self::Swimming => 4,
+            Self::Piano => 5,
+            Self::Guitar | Self::Photography => 8,
+            Self::Programming | Self::VideoEditing | Self::Writing => 10,
+            Self::GraphicDesign | Self::PublicSpeaking | Self::FirstAid => 12,
+            Self::Carpentry => 14,
+            Self::DataAnalysis | Self::Plumbing => 16,
+            Self::Accounting => 18,
+            Self::ProjectManagement | Self::Negotiation => 20,
+        }
+    }
+}
+impl std::fmt::Display for Skill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Accounting => "accounting",
+            Self::Carpentry => "carpentry",
+            Self::DataAnalysis => "data analysis",
+            Self::FirstAid => "first aid",
+            Self::GraphicDesign => "graphic design",
+            Self::Guitar => "guitar",
+            Self::Negotiation => "negotiation",
+            Self::Photography => "photography",
+            Self::Piano => "piano",
+            Self::Plumbing => "plumbing",
+            Self::Programming => "programming",
+            Self::ProjectManagement => "project management",
+            Self::PublicSpeaking => "public speaking",
+            Self::Swimming => "swimming",
+            Self::VideoEditing => "video editing",
+            Self::Writing => "writing",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// How well a `Person` masters a skill, from the lowest to the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Proficiency {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+impl Proficiency {
+    /// All proficiencies, from the lowest to the highest.
+    pub const ALL: [Proficiency; 4] = [
+        Proficiency::Beginner,
+        Proficiency::Intermediate,
+        Proficiency::Advanced,
+        Proficiency::Expert,
+    ];
+
+    /// Returns the fewest years of practice the proficiency takes.
+    pub fn min_years(self) -> u32 {
+        match self {
+            Self::Beginner => 0,
+            Self::Intermediate => 2,
+            Self::Advanced => 5,
+            Self::Expert => 10,
+        }
+    }
+}
+impl std::fmt::Display for Proficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Beginner => "beginner",
+            Self::Intermediate => "intermediate",
+            Self::Advanced => "advanced",
+            Self::Expert => "expert",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A skill with the proficiency a `Person` has in it. Displays like "programming (advanced)".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SkillProficiency {
+    pub skill: Skill,
+    pub proficiency: Proficiency,
+}
+impl SkillProficiency {
+    /// Picks `count` different skills that `Person`s of the age can have, each with a proficiency
+    /// they had the years to reach since they started learning it, like no expert of 15.
+    /// Returns fewer skills if the age allows fewer.
+    /// ## Example
+    /// ```rust
+    /// use person::{Proficiency, Skill, SkillProficiency};
+    /// let skills = SkillProficiency::random_for_age(12, 4);
+    /// assert_eq!(skills.len(), 4);
+    /// assert!(skills.iter().all(|s| s.skill.min_age() <= 12));
+    /// assert!(skills.iter().all(|s| s.proficiency <= Proficiency::Advanced));
+    /// assert!(SkillProficiency::random_for_age(3, 4).is_empty());
+    /// ```
+    pub fn random_for_age(age: u32, count: usize) -> Vec<SkillProficiency> {
+        Self::random_for_age_custom_rng(&mut rand::thread_rng(), age, count)
+    }
+
+    /// Same as [`SkillProficiency::random_for_age`], but uses the given random number generator.
+    pub fn random_for_age_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        age: u32,
+        count: usize,
+    ) -> Vec<SkillProficiency> {
+        let available: Vec<Skill> = Skill::ALL
+            .into_iter()
+            .filter(|skill| skill.min_age() <= age)
+            .collect();
+        available
+            .choose_multiple(rng, count)
+            .map(|&skill| {
+                let years = age - skill.min_age();
+                let reachable: Vec<Proficiency> = Proficiency::ALL
+                    .into_iter()
+                    .filter(|proficiency| proficiency.min_years() <= years)
+                    .collect();
+                SkillProficiency {
+                    skill,
+                    proficiency: *reachable.choose(rng).unwrap(),
+                }
+            })
+            .collect()
+    }
+}
+impl std::fmt::Display for SkillProficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.skill, self.proficiency)
+    }
+}
+
+/// Generates the interests and skills of a `Person` of the age.
+pub(crate) fn random_interests_and_skills<R: Rng + ?Sized>(
+    rng: &mut R,
+    age: u32,
+) -> (Vec<Interest>, Vec<SkillProficiency>) {
+    let (min_interests, max_interests) = INTEREST_COUNT;
+    let interest_count = rng.gen_range(min_interests..=max_interests);
+    let interests = Interest::random_for_age_custom_rng(rng, age, interest_count);
+    let (min_skills, max_skills) = SKILL_COUNT;
+    let skill_count = rng.gen_range(min_skills..=max_skills);
+    let skills = SkillProficiency::random_for_age_custom_rng(rng, age, skill_count);
+    (interests, skills)
+}
@@ -0,0 +1,46 @@
+use rand::{rngs::ThreadRng, Rng};
+
+use crate::{GenerationConfig, Person};
+
+/// An infinite iterator of random `Person`s, created with [`Person::iter`].
+/// ## Example
+/// ```rust
+/// use person::Person;
+/// let adults: Vec<Person> = Person::iter().filter(|p| p.get_age() >= 18).take(100).collect();
+/// assert_eq!(adults.len(), 100);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PersonIter<R = ThreadRng> {
+    rng: R,
+    config: GenerationConfig,
+}
+impl<R: Rng> PersonIter<R> {
+    /// Creates a new `PersonIter` using the given random number generator.
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            config: GenerationConfig::default(),
+        }
+    }
+
+    /// Sets the config used for generating `Person`s.
+    pub fn with_config(mut self, config: GenerationConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+impl<R: Rng> Iterator for PersonIter<R> {
+    type Item = Person;
+
+    fn next(&mut self) -> Option<Person> {
+        Some(Person::random_with_config_custom_rng(
+            &mut self.rng,
+            &self.config,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+impl<R: Rng> std::iter::FusedIterator for PersonIter<R> {}
@@ -0,0 +1,28 @@
+/// A jurisdiction with its own age of majority, used by [`Person::is_adult`](crate::Person::is_adult).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Jurisdiction {
+    /// The age of majority is 18, as in most countries.
+    #[default]
+    Common,
+    /// The age of majority is 19.
+    Alabama,
+    /// The age of majority is 19.
+    Nebraska,
+    /// The age of majority is 21.
+    Mississippi,
+    /// The age of majority is 19.
+    SouthKorea,
+    /// A custom legal age, e.g. 21 for the drinking age in the United States.
+    Custom(u32),
+}
+impl Jurisdiction {
+    /// Returns the age at which a `Person` is an adult in this jurisdiction.
+    pub fn age_of_majority(self) -> u32 {
+        match self {
+            Self::Common => 18,
+            Self::Alabama | Self::Nebraska | Self::SouthKorea => 19,
+            Self::Mississippi => 21,
+            Self::Custom(age) => age,
+        }
+    }
+}
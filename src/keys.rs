@@ -0,0 +1,140 @@
+use rand::Rng;
+
+use crate::{hash::sha256, Person};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A realistic looking but random PGP key, for testing key management UIs without generating real keys.
+/// ## Example
+/// ```rust
+/// use chrono::Utc;
+/// use person::Person;
+/// let key = Person::from_parts("Jane", None, "Doe", Utc::now()).get_random_pgp_key();
+/// assert_eq!(key.user_id, "Jane Doe <jane.doe@example.com>");
+/// assert_eq!(key.fingerprint().len(), 49);
+/// assert!(key.fingerprint().replace(' ', "").ends_with(&key.key_id()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgpKey {
+    /// The name and email address the key belongs to.
+    pub user_id: String,
+    /// The 160 bit fingerprint of a version 4 key.
+    pub fingerprint: [u8; 20],
+}
+impl PgpKey {
+    /// Returns the fingerprint as ten groups of four hex digits, like "ABCD 1234 …".
+    pub fn fingerprint(&self) -> String {
+        let hex = upper_hex(&self.fingerprint);
+        let groups: Vec<&str> = (0..hex.len()).step_by(4).map(|i| &hex[i..i + 4]).collect();
+        groups.join(" ")
+    }
+
+    /// Returns the long key ID: the last 16 hex digits of the fingerprint.
+    pub fn key_id(&self) -> String {
+        upper_hex(&self.fingerprint[12..])
+    }
+
+    /// Returns the short key ID: the last 8 hex digits of the fingerprint.
+    pub fn short_key_id(&self) -> String {
+        upper_hex(&self.fingerprint[16..])
+    }
+}
+
+/// A realistic looking but random Ed25519 SSH public key.
+/// ## Example
+/// ```rust
+/// use chrono::Utc;
+/// use person::Person;
+/// let key = Person::from_parts("Jane", None, "Doe", Utc::now()).get_random_ssh_key();
+/// assert_eq!(key.comment, "jdoe@jdoe-laptop");
+/// assert!(key.public_key().starts_with("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI"));
+/// assert!(key.public_key().ends_with(" jdoe@jdoe-laptop"));
+/// assert!(key.fingerprint().starts_with("SHA256:"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SshKey {
+    /// The 32 bytes of the public key.
+    pub key: [u8; 32],
+    /// The comment, usually "user@host".
+    pub comment: String,
+}
+impl SshKey {
+    /// Returns the key in the `authorized_keys` format: "ssh-ed25519 <base64> <comment>".
+    pub fn public_key(&self) -> String {
+        format!("ssh-ed25519 {} {}", base64(&self.blob()), self.comment)
+    }
+
+    /// Returns the SHA-256 fingerprint, like `ssh-keygen -l` shows it.
+    pub fn fingerprint(&self) -> String {
+        let hash = base64(&sha256(&self.blob()));
+        format!("SHA256:{}", hash.trim_end_matches('='))
+    }
+
+    /// Returns the wire format of the key: the length-prefixed key type followed by the length-prefixed key.
+    fn blob(&self) -> Vec<u8> {
+        let mut blob = vec![];
+        for part in [&b"ssh-ed25519"[..], &self.key[..]] {
+            blob.extend_from_slice(&(part.len() as u32).to_be_bytes());
+            blob.extend_from_slice(part);
+        }
+        blob
+    }
+}
+
+impl Person {
+    /// Generates a random PGP key with the `Person`'s full name and [mailbox](Person::get_mailbox) address.
+    pub fn get_random_pgp_key(&self) -> PgpKey {
+        self.get_random_pgp_key_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_pgp_key`], but uses the given random number generator.
+    pub fn get_random_pgp_key_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> PgpKey {
+        let mailbox = self.get_mailbox();
+        let address = &mailbox[mailbox.rfind('<').unwrap()..];
+        PgpKey {
+            user_id: format!("{} {address}", self.get_full_name()),
+            fingerprint: rng.gen(),
+        }
+    }
+
+    /// Generates a random SSH key with a "user@host" comment made of the `Person`'s
+    /// [canonical username](Person::get_canonical_username).
+    pub fn get_random_ssh_key(&self) -> SshKey {
+        self.get_random_ssh_key_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_ssh_key`], but uses the given random number generator.
+    pub fn get_random_ssh_key_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> SshKey {
+        let username = self.get_canonical_username();
+        SshKey {
+            key: rng.gen(),
+            comment: format!("{username}@{username}-laptop"),
+        }
+    }
+}
+
+fn upper_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+/// Encodes the data as padded standard base64.
+fn base64(data: &[u8]) -> String {
+    let mut result = String::new();
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(BASE64_ALPHABET[((group >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,360 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+use crate::{Country, Origin};
+
+/// The chance of a `Person` in Canada growing up with French instead of English.
+const CANADIAN_FRENCH_PROBABILITY: f64 = 0.22;
+/// The chance of a `Person` speaking the language of their origin, if they didn't grow up with it.
+const HERITAGE_LANGUAGE_PROBABILITY: f64 = 0.5;
+/// The age at which `Person`s usually start learning foreign languages at school.
+const FOREIGN_LANGUAGE_START_AGE: u32 = 8;
+/// Immigrants older than [`FOREIGN_LANGUAGE_START_AGE`] are assumed to have spent one in this many
+/// of their years since that age learning the language of the country they live in.
+const LOCAL_LANGUAGE_YEARS_DIVISOR: u32 = 2;
+
+/// A language spoken by a `Person`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Language {
+    Arabic,
+    Chinese,
+    Dutch,
+    English,
+    French,
+    German,
+    Hindi,
+    Italian,
+    Japanese,
+    Korean,
+    Polish,
+    Portuguese,
+    Russian,
+    Spanish,
+}
+impl Language {
+    /// All languages.
+    pub const ALL: [Language; 14] = [
+        Language::Arabic,
+        Language::Chinese,
+        Language::Dutch,
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Hindi,
+        Language::Italian,
+        Language::Japanese,
+        Language::Korean,
+        Language::Polish,
+        Language::Portuguese,
+        Language::Russian,
+        Language::Spanish,
+    ];
+
+    /// Returns the ISO 639-1 code, like "de".
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Arabic => "ar",
+            Self::Chinese => "zh",
+            Self::Dutch => "nl",
+            Self::English => "en",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Hindi => "hi",
+            Self::Italian => "it",
+            Self::Japanese => "ja",
+            Self::Korean => "ko",
+            Self::Polish => "pl",
+            Self::Portuguese => "pt",
+            Self::Russian => "ru",
+            Self::Spanish => "es",
+        }
+    }
+
+    /// Returns the most spoken language of the country, like English for Canada.
+    pub fn of_country(country: Country) -> Self {
+        match country {
+            Country::UnitedStates
+            | Country::Canada
+            | Country::UnitedKingdom
+            | Country::Australia => Self::English,
+            Country::Germany => Self::German,
+            Country::France => Self::French,
+            Country::Spain => Self::Spanish,
+            Country::Italy => Self::Italian,
+            Country::Netherlands => Self::Dutch,
+            Country::Japan => Self::Japanese,
+        }
+    }
+
+    /// Returns the language of `Person`s with names of the origin, if it has one of its own.
+    pub fn of_origin(origin: Origin) -> Option<Self> {
+        match origin {
+            Origin::English => Some(Self::English),
+            Origin::Spanish => Some(Self::Spanish),
+            Origin::German => Some(Self::German),
+            Origin::French => Some(Self::French),
+            Origin::Italian => Some(Self::Italian),
+            Origin::Dutch => Some(Self::Dutch),
+            Origin::Polish => Some(Self::Polish),
+            Origin::Russian => Some(Self::Russian),
+            Origin::Chinese => Some(Self::Chinese),
+            Origin::Japanese => Some(Self::Japanese),
+            Origin::Korean => Some(Self::Korean),
+            Origin::Indian => Some(Self::Hindi),
+            Origin::Arabic => Some(Self::Arabic),
+            Origin::Nigerian => None,
+        }
+    }
+}
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// How well a `Person` speaks a language, as a level of the Common European Framework of
+/// Reference (CEFR) from A1 to C2, or as a native speaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LanguageLevel {
+    A1,
+    A2,
+    B1,
+    B2,
+    C1,
+    C2,
+    Native,
+}
+impl LanguageLevel {
+    /// All levels, from the lowest to the highest.
+    pub const ALL: [LanguageLevel; 7] = [
+        LanguageLevel::A1,
+        LanguageLevel::A2,
+        LanguageLevel::B1,
+        LanguageLevel::B2,
+        LanguageLevel::C1,
+        LanguageLevel::C2,
+        LanguageLevel::Native,
+    ];
+
+    /// Returns the highest level `Person`s usually reach after learning a foreign language for
+    /// the given number of years.
+    pub fn max_after_years(years: u32) -> Self {
+        match years {
+            0 => Self::A1,
+            1..=2 => Self::A2,
+            3..=4 => Self::B1,
+            5..=6 => Self::B2,
+            7..=9 => Self::C1,
+            _ => Self::C2,
+        }
+    }
+}
+impl std::fmt::Display for LanguageLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Native => write!(f, "native"),
+            level => write!(f, "{level:?}"),
+        }
+    }
+}
+
+/// A language with the level a `Person` speaks it at. Displays like "German (B2)".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LanguageProficiency {
+    pub language: Language,
+    pub level: LanguageLevel,
+}
+impl LanguageProficiency {
+    /// Returns whether the `Person` grew up with the language.
+    pub fn is_native(&self) -> bool {
+        self.level == LanguageLevel::Native
+    }
+}
+impl std::fmt::Display for LanguageProficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.language, self.level)
+    }
+}
+
+/// Generates the languages of a `Person` of the age, best spoken first: the languages of their
+/// nationalities, the one of their origin and the one of the country they live in, and foreign
+/// languages commonly taught there at levels they had the years to reach.
+pub(crate) fn random_languages<R: Rng + ?Sized>(
+    rng: &mut R,
+    age: u32,
+    nationalities: &[Country],
+    origin: Option<Origin>,
+    residence: Country,
+) -> Vec<LanguageProficiency> {
+    let mut languages: Vec<LanguageProficiency> = vec![];
+    let countries = if nationalities.is_empty() {
+        std::slice::from_ref(&residence)
+    } else {
+        nationalities
+    };
+    for &country in countries {
+        let language = if country == Country::Canada && rng.gen_bool(CANADIAN_FRENCH_PROBABILITY) {
+            Language::French
+        } else {
+            Language::of_country(country)
+        };
+        add_language(&mut languages, language, LanguageLevel::Native);
+    }
+    if let Some(language) = origin.and_then(Language::of_origin) {
+        if rng.gen_bool(HERITAGE_LANGUAGE_PROBABILITY) {
+            let level = if rng.gen_bool(0.5) {
+                LanguageLevel::Native
+            } else {
+                random_level(rng, LanguageLevel::A2, LanguageLevel::C1)
+            };
+            add_language(&mut languages, language, level);
+        }
+    }
+    // Immigrants learn the language of the country they live in, young children as natives.
+    // Older ones arrived at some unknown point, so only part of their years since school age count.
+    let local_level = if age < FOREIGN_LANGUAGE_START_AGE {
+        LanguageLevel::Native
+    } else {
+        let years = (age - FOREIGN_LANGUAGE_START_AGE) / LOCAL_LANGUAGE_YEARS_DIVISOR;
+        let max = LanguageLevel::max_after_years(years);
+        random_level(rng, LanguageLevel::A2, max)
+    };
+    add_language(&mut languages, Language::of_country(residence), local_level);
+
+    let Some(years) = age.checked_sub(FOREIGN_LANGUAGE_START_AGE) else {
+        return languages;
+    };
+    let (min_count, max_count, options) = foreign_languages(residence);
+    let mut remaining: Vec<(Language, f64)> = options
+        .iter()
+        .copied()
+        .filter(|(language, _)| languages.iter().all(|known| known.language != *language))
+        .collect();
+    let count = rng.gen_range(min_count..=max_count);
+    for _ in 0..count {
+        if remaining.is_empty() {
+            break;
+        }
+        let weights = WeightedIndex::new(remaining.iter().map(|(_, weight)| weight)).unwrap();
+        let (language, _) = remaining.swap_remove(weights.sample(rng));
+        let level = random_level(
+            rng,
+            LanguageLevel::A1,
+            LanguageLevel::max_after_years(years),
+        );
+        add_language(&mut languages, language, level);
+    }
+    // The sort is stable, so languages of the same level keep their order.
+    languages.sort_by_key(|language| std::cmp::Reverse(language.level));
+    languages
+}
+
+/// Adds the language at the level, unless the `Person` already speaks it.
+fn add_language(
+    languages: &mut Vec<LanguageProficiency>,
+    language: Language,
+    level: LanguageLevel,
+) {
+    if languages.iter().all(|known| known.language != language) {
+        languages.push(LanguageProficiency { language, level });
+    }
+}
+
+/// Picks a level between the minimum and the maximum, both inclusive.
+fn random_level<R: Rng + ?Sized>(
+    rng: &mut R,
+    min: LanguageLevel,
+    max: LanguageLevel,
+) -> LanguageLevel {
+    let max = max.max(min);
+    LanguageLevel::ALL[rng.gen_range(min as usize..=max as usize)]
+}
+
+/// Returns the fewest and most foreign languages `Person`s in the country speak, and the
+/// languages they learn with their relative popularity.
+fn foreign_languages(country: Country) -> (usize, usize, &'static [(Language, f64)]) {
+    match country {
+        Country::UnitedStates | Country::UnitedKingdom | Country::Australia => (
+            0,
+            1,
+            &[
+                (Language::Spanish, 4.0),
+                (Language::French, 3.0),
+                (Language::German, 1.0),
+                (Language::Italian, 0.5),
+                (Language::Japanese, 0.5),
+                (Language::Chinese, 0.5),
+            ],
+        ),
+        Country::Canada => (
+            0,
+            2,
+            &[
+                (Language::French, 6.0),
+                (Language::English, 6.0),
+                (Language::Spanish, 2.0),
+                (Language::Chinese, 0.5),
+            ],
+        ),
+        Country::Germany => (
+            1,
+            2,
+            &[
+                (Language::English, 8.0),
+                (Language::French, 2.0),
+                (Language::Spanish, 1.5),
+                (Language::Italian, 0.5),
+                (Language::Russian, 0.5),
+            ],
+        ),
+        Country::France => (
+            1,
+            2,
+            &[
+                (Language::English, 8.0),
+                (Language::Spanish, 3.0),
+                (Language::German, 2.0),
+                (Language::Italian, 1.0),
+            ],
+        ),
+        Country::Spain => (
+            1,
+            2,
+            &[
+                (Language::English, 8.0),
+                (Language::French, 3.0),
+                (Language::German, 1.0),
+                (Language::Italian, 1.0),
+                (Language::Portuguese, 1.0),
+            ],
+        ),
+        Country::Italy => (
+            1,
+            2,
+            &[
+                (Language::English, 8.0),
+                (Language::French, 3.0),
+                (Language::Spanish, 2.0),
+                (Language::German, 1.0),
+            ],
+        ),
+        Country::Netherlands => (
+            1,
+            3,
+            &[
+                (Language::English, 10.0),
+                (Language::German, 5.0),
+                (Language::French, 2.0),
+                (Language::Spanish, 1.0),
+            ],
+        ),
+        Country::Japan => (
+            0,
+            1,
+            &[
+                (Language::English, 8.0),
+                (Language::Chinese, 1.5),
+                (Language::Korean, 1.0),
+            ],
+        ),
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::PersonError;
+
+/// When a `Person` born on February 29 celebrates their birthday in non-leap years.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LeapDayPolicy {
+    /// On February 28, the last day of their birth month.
+    #[default]
+    February28,
+    /// On March 1, the day after February 28.
+    March1,
+}
+impl LeapDayPolicy {
+    /// Returns the date a birthday on February 29 falls on in a non-leap year.
+    pub(crate) fn birthday_in(self, year: i32) -> NaiveDate {
+        match self {
+            Self::February28 => NaiveDate::from_ymd_opt(year, 2, 28).unwrap(),
+            Self::March1 => NaiveDate::from_ymd_opt(year, 3, 1).unwrap(),
+        }
+    }
+}
+
+/// Picks a random time on a February 29 between `min` and `max`.
+pub(crate) fn random_leap_day_between<R: Rng + ?Sized>(
+    rng: &mut R,
+    min: DateTime<Utc>,
+    max: DateTime<Utc>,
+) -> Result<DateTime<Utc>, PersonError> {
+    let leap_days: Vec<_> = (min.year()..=max.year())
+        .filter_map(|year| Utc.with_ymd_and_hms(year, 2, 29, 0, 0, 0).single())
+        .filter(|leap_day| *leap_day >= min && *leap_day < max)
+        .collect();
+    let leap_day = leap_days
+        .choose(rng)
+        .ok_or(PersonError::InvalidDobRange { min, max })?;
+    let millis = (max - *leap_day)
+        .num_milliseconds()
+        .min(24 * 60 * 60 * 1000);
+    Ok(*leap_day + Duration::milliseconds(rng.gen_range(0..millis)))
+}
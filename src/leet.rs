@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+/// The substitutions used to leetify usernames, e.g. 'e' to '3'. Characters without a substitution
+/// are kept as they are.
+/// ## Example
+/// ```rust
+/// use person::{GenerationConfig, LeetMap, Person};
+/// let config = GenerationConfig {
+///     leet_probability: 1.0,
+///     leet_map: LeetMap::from_iter([('a', '@')]),
+///     ..GenerationConfig::clean()
+/// };
+/// let username = Person::random().get_random_username_with_config(&config);
+/// assert!(!username[1..].contains('a'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeetMap(HashMap<char, char>);
+impl Default for LeetMap {
+    /// The classic substitutions, like 'a' to '4', 'e' to '3' and 'o' to '0'.
+    fn default() -> Self {
+        Self::from_iter([
+            ('a', '4'),
+            ('b', '8'),
+            ('c', 'C'),
+            ('e', '3'),
+            ('f', 'F'),
+            ('g', '6'),
+            ('j', 'J'),
+            ('l', '1'),
+            ('o', '0'),
+            ('q', 'Q'),
+            ('s', '5'),
+            ('t', '7'),
+            ('y', 'Y'),
+            ('z', '2'),
+        ])
+    }
+}
+impl LeetMap {
+    /// A map without substitutions, which turns leetification off.
+    pub fn empty() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Adds or replaces the substitution of a character.
+    pub fn insert(&mut self, from: char, to: char) {
+        self.0.insert(from, to);
+    }
+
+    /// Removes the substitution of a character.
+    pub fn remove(&mut self, from: char) {
+        self.0.remove(&from);
+    }
+
+    /// Returns the substitution of a character, or the character itself if it has none.
+    pub fn get(&self, c: char) -> char {
+        *self.0.get(&c).unwrap_or(&c)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+impl FromIterator<(char, char)> for LeetMap {
+    fn from_iter<I: IntoIterator<Item = (char, char)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
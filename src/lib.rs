@@ -1,41 +1,389 @@
-use std::collections::HashMap;
-
-use chrono::{DateTime, Datelike, Duration, Utc};
-use rand::{rngs::ThreadRng, seq::SliceRandom, Rng};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveTime, TimeZone, Utc,
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    seq::SliceRandom,
+    Rng, SeedableRng,
+};
 
+mod address;
+mod age;
+mod allocator;
+mod avatar;
+mod bank;
+mod bio;
+mod birthplace;
+mod builder;
+mod checkout;
+mod clock;
+mod collation;
+mod company;
+mod config;
+mod contact;
+mod country;
+mod device;
+mod domain;
+mod drivers_license;
+mod education;
+mod email;
+mod employment;
+mod error;
+mod family;
+mod financial;
+mod gender;
+mod generation;
+mod generator;
+mod geo;
+mod hash;
+mod health;
+mod insurance;
+mod interests;
+mod iter;
+mod jurisdiction;
+mod keys;
+mod language;
+mod leap_day;
+mod leet;
 mod list;
+mod locale;
+mod loyalty;
+mod marital;
+mod messaging;
+mod name_format;
+mod national_id;
+mod nickname;
+mod occupation;
+mod origin;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod passport;
+mod password;
+mod payment;
+mod personality;
+mod phone;
+mod phonetic;
+mod physique;
+mod platform;
+mod popularity;
+mod postal;
+mod pronouns;
+mod redacted;
+mod religion;
+mod residence;
+mod salary;
+mod salutation;
+mod security;
+mod slug;
+mod ssn;
+mod suffix;
+mod tax;
+mod timezone;
+mod title;
+mod transliterate;
+mod two_factor;
+mod unique;
+mod username;
+mod vehicle;
+mod wallet;
+mod zodiac;
+
+pub use address::Address;
+pub use age::{AgeBracket, AgeDistribution};
+pub use allocator::UsernameAllocator;
+pub use avatar::AvatarProvider;
+pub use bank::{is_valid_iban, is_valid_routing_number, BankAccount, UsAccountKind, UsBankAccount};
+pub use bio::BioStyle;
+pub use birthplace::PlaceOfBirth;
+pub use builder::PersonBuilder;
+pub use checkout::CheckoutAddresses;
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use collation::Collation;
+pub use company::Company;
+pub use config::{GenerationConfig, UsernameNumber, UsernameSeparator, MAX_MIDDLE_NAMES};
+pub use contact::ContactProfile;
+pub use country::Country;
+pub use device::{Device, DeviceKind, MacAddress};
+pub use drivers_license::{DriversLicense, LicenseClass, UsState};
+pub use education::{DegreeLevel, Education, FieldOfStudy};
+pub use email::{
+    email_alias_domains, email_dot_variants, email_with_tag, DomainPool, EmailPattern,
+};
+pub use employment::Job;
+pub use error::{ParseNameError, PersonError};
+pub use family::{Family, SurnamePolicy};
+pub use financial::{FinancialProfile, IncomeBracket};
+pub use gender::Gender;
+pub use generation::Generation;
+pub use generator::{NameList, PersonGenerator};
+pub use geo::{BoundingBox, Coordinates};
+pub use health::{Allergy, Condition, HealthRecord};
+pub use insurance::{InsuranceCard, MemberIdFormat};
+pub use interests::{Interest, Proficiency, Skill, SkillProficiency};
+pub use iter::PersonIter;
+pub use jurisdiction::Jurisdiction;
+pub use keys::{PgpKey, SshKey};
+pub use language::{Language, LanguageLevel, LanguageProficiency};
+pub use leap_day::LeapDayPolicy;
+pub use leet::LeetMap;
+pub use locale::Locale;
+pub use loyalty::{is_valid_ean13, Airline, FrequentFlyerNumber};
+pub use marital::{MaritalStatus, MARRIAGE_AGE};
+pub use name_format::NameFormat;
+pub use national_id::NationalIdKind;
+pub use occupation::{Industry, Occupation, Seniority};
+pub use origin::Origin;
+pub use passport::TravelDocument;
+pub use password::PasswordPolicy;
+pub use payment::{is_luhn_valid, CardNetwork, PaymentCard};
+pub use personality::Personality;
+pub use phone::PhoneNumber;
+pub use phonetic::{metaphone, soundex};
+pub use physique::{BloodType, EyeColor, HairColor, Physique};
+pub use platform::Platform;
+pub use postal::{is_valid_postal_code, random_postal_code, random_postal_code_custom_rng};
+pub use pronouns::Pronouns;
+pub use redacted::Redacted;
+pub use religion::Religion;
+pub use residence::Residence;
+pub use salary::{Salary, SalaryRange};
+pub use salutation::SalutationStyle;
+pub use security::SecurityQuestion;
+pub use slug::SlugCase;
+pub use ssn::Ssn;
+pub use suffix::Suffix;
+pub use tax::{
+    is_valid_ein, is_valid_vat_id, random_ein, random_ein_custom_rng, random_vat_id,
+    random_vat_id_custom_rng,
+};
+pub use timezone::Timezone;
+pub use title::Title;
+pub use two_factor::{TwoFactor, BACKUP_CODE_COUNT};
+pub use unique::UniqueGenerator;
+pub use username::UsernamePolicy;
+pub use vehicle::{is_valid_vin, Vehicle};
+pub use wallet::CryptoNetwork;
+pub use zodiac::{ChineseZodiac, ZodiacSign};
+
+/// How many `Person`s are generated by default before giving up on finding one that satisfies the constraints.
+pub const DEFAULT_MAX_ATTEMPTS: usize = 10_000;
+
+/// The random number generator used for seeded generation.
+/// Its output is stable across platforms and releases, which makes it suitable for snapshot tests.
+pub type SeededRng = rand_chacha::ChaCha8Rng;
 
-#[derive(Debug, Clone)]
+/// A random identity.
+///
+/// `Person`s are equal if all of their fields are equal. They are ordered by last name, first name
+/// and date of birth, with the remaining fields used as tie-breakers so the order is consistent with equality.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Person {
     date_of_birth: DateTime<Utc>,
     first_name: String,
-    middle_name: Option<String>,
+    middle_names: Vec<String>,
     last_name: String,
+    gender: Option<Gender>,
+    pronouns: Option<Pronouns>,
+    title: Option<Title>,
+    suffix: Option<Suffix>,
+    former_names: Vec<String>,
+    origin: Option<Origin>,
+    date_of_death: Option<DateTime<Utc>>,
+    physique: Option<Physique>,
+    phone_number: Option<PhoneNumber>,
+    address: Option<Address>,
+    place_of_birth: Option<PlaceOfBirth>,
+    nationalities: Vec<Country>,
+    financial_profile: Option<FinancialProfile>,
+    health_record: Option<HealthRecord>,
+    occupation: Option<Occupation>,
+    education: Vec<Education>,
+    interests: Vec<Interest>,
+    skills: Vec<SkillProficiency>,
+    personality: Option<Personality>,
+    marital_status: Option<MaritalStatus>,
+    languages: Vec<LanguageProficiency>,
+    religion: Option<Religion>,
 }
 impl Person {
     /// Creates a new `Person` and allows you to specify whether the `Person` should have a middle name.
     pub fn new(have_middle_name: bool) -> Self {
+        Self::new_custom_rng(&mut rand::thread_rng(), have_middle_name)
+    }
+
+    /// Same as [`Person::new`], but uses the given random number generator.
+    pub fn new_custom_rng<R: Rng + ?Sized>(rng: &mut R, have_middle_name: bool) -> Self {
         let now = Utc::now();
-        Self::with_dob_range(now - Duration::days(366 * 100), now, have_middle_name)
+        Self::with_dob_range_custom_rng(rng, now - Duration::days(366 * 100), now, have_middle_name)
     }
 
     /// Creates a completely random `Person`.
     /// There is a 50% chance the `Person` will have a middle name.
     /// The `Person` will be between 0 and 100 years old.
     pub fn random() -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::random`], but uses the given random number generator.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// // The same seed always gives the same `Person`.
+    /// let first = Person::random_custom_rng(&mut StdRng::seed_from_u64(42));
+    /// let second = Person::random_custom_rng(&mut StdRng::seed_from_u64(42));
+    /// assert_eq!(first.get_full_name(), second.get_full_name());
+    /// ```
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::random`], but uses the probabilities of the given config.
+    pub fn random_with_config(config: &GenerationConfig) -> Self {
+        Self::random_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::random_with_config`], but uses the given random number generator.
+    pub fn random_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> Self {
         let now = Utc::now();
-        Self::with_dob_range(
+        Self::random_with_dob_range_and_config_custom_rng(
+            rng,
             now - Duration::days(366 * 100),
             now,
-            rand::thread_rng().gen_bool(0.5),
+            config,
+        )
+    }
+
+    /// Creates a reproducible random `Person`: the same seed always produces the same `Person`.
+    /// The date of birth is drawn from a fixed window between 1925 and 2025, so it doesn't depend on the current time.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let a = Person::from_seed(7);
+    /// let b = Person::from_seed(7);
+    /// assert_eq!(a.get_full_name(), b.get_full_name());
+    /// assert_eq!(a.get_date_of_birth(), b.get_date_of_birth());
+    /// ```
+    pub fn from_seed(seed: u64) -> Self {
+        Self::from_seed_with_dob_range(
+            seed,
+            Utc.with_ymd_and_hms(1925, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
         )
     }
 
+    /// Creates a reproducible random `Person` born within the given date of birth range.
+    /// ## Panics
+    /// Panics if `min` is not before `max`, see [`Person::try_from_seed_with_dob_range`].
+    pub fn from_seed_with_dob_range(seed: u64, min: DateTime<Utc>, max: DateTime<Utc>) -> Self {
+        Self::try_from_seed_with_dob_range(seed, min, max).unwrap()
+    }
+
+    /// Same as [`Person::from_seed_with_dob_range`], but returns an error instead of panicking.
+    pub fn try_from_seed_with_dob_range(
+        seed: u64,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+    ) -> Result<Self, PersonError> {
+        Self::try_random_with_dob_range_custom_rng(&mut Self::seeded_rng(seed), min, max)
+    }
+
+    /// Returns the random number generator used by the seeded functions, initialized with the given seed.
+    pub fn seeded_rng(seed: u64) -> SeededRng {
+        SeededRng::seed_from_u64(seed)
+    }
+
     /// Creates a new `Person` and allows you to specify the date of birth range.
+    /// ## Panics
+    /// Panics if `min` is not before `max`, see [`Person::try_random_with_dob_range`].
     pub fn random_with_dob_range(min: DateTime<Utc>, max: DateTime<Utc>) -> Self {
-        let mut rng = rand::thread_rng();
-        let have_middle_name = rng.gen_bool(0.5);
-        Self::with_dob_range_custom_rng(&mut rng, min, max, have_middle_name)
+        Self::random_with_dob_range_custom_rng(&mut rand::thread_rng(), min, max)
+    }
+
+    /// Same as [`Person::random_with_dob_range`], but uses the given random number generator.
+    pub fn random_with_dob_range_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+    ) -> Self {
+        Self::try_random_with_dob_range_custom_rng(rng, min, max).unwrap()
+    }
+
+    /// Same as [`Person::random_with_dob_range`], but returns an error instead of panicking.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Person, PersonError};
+    /// let now = Utc::now();
+    /// let result = Person::try_random_with_dob_range(now, now);
+    /// assert!(matches!(result, Err(PersonError::InvalidDobRange { .. })));
+    /// ```
+    pub fn try_random_with_dob_range(
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+    ) -> Result<Self, PersonError> {
+        Self::try_random_with_dob_range_custom_rng(&mut rand::thread_rng(), min, max)
+    }
+
+    /// Same as [`Person::try_random_with_dob_range`], but uses the given random number generator.
+    pub fn try_random_with_dob_range_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+    ) -> Result<Self, PersonError> {
+        Self::try_random_with_dob_range_and_config_custom_rng(
+            rng,
+            min,
+            max,
+            &GenerationConfig::default(),
+        )
+    }
+
+    /// Same as [`Person::random_with_dob_range`], but uses the probabilities of the given config.
+    pub fn random_with_dob_range_and_config(
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+        config: &GenerationConfig,
+    ) -> Self {
+        Self::random_with_dob_range_and_config_custom_rng(&mut rand::thread_rng(), min, max, config)
+    }
+
+    /// Same as [`Person::random_with_dob_range_and_config`], but uses the given random number generator.
+    pub fn random_with_dob_range_and_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+        config: &GenerationConfig,
+    ) -> Self {
+        Self::try_random_with_dob_range_and_config_custom_rng(rng, min, max, config).unwrap()
+    }
+
+    /// Same as [`Person::random_with_dob_range_and_config`], but returns an error instead of panicking.
+    pub fn try_random_with_dob_range_and_config(
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+        config: &GenerationConfig,
+    ) -> Result<Self, PersonError> {
+        Self::try_random_with_dob_range_and_config_custom_rng(
+            &mut rand::thread_rng(),
+            min,
+            max,
+            config,
+        )
+    }
+
+    /// Same as [`Person::try_random_with_dob_range_and_config`], but uses the given random number generator.
+    pub fn try_random_with_dob_range_and_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+        config: &GenerationConfig,
+    ) -> Result<Self, PersonError> {
+        let date_of_birth = random_date_between(rng, min, max)?;
+        generator::generate_person(rng, config, date_of_birth, Utc::now())
     }
 
     /// Creates a new `Person` and allows you to specify the date of birth range and whether the `Person` should have a middle name.
@@ -50,185 +398,1810 @@ impl Person {
     /// );
     /// assert_eq!(person.get_age() >= 21, true);
     /// ```
+    /// ## Panics
+    /// Panics if `min` is not before `max`, see [`Person::try_with_dob_range`].
     pub fn with_dob_range(min: DateTime<Utc>, max: DateTime<Utc>, have_middle_name: bool) -> Self {
         Self::with_dob_range_custom_rng(&mut rand::thread_rng(), min, max, have_middle_name)
     }
 
-    /// Creates a new `Person` and allows you to specify the range of years
-    pub fn with_dob_range_custom_rng(
-        rng: &mut ThreadRng,
+    /// Same as [`Person::with_dob_range`], but uses the given random number generator.
+    pub fn with_dob_range_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
         min: DateTime<Utc>,
         max: DateTime<Utc>,
         have_middle_name: bool,
     ) -> Self {
-        let range_millis = (max - min).num_milliseconds();
-        let random_millis = rng.gen_range(0..range_millis);
-        Self {
-            date_of_birth: min + Duration::milliseconds(random_millis),
-            first_name: list::NAMES.choose(rng).unwrap().to_string(),
-            middle_name: if have_middle_name {
-                Some(list::NAMES.choose(rng).unwrap().to_string())
-            } else {
-                None
-            },
-            last_name: list::SURNAMES.choose(rng).unwrap().to_string(),
-        }
+        Self::try_with_dob_range_custom_rng(rng, min, max, have_middle_name).unwrap()
     }
 
-    pub fn get_first_name(&self) -> String {
-        self.first_name.clone()
+    /// Same as [`Person::with_dob_range`], but returns an error instead of panicking.
+    pub fn try_with_dob_range(
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+        have_middle_name: bool,
+    ) -> Result<Self, PersonError> {
+        Self::try_with_dob_range_custom_rng(&mut rand::thread_rng(), min, max, have_middle_name)
     }
 
-    pub fn get_middle_name(&self) -> Option<String> {
-        self.middle_name.clone()
+    /// Same as [`Person::try_with_dob_range`], but uses the given random number generator.
+    pub fn try_with_dob_range_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        min: DateTime<Utc>,
+        max: DateTime<Utc>,
+        have_middle_name: bool,
+    ) -> Result<Self, PersonError> {
+        let config = GenerationConfig {
+            middle_name_probability: if have_middle_name { 1.0 } else { 0.0 },
+            additional_middle_name_probability: 0.0,
+            ..GenerationConfig::default()
+        };
+        let date_of_birth = random_date_between(rng, min, max)?;
+        generator::generate_person(rng, &config, date_of_birth, Utc::now())
     }
 
-    pub fn get_last_name(&self) -> String {
-        self.last_name.clone()
+    /// Creates a random `Person` of the given gender, with first and middle names picked from the matching list.
+    /// ## Example
+    /// ```rust
+    /// use person::{Gender, Person};
+    /// let person = Person::random_with_gender(Gender::Female);
+    /// assert_eq!(person.get_gender(), Some(Gender::Female));
+    /// ```
+    pub fn random_with_gender(gender: Gender) -> Self {
+        Self::random_with_gender_custom_rng(&mut rand::thread_rng(), gender)
     }
 
-    pub fn get_date_of_birth(&self) -> DateTime<Utc> {
-        self.date_of_birth.clone()
+    /// Same as [`Person::random_with_gender`], but uses the given random number generator.
+    pub fn random_with_gender_custom_rng<R: Rng + ?Sized>(rng: &mut R, gender: Gender) -> Self {
+        Self::random_with_gender_and_config_custom_rng(rng, gender, &GenerationConfig::default())
     }
 
-    /// Returns the elapsed years since the `Person`'s date of birth
-    pub fn get_age(&self) -> u32 {
-        Utc::now().years_since(self.date_of_birth).unwrap()
+    /// Same as [`Person::random_with_gender`], but uses the probabilities of the given config.
+    /// The gender replaces the config's [genders](GenerationConfig::genders).
+    /// ## Example
+    /// ```rust
+    /// use person::{Gender, GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     middle_name_probability: 0.0,
+    ///     ..GenerationConfig::clean()
+    /// };
+    /// let person = Person::random_with_gender_and_config(Gender::Male, &config);
+    /// assert_eq!(person.get_gender(), Some(Gender::Male));
+    /// assert!(person.get_middle_name().is_none());
+    /// ```
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_with_gender_and_config(gender: Gender, config: &GenerationConfig) -> Self {
+        Self::random_with_gender_and_config_custom_rng(&mut rand::thread_rng(), gender, config)
     }
 
-    /// Returns the person's full name, including the middle name.
-    pub fn get_full_name(&self) -> String {
-        format!(
-            "{}{}{}",
-            self.first_name,
-            match self.middle_name.as_ref() {
-                Some(mn) => format!(" {mn} "),
-                _ => " ".to_string(),
-            },
-            self.last_name,
+    /// Same as [`Person::random_with_gender_and_config`], but uses the given random number generator.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_with_gender_and_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        gender: Gender,
+        config: &GenerationConfig,
+    ) -> Self {
+        let config = GenerationConfig {
+            genders: vec![gender],
+            ..config.clone()
+        };
+        Self::random_with_config_custom_rng(rng, &config)
+    }
+
+    /// Creates a random `Person` born in the given generation.
+    /// ## Example
+    /// ```rust
+    /// use person::{Generation, Person};
+    /// let person = Person::random_from_generation(Generation::GenZ);
+    /// assert_eq!(person.get_generation(), Some(Generation::GenZ));
+    /// ```
+    pub fn random_from_generation(generation: Generation) -> Self {
+        Self::random_from_generation_custom_rng(&mut rand::thread_rng(), generation)
+    }
+
+    /// Same as [`Person::random_from_generation`], but uses the given random number generator.
+    pub fn random_from_generation_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        generation: Generation,
+    ) -> Self {
+        Self::random_from_generation_with_config_custom_rng(
+            rng,
+            generation,
+            &GenerationConfig::default(),
         )
     }
 
-    /// Returns the person's full name with a shortened middle name.
-    pub fn get_short_full_name(&self) -> String {
-        format!(
-            "{}{}{}",
-            self.first_name,
-            match self.middle_name.as_ref() {
-                Some(mn) => format!(" {}. ", mn.chars().next().unwrap()),
-                _ => " ".to_string(),
-            },
-            self.last_name,
+    /// Same as [`Person::random_from_generation`], but uses the probabilities of the given config.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_from_generation_with_config(
+        generation: Generation,
+        config: &GenerationConfig,
+    ) -> Self {
+        Self::random_from_generation_with_config_custom_rng(
+            &mut rand::thread_rng(),
+            generation,
+            config,
         )
     }
 
-    /// Generates a random username by using random separators, numbers and the person's identity.
-    pub fn get_random_username(&self) -> String {
-        let mut rng = rand::thread_rng();
-        let number = [
-            rng.gen_range(0..9999).to_string(),
-            "".into(),
-            self.get_age().to_string(),
-            self.date_of_birth.year().to_string(),
-        ]
-        .choose(&mut rng)
-        .unwrap()
-        .clone();
-        let middle_name_initial = self
-            .get_middle_name()
-            .unwrap_or(".".into())
-            .chars()
-            .next()
-            .unwrap()
-            .to_string();
-        let divisor = [
-            "".into(),
-            "-".into(),
-            "_".into(),
-            ".".into(),
-            middle_name_initial,
-        ]
-        .choose(&mut rng)
-        .unwrap()
-        .clone();
+    /// Same as [`Person::random_from_generation_with_config`], but uses the given random number
+    /// generator.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_from_generation_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        generation: Generation,
+        config: &GenerationConfig,
+    ) -> Self {
+        let (min, max) = generation.dob_range(Utc::now());
+        Self::random_with_dob_range_and_config_custom_rng(rng, min, max, config)
+    }
 
-        let mut parts = vec![];
-        if rng.gen_bool(0.70) {
-            parts.push(repeat_last_char(&self.first_name, rng.gen_range(0..2)));
-            parts.push(divisor.to_string());
-            parts.push(repeat_last_char(&self.last_name, rng.gen_range(0..2)));
-        } else {
-            parts.push(repeat_last_char(&self.last_name, rng.gen_range(0..2)));
-            parts.push(divisor.to_string());
-            parts.push(repeat_last_char(&self.first_name, rng.gen_range(0..2)));
+    /// Creates a random adult `Person`, between 18 and 100 years old.
+    /// ## Example
+    /// ```rust
+    /// use person::{Jurisdiction, Person};
+    /// assert!(Person::random_adult().is_adult(Jurisdiction::Common));
+    /// assert!(!Person::random_minor().is_adult(Jurisdiction::Common));
+    /// ```
+    pub fn random_adult() -> Self {
+        Self::random_adult_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::random_adult`], but uses the given random number generator.
+    pub fn random_adult_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_adult_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::random_adult`], but uses the probabilities of the given config.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_adult_with_config(config: &GenerationConfig) -> Self {
+        Self::random_adult_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::random_adult_with_config`], but uses the given random number generator.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_adult_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> Self {
+        let now = Utc::now();
+        let adult_age = Months::new(12 * Jurisdiction::Common.age_of_majority());
+        let max = now.checked_sub_months(adult_age).unwrap();
+        Self::random_with_dob_range_and_config_custom_rng(
+            rng,
+            now - Duration::days(366 * 100),
+            max,
+            config,
+        )
+    }
+
+    /// Creates a random minor `Person`, younger than 18 years.
+    pub fn random_minor() -> Self {
+        Self::random_minor_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::random_minor`], but uses the given random number generator.
+    pub fn random_minor_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_minor_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::random_minor`], but uses the probabilities of the given config.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_minor_with_config(config: &GenerationConfig) -> Self {
+        Self::random_minor_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::random_minor_with_config`], but uses the given random number generator.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_minor_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> Self {
+        let now = Utc::now();
+        let adult_age = Months::new(12 * Jurisdiction::Common.age_of_majority());
+        let min = now.checked_sub_months(adult_age).unwrap() + Duration::days(1);
+        Self::random_with_dob_range_and_config_custom_rng(rng, min, now, config)
+    }
+
+    /// Creates a random `Person` born on February 29, between 0 and 100 years old.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let person = Person::random_leap_day();
+    /// assert!(person.is_leap_day_birthday());
+    /// ```
+    pub fn random_leap_day() -> Self {
+        Self::random_leap_day_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::random_leap_day`], but uses the given random number generator.
+    pub fn random_leap_day_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_leap_day_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::random_leap_day`], but uses the probabilities of the given config.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_leap_day_with_config(config: &GenerationConfig) -> Self {
+        Self::random_leap_day_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::random_leap_day_with_config`], but uses the given random number generator.
+    /// ## Panics
+    /// Panics if the config is invalid, see [`Person::try_random_with_dob_range_and_config`].
+    pub fn random_leap_day_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> Self {
+        let now = Utc::now();
+        let date_of_birth =
+            leap_day::random_leap_day_between(rng, now - Duration::days(366 * 100), now).unwrap();
+        generator::generate_person(rng, config, date_of_birth, now).unwrap()
+    }
+
+    /// Creates a random `Person` that satisfies the given predicate by generating up to
+    /// [`DEFAULT_MAX_ATTEMPTS`] `Person`s and returning the first match.
+    /// Use [`PersonBuilder::constraint`] to combine predicates with other settings.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let person = Person::random_where(|p| p.get_age() >= 18 && p.get_last_name().starts_with('S'))
+    ///     .unwrap();
+    /// assert!(person.get_last_name().starts_with('S'));
+    /// ```
+    pub fn random_where<F: Fn(&Person) -> bool>(predicate: F) -> Result<Self, PersonError> {
+        Self::random_where_custom_rng(&mut rand::thread_rng(), predicate)
+    }
+
+    /// Same as [`Person::random_where`], but uses the given random number generator.
+    pub fn random_where_custom_rng<R: Rng + ?Sized, F: Fn(&Person) -> bool>(
+        rng: &mut R,
+        predicate: F,
+    ) -> Result<Self, PersonError> {
+        Self::random_where_with_config_custom_rng(rng, &GenerationConfig::default(), predicate)
+    }
+
+    /// Same as [`Person::random_where`], but uses the probabilities of the given config.
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let person = Person::random_where_with_config(&GenerationConfig::clean(), |p| p.get_age() >= 18)
+    ///     .unwrap();
+    /// assert!(person.get_age() >= 18);
+    /// ```
+    pub fn random_where_with_config<F: Fn(&Person) -> bool>(
+        config: &GenerationConfig,
+        predicate: F,
+    ) -> Result<Self, PersonError> {
+        Self::random_where_with_config_custom_rng(&mut rand::thread_rng(), config, predicate)
+    }
+
+    /// Same as [`Person::random_where_with_config`], but uses the given random number generator.
+    pub fn random_where_with_config_custom_rng<R: Rng + ?Sized, F: Fn(&Person) -> bool>(
+        rng: &mut R,
+        config: &GenerationConfig,
+        predicate: F,
+    ) -> Result<Self, PersonError> {
+        let now = Utc::now();
+        let min = now - Duration::days(366 * 100);
+        for _ in 0..DEFAULT_MAX_ATTEMPTS {
+            let person =
+                Self::try_random_with_dob_range_and_config_custom_rng(rng, min, now, config)?;
+            if predicate(&person) {
+                return Ok(person);
+            }
         }
-        parts.push(number);
+        Err(PersonError::ConstraintsNotSatisfied {
+            attempts: DEFAULT_MAX_ATTEMPTS,
+        })
+    }
 
-        let leet_map: HashMap<char, char> = [
-            ('a', '4'),
-            ('b', '8'),
-            ('c', 'C'),
-            ('d', 'd'),
-            ('e', '3'),
-            ('f', 'F'),
-            ('g', '6'),
-            ('h', 'h'),
-            ('j', 'J'),
-            ('k', 'k'),
-            ('l', '1'),
-            ('m', 'm'),
-            ('n', 'n'),
-            ('o', '0'),
-            ('p', 'p'),
-            ('q', 'Q'),
-            ('r', 'r'),
-            ('s', '5'),
-            ('t', '7'),
-            ('u', 'u'),
-            ('v', 'v'),
-            ('w', 'w'),
-            ('x', 'x'),
-            ('y', 'Y'),
-            ('z', '2'),
-        ]
-        .iter()
-        .cloned()
-        .collect();
+    /// Creates a `Person` with the given names and date of birth, nothing is randomized.
+    /// An empty last name creates a mononym, see [`Person::is_mononym`]. An empty or whitespace-only
+    /// middle name is ignored.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::Person;
+    /// let person = Person::from_parts(
+    ///     "Jane",
+    ///     Some("Quinn"),
+    ///     "Doe",
+    ///     Utc.with_ymd_and_hms(1985, 4, 12, 0, 0, 0).unwrap(),
+    /// );
+    /// assert_eq!(person.get_short_full_name(), "Jane Q. Doe");
+    /// assert_eq!(person.with_first_name("John").get_full_name(), "John Quinn Doe");
+    /// let person = Person::from_parts("Jane", Some(" "), "Doe", person.get_date_of_birth());
+    /// assert_eq!(person.get_middle_name(), None);
+    /// ```
+    pub fn from_parts(
+        first_name: impl Into<String>,
+        middle_name: Option<&str>,
+        last_name: impl Into<String>,
+        date_of_birth: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            date_of_birth,
+            first_name: first_name.into(),
+            middle_names: middle_name
+                .filter(|middle_name| !middle_name.trim().is_empty())
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            last_name: last_name.into(),
+            gender: None,
+            pronouns: None,
+            title: None,
+            suffix: None,
+            former_names: vec![],
+            origin: None,
+            date_of_death: None,
+            physique: None,
+            phone_number: None,
+            address: None,
+            place_of_birth: None,
+            nationalities: vec![],
+            financial_profile: None,
+            health_record: None,
+            occupation: None,
+            education: vec![],
+            interests: vec![],
+            skills: vec![],
+            personality: None,
+            marital_status: None,
+            languages: vec![],
+            religion: None,
+        }
+    }
 
-        leetify_string(&parts.join(""), &leet_map)
+    /// Parses a full name like `"Jane Q. Doe"` or `"Doe, Jane Q."` into a `Person` with the given date of birth.
+    /// Every name between the first and the last name is treated as a middle name,
+    /// and a single name without a comma is parsed as a mononym. A trailing suffix like "Jr." is recognized,
+    /// and lowercase surname particles like "van der" are kept with the last name.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::Person;
+    /// let dob = Utc.with_ymd_and_hms(1985, 4, 12, 0, 0, 0).unwrap();
+    /// let person = Person::parse_full_name("Doe, Jane Q.", dob).unwrap();
+    /// assert_eq!(person.get_first_name(), "Jane");
+    /// assert_eq!(person.get_middle_name().as_deref(), Some("Q."));
+    /// assert_eq!(person.get_last_name(), "Doe");
+    /// assert_eq!(person.get_full_name().parse::<Person>().unwrap().get_full_name(), "Jane Q. Doe");
+    /// let person = Person::parse_full_name("Anna Maria van der Berg-Smith", dob).unwrap();
+    /// assert_eq!(person.get_last_name(), "van der Berg-Smith");
+    /// assert_eq!(person.get_short_full_name(), "Anna M. van der Berg-Smith");
+    /// ```
+    pub fn parse_full_name(
+        name: &str,
+        date_of_birth: DateTime<Utc>,
+    ) -> Result<Self, ParseNameError> {
+        if name.trim().is_empty() {
+            return Err(ParseNameError::Empty);
+        }
+        let (name, suffix) = match name.trim_end().rsplit_once([' ', ',']) {
+            Some((rest, last)) if !rest.trim().is_empty() => match Suffix::parse(last) {
+                Some(suffix) => (rest.trim_end().trim_end_matches(','), Some(suffix)),
+                None => (name, None),
+            },
+            _ => (name, None),
+        };
+        let (given_names, last_name) = match name.split_once(',') {
+            Some((_, rest)) if rest.contains(',') => return Err(ParseNameError::TooManyCommas),
+            Some((last_name, given_names)) => {
+                let last_name = last_name.split_whitespace().collect::<Vec<_>>().join(" ");
+                if last_name.is_empty() {
+                    return Err(ParseNameError::MissingLastName);
+                }
+                (
+                    given_names.split_whitespace().collect::<Vec<_>>(),
+                    last_name,
+                )
+            }
+            None => {
+                let mut names = name.split_whitespace().collect::<Vec<_>>();
+                let mut last_name = if names.len() > 1 {
+                    names.pop().unwrap().to_string()
+                } else {
+                    String::new()
+                };
+                while names.len() > 1 && is_surname_particle(names[names.len() - 1]) {
+                    last_name = format!("{} {last_name}", names.pop().unwrap());
+                }
+                (names, last_name)
+            }
+        };
+        let Some((first_name, middle_names)) = given_names.split_first() else {
+            return Err(ParseNameError::MissingFirstName);
+        };
+        Ok(Self {
+            middle_names: middle_names.iter().map(|name| name.to_string()).collect(),
+            suffix,
+            ..Self::from_parts(*first_name, None, last_name, date_of_birth)
+        })
     }
-}
-impl std::fmt::Display for Person {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}, {}", self.get_short_full_name(), self.get_age())
+
+    /// Returns a copy of the `Person` with the given first name.
+    pub fn with_first_name(&self, first_name: impl Into<String>) -> Self {
+        Self {
+            first_name: first_name.into(),
+            ..self.clone()
+        }
     }
-}
 
-fn repeat_last_char(s: &str, times: usize) -> String {
-    let mut result = s.to_string();
-    if let Some(last_char) = s.chars().last() {
-        for _ in 0..times {
-            result.push(last_char);
+    /// Returns a copy of the `Person` with the given middle name replacing all middle names,
+    /// `None` removes the middle names.
+    pub fn with_middle_name(&self, middle_name: Option<&str>) -> Self {
+        Self {
+            middle_names: middle_name.into_iter().map(Into::into).collect(),
+            ..self.clone()
         }
     }
-    result
-}
 
-fn leetify_string(input: &str, leet_map: &HashMap<char, char>) -> String {
-    let mut rng = rand::thread_rng();
-    let mut result = String::new();
+    /// Returns a copy of the `Person` with the given middle names.
+    pub fn with_middle_names<S: Into<String>>(
+        &self,
+        middle_names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        Self {
+            middle_names: middle_names.into_iter().map(Into::into).collect(),
+            ..self.clone()
+        }
+    }
 
-    for (i, c) in input.chars().enumerate() {
-        if i == 0 || !rng.gen_bool(0.25) {
-            result.push(c);
-        } else {
-            result.push(leetify_char(c, leet_map));
+    /// Returns a copy of the `Person` with the given last name, an empty last name creates a mononym.
+    pub fn with_last_name(&self, last_name: impl Into<String>) -> Self {
+        Self {
+            last_name: last_name.into(),
+            ..self.clone()
         }
     }
 
-    result
-}
+    /// Returns a copy of the `Person` with the given gender.
+    pub fn with_gender(&self, gender: Option<Gender>) -> Self {
+        Self {
+            gender,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the `Person` with the given date of birth.
+    pub fn with_date_of_birth(&self, date_of_birth: DateTime<Utc>) -> Self {
+        Self {
+            date_of_birth,
+            ..self.clone()
+        }
+    }
+
+    pub fn set_first_name(&mut self, first_name: impl Into<String>) {
+        self.first_name = first_name.into();
+    }
+
+    /// Sets the middle name replacing all middle names, `None` removes the middle names.
+    pub fn set_middle_name(&mut self, middle_name: Option<&str>) {
+        self.middle_names = middle_name.into_iter().map(Into::into).collect();
+    }
+
+    pub fn set_middle_names<S: Into<String>>(&mut self, middle_names: impl IntoIterator<Item = S>) {
+        self.middle_names = middle_names.into_iter().map(Into::into).collect();
+    }
+
+    /// Sets the last name, an empty last name creates a mononym.
+    pub fn set_last_name(&mut self, last_name: impl Into<String>) {
+        self.last_name = last_name.into();
+    }
+
+    pub fn set_gender(&mut self, gender: Option<Gender>) {
+        self.gender = gender;
+    }
+
+    pub fn set_origin(&mut self, origin: Option<Origin>) {
+        self.origin = origin;
+    }
+
+    pub fn set_title(&mut self, title: Option<Title>) {
+        self.title = title;
+    }
+
+    pub fn set_suffix(&mut self, suffix: Option<Suffix>) {
+        self.suffix = suffix;
+    }
+
+    /// Sets the former last names, oldest first.
+    pub fn set_former_names<S: Into<String>>(&mut self, former_names: impl IntoIterator<Item = S>) {
+        self.former_names = former_names.into_iter().map(Into::into).collect();
+    }
+
+    /// Sets the pronouns, `None` derives them from the gender again.
+    pub fn set_pronouns(&mut self, pronouns: Option<Pronouns>) {
+        self.pronouns = pronouns;
+    }
+
+    pub fn set_date_of_birth(&mut self, date_of_birth: DateTime<Utc>) {
+        self.date_of_birth = date_of_birth;
+    }
+
+    pub fn set_phone_number(&mut self, phone_number: Option<PhoneNumber>) {
+        self.phone_number = phone_number;
+    }
+
+    pub fn set_address(&mut self, address: Option<Address>) {
+        self.address = address;
+    }
+
+    pub fn set_place_of_birth(&mut self, place_of_birth: Option<PlaceOfBirth>) {
+        self.place_of_birth = place_of_birth;
+    }
+
+    pub fn set_financial_profile(&mut self, financial_profile: Option<FinancialProfile>) {
+        self.financial_profile = financial_profile;
+    }
+
+    pub fn set_health_record(&mut self, health_record: Option<HealthRecord>) {
+        self.health_record = health_record;
+    }
+
+    pub fn set_occupation(&mut self, occupation: Option<Occupation>) {
+        self.occupation = occupation;
+    }
+
+    /// Sets the completed education, lowest level first.
+    pub fn set_education(&mut self, education: Vec<Education>) {
+        self.education = education;
+    }
+
+    pub fn set_interests(&mut self, interests: Vec<Interest>) {
+        self.interests = interests;
+    }
+
+    pub fn set_skills(&mut self, skills: Vec<SkillProficiency>) {
+        self.skills = skills;
+    }
 
-fn leetify_char(c: char, leet_map: &HashMap<char, char>) -> char {
-    *leet_map.get(&c).unwrap_or(&c)
+    pub fn set_personality(&mut self, personality: Option<Personality>) {
+        self.personality = personality;
+    }
+
+    pub fn set_marital_status(&mut self, marital_status: Option<MaritalStatus>) {
+        self.marital_status = marital_status;
+    }
+
+    pub fn set_languages(&mut self, languages: Vec<LanguageProficiency>) {
+        self.languages = languages;
+    }
+
+    pub fn set_religion(&mut self, religion: Option<Religion>) {
+        self.religion = religion;
+    }
+
+    /// Sets the countries the `Person` is a citizen of, the primary nationality first.
+    pub fn set_nationalities(&mut self, nationalities: impl IntoIterator<Item = Country>) {
+        self.nationalities.clear();
+        for nationality in nationalities {
+            if !self.nationalities.contains(&nationality) {
+                self.nationalities.push(nationality);
+            }
+        }
+    }
+
+    pub fn set_physique(&mut self, physique: Option<Physique>) {
+        self.physique = physique;
+    }
+
+    /// Sets the date of death, `None` makes the `Person` alive.
+    pub fn set_date_of_death(&mut self, date_of_death: Option<DateTime<Utc>>) {
+        self.date_of_death = date_of_death;
+    }
+
+    /// Applies the given changes to the `Person` and returns it, which is handy for adjusting generated `Person`s.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::Person;
+    /// let person = Person::random().modify(|p| {
+    ///     p.set_middle_name(None);
+    ///     p.set_date_of_birth(Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap());
+    /// });
+    /// assert!(person.get_middle_name().is_none());
+    /// assert!(person.get_age() >= 120);
+    /// ```
+    pub fn modify<F: FnOnce(&mut Person)>(mut self, f: F) -> Self {
+        f(&mut self);
+        self
+    }
+
+    /// Generates `n` random `Person`s at once, which is faster than calling [`Person::random`] in a loop.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// assert_eq!(Person::random_many(1000).len(), 1000);
+    /// ```
+    pub fn random_many(n: usize) -> Vec<Self> {
+        Self::random_many_custom_rng(&mut rand::thread_rng(), n)
+    }
+
+    /// Same as [`Person::random_many`], but uses the given random number generator.
+    pub fn random_many_custom_rng<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<Self> {
+        Self::random_many_with_config_custom_rng(rng, n, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::random_many`], but uses the probabilities of the given config.
+    pub fn random_many_with_config(n: usize, config: &GenerationConfig) -> Vec<Self> {
+        Self::random_many_with_config_custom_rng(&mut rand::thread_rng(), n, config)
+    }
+
+    /// Same as [`Person::random_many_with_config`], but uses the given random number generator.
+    pub fn random_many_with_config_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        n: usize,
+        config: &GenerationConfig,
+    ) -> Vec<Self> {
+        let now = Utc::now();
+        let min = now - Duration::days(366 * 100);
+        let mut persons = Vec::with_capacity(n);
+        for _ in 0..n {
+            persons.push(Self::random_with_dob_range_and_config_custom_rng(
+                rng, min, now, config,
+            ));
+        }
+        persons
+    }
+
+    /// Returns an infinite iterator of random `Person`s, see [`PersonIter`].
+    pub fn iter() -> PersonIter {
+        PersonIter::new(rand::thread_rng())
+    }
+
+    /// Same as [`Person::iter`], but uses the given random number generator.
+    pub fn iter_custom_rng<R: Rng>(rng: R) -> PersonIter<R> {
+        PersonIter::new(rng)
+    }
+
+    /// Creates a [`PersonBuilder`] to customize individual fields of the `Person`.
+    pub fn builder() -> PersonBuilder {
+        PersonBuilder::new()
+    }
+
+    pub fn get_first_name(&self) -> String {
+        self.first_name.clone()
+    }
+
+    /// Returns the first middle name, see [`Person::get_middle_names`] for all of them.
+    pub fn get_middle_name(&self) -> Option<String> {
+        self.middle_names.first().cloned()
+    }
+
+    pub fn get_middle_names(&self) -> Vec<String> {
+        self.middle_names.clone()
+    }
+
+    /// Returns the last name, which is empty for mononyms.
+    pub fn get_last_name(&self) -> String {
+        self.last_name.clone()
+    }
+
+    /// Returns whether the `Person` only has a single name and no last name, as is common in several cultures.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let person: Person = "Sukarno".parse().unwrap();
+    /// assert!(person.is_mononym());
+    /// assert_eq!(person.get_full_name(), "Sukarno");
+    /// ```
+    pub fn is_mononym(&self) -> bool {
+        self.last_name.is_empty()
+    }
+
+    /// Returns the gender, which is `None` unless it was requested during generation or set explicitly.
+    pub fn get_gender(&self) -> Option<Gender> {
+        self.gender
+    }
+
+    /// Returns the pronouns, which are derived from the gender unless they were randomized or set explicitly.
+    /// ## Example
+    /// ```rust
+    /// use person::{Gender, Person, Pronouns};
+    /// let person = Person::random_with_gender(Gender::Male);
+    /// assert_eq!(person.get_pronouns(), Pronouns::HE);
+    /// assert_eq!(person.get_pronouns().to_string(), "he/him");
+    /// let person = person.modify(|p| p.set_pronouns(Some(Pronouns::THEY)));
+    /// assert_eq!(person.get_pronouns().possessive, "theirs");
+    /// ```
+    pub fn get_pronouns(&self) -> Pronouns {
+        self.pronouns
+            .unwrap_or_else(|| Pronouns::for_gender(self.gender))
+    }
+
+    /// Returns the cultural origin the names were picked from, which is `None` unless it was requested
+    /// during generation or set explicitly.
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Origin, Person};
+    /// let config = GenerationConfig {
+    ///     origins: vec![Origin::Japanese],
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// assert_eq!(person.get_origin(), Some(Origin::Japanese));
+    /// assert!(Origin::Japanese.surnames().contains(&person.get_last_name().as_str()));
+    /// ```
+    pub fn get_origin(&self) -> Option<Origin> {
+        self.origin
+    }
+
+    /// Returns the physical attributes, which are `None` unless they were generated or set explicitly.
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     physique_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let physique = Person::random_with_config(&config).get_physique().unwrap();
+    /// assert!(physique.height_cm >= 40);
+    /// ```
+    pub fn get_physique(&self) -> Option<Physique> {
+        self.physique
+    }
+
+    /// Returns the phone number, which is `None` unless it was generated or set explicitly.
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     phone_number_probability: 1.0,
+    ///     phone_countries: vec![Country::Germany],
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let phone_number = Person::random_with_config(&config).get_phone_number().unwrap();
+    /// assert!(phone_number.e164().starts_with("+491"));
+    /// ```
+    pub fn get_phone_number(&self) -> Option<PhoneNumber> {
+        self.phone_number.clone()
+    }
+
+    /// Returns the postal address, which is `None` unless it was generated or set explicitly.
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     address_probability: 1.0,
+    ///     address_countries: vec![Country::Japan],
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let address = Person::random_with_config(&config).get_address().unwrap();
+    /// assert_eq!(address.country, Country::Japan);
+    /// ```
+    pub fn get_address(&self) -> Option<Address> {
+        self.address.clone()
+    }
+
+    /// Returns the place of birth, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::place_of_birth_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, GenerationConfig, Origin, Person};
+    /// let config = GenerationConfig {
+    ///     place_of_birth_probability: 1.0,
+    ///     origins: vec![Origin::German],
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let place_of_birth = Person::random_with_config(&config).get_place_of_birth().unwrap();
+    /// assert_eq!(place_of_birth.country, Country::Germany);
+    /// ```
+    pub fn get_place_of_birth(&self) -> Option<PlaceOfBirth> {
+        self.place_of_birth.clone()
+    }
+
+    /// Returns the financial profile, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::financial_profile_probability`].
+    /// ## Example
+    /// ```rust
+    /// use chrono::{Months, Utc};
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     financial_profile_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::builder()
+    ///     .config(config)
+    ///     .date_of_birth(Utc::now() - Months::new(12 * 40))
+    ///     .build();
+    /// let profile = person.get_financial_profile().unwrap();
+    /// assert!((300..=850).contains(&profile.credit_score));
+    /// assert_eq!(profile.us_bank_account.unwrap().holder, person.get_full_name());
+    /// ```
+    pub fn get_financial_profile(&self) -> Option<FinancialProfile> {
+        self.financial_profile.clone()
+    }
+
+    /// Returns the health record, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::health_record_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{Condition, GenerationConfig, Person, Title};
+    /// let config = GenerationConfig {
+    ///     health_record_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let child = Person::builder().config(config).date_of_birth(chrono::Utc::now()).build();
+    /// let record = child.get_health_record().unwrap();
+    /// assert_eq!(record.medical_record_number.len(), 8);
+    /// assert_eq!(record.primary_physician.get_title(), Some(Title::Dr));
+    /// assert!(!record.conditions.contains(&Condition::Hypertension));
+    /// ```
+    pub fn get_health_record(&self) -> Option<HealthRecord> {
+        self.health_record.clone()
+    }
+
+    /// Returns the occupation, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::occupation_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Industry, Occupation, Person, Seniority};
+    /// let config = GenerationConfig {
+    ///     occupation_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let child = Person::builder().config(config).date_of_birth(chrono::Utc::now()).build();
+    /// assert_eq!(child.get_occupation(), None);
+    ///
+    /// let occupation = Occupation {
+    ///     industry: Industry::Healthcare,
+    ///     seniority: Seniority::Mid,
+    ///     title: "Registered Nurse".to_string(),
+    /// };
+    /// let nurse = Person::builder().occupation(occupation.clone()).build();
+    /// assert_eq!(nurse.get_occupation(), Some(occupation));
+    /// ```
+    pub fn get_occupation(&self) -> Option<Occupation> {
+        self.occupation.clone()
+    }
+
+    /// Returns the completed education, lowest level first, which is empty unless it was generated
+    /// or set explicitly, see [`GenerationConfig::education_probability`].
+    /// Graduations line up with the date of birth, like a bachelor's degree at 22 to 24.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{Datelike, Utc};
+    /// use person::{DegreeLevel, GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     education_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// let birth_year = person.get_date_of_birth().year();
+    /// for education in person.get_education() {
+    ///     assert!(education.graduation_year >= birth_year + 18);
+    ///     assert!(education.graduation_year < Utc::now().year());
+    ///     if education.level == DegreeLevel::Doctorate {
+    ///         assert!(education.graduation_year >= birth_year + 28);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_education(&self) -> Vec<Education> {
+        self.education.clone()
+    }
+
+    /// Returns the hobbies and interests, which are empty unless they were generated or set
+    /// explicitly, see [`GenerationConfig::interests_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     interests_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// assert!((2..=5).contains(&person.get_interests().len()));
+    /// ```
+    pub fn get_interests(&self) -> Vec<Interest> {
+        self.interests.clone()
+    }
+
+    /// Returns the skills with their proficiencies, which are empty unless they were generated or set
+    /// explicitly, see [`GenerationConfig::interests_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     interests_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// let age = person.get_age();
+    /// assert!(person.get_skills().iter().all(|s| s.skill.min_age() <= age));
+    /// ```
+    pub fn get_skills(&self) -> Vec<SkillProficiency> {
+        self.skills.clone()
+    }
+
+    /// Returns the personality, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::personality_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     personality_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let personality = Person::random_with_config(&config).get_personality().unwrap();
+    /// assert!(personality.openness <= 100);
+    /// assert_eq!(personality.mbti_type().len(), 4);
+    /// ```
+    pub fn get_personality(&self) -> Option<Personality> {
+        self.personality
+    }
+
+    /// Returns the marital status, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::marital_status_probability`].
+    /// ## Example
+    /// ```rust
+    /// use chrono::{Duration, Utc};
+    /// use person::{GenerationConfig, MaritalStatus, PersonBuilder};
+    /// let config = GenerationConfig {
+    ///     marital_status_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let child = PersonBuilder::new()
+    ///     .config(config)
+    ///     .date_of_birth(Utc::now() - Duration::days(365 * 7))
+    ///     .build();
+    /// assert_eq!(child.get_marital_status(), Some(MaritalStatus::Single));
+    /// ```
+    pub fn get_marital_status(&self) -> Option<MaritalStatus> {
+        self.marital_status
+    }
+
+    /// Returns the languages the `Person` speaks, best spoken first, which are empty unless they
+    /// were generated or set explicitly, see [`GenerationConfig::languages_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, GenerationConfig, Language, LanguageLevel, Person};
+    /// let config = GenerationConfig {
+    ///     languages_probability: 1.0,
+    ///     nationalities: vec![Country::Germany],
+    ///     address_probability: 1.0,
+    ///     address_countries: vec![Country::Germany],
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let languages = Person::random_with_config(&config).get_languages();
+    /// assert_eq!(languages[0].language, Language::German);
+    /// assert_eq!(languages[0].level, LanguageLevel::Native);
+    /// ```
+    pub fn get_languages(&self) -> Vec<LanguageProficiency> {
+        self.languages.clone()
+    }
+
+    /// Returns the religion or belief, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::religion_probability`].
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// assert_eq!(Person::random().get_religion(), None);
+    /// let config = GenerationConfig {
+    ///     religion_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// assert!(Person::random_with_config(&config).get_religion().is_some());
+    /// ```
+    pub fn get_religion(&self) -> Option<Religion> {
+        self.religion
+    }
+
+    /// Returns the primary nationality, which is `None` unless it was generated or set explicitly,
+    /// see [`GenerationConfig::nationalities`].
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     nationalities: vec![Country::France],
+    ///     dual_citizenship_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// assert_eq!(person.get_nationality(), Some(Country::France));
+    /// assert_eq!(person.get_nationalities().len(), 2);
+    /// assert_eq!(person.get_nationality().unwrap().code(), "FR");
+    /// ```
+    pub fn get_nationality(&self) -> Option<Country> {
+        self.nationalities.first().copied()
+    }
+
+    /// Returns all countries the `Person` is a citizen of, the primary nationality first.
+    pub fn get_nationalities(&self) -> Vec<Country> {
+        self.nationalities.clone()
+    }
+
+    /// Returns whether the `Person` is a citizen of the country.
+    pub fn is_citizen_of(&self, country: Country) -> bool {
+        self.nationalities.contains(&country)
+    }
+
+    /// Returns the time zone the `Person` lives in: the one of their address, or of their
+    /// phone number's country if they have no address.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{Address, Country, Person, Timezone};
+    /// let dob = Utc.with_ymd_and_hms(1990, 1, 1, 3, 0, 0).unwrap();
+    /// let mut person = Person::from_parts("Jane", None, "Doe", dob);
+    /// assert_eq!(person.get_timezone(), None);
+    /// person.set_address(Some(Address::random(Country::Japan)));
+    /// assert_eq!(person.get_timezone(), Some(Timezone::Tokyo));
+    /// assert_eq!(person.get_local_dob().to_rfc3339(), "1990-01-01T12:00:00+09:00");
+    /// ```
+    pub fn get_timezone(&self) -> Option<Timezone> {
+        match (&self.address, &self.phone_number) {
+            (Some(address), _) => Some(address.timezone()),
+            (None, Some(phone_number)) => Some(Timezone::for_country(phone_number.country())),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the date of birth in the local time of the `Person`'s [time zone](Person::get_timezone),
+    /// or in UTC if it is unknown.
+    pub fn get_local_dob(&self) -> DateTime<FixedOffset> {
+        match self.get_timezone() {
+            Some(timezone) => timezone.to_local(self.date_of_birth),
+            None => self.date_of_birth.fixed_offset(),
+        }
+    }
+
+    pub fn get_title(&self) -> Option<Title> {
+        self.title
+    }
+
+    pub fn get_suffix(&self) -> Option<Suffix> {
+        self.suffix
+    }
+
+    /// Returns the last names the `Person` had before their current one, oldest first.
+    pub fn get_former_names(&self) -> Vec<String> {
+        self.former_names.clone()
+    }
+
+    /// Returns the last name the `Person` was born with, if it differs from their current one.
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     former_name_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// assert_eq!(person.get_maiden_name(), person.get_former_names().first().cloned());
+    /// assert!(person.get_maiden_name().is_some());
+    /// ```
+    pub fn get_maiden_name(&self) -> Option<String> {
+        self.former_names.first().cloned()
+    }
+
+    pub fn get_date_of_birth(&self) -> DateTime<Utc> {
+        self.date_of_birth
+    }
+
+    /// Returns the date of death, which is `None` for `Person`s that are alive.
+    pub fn get_date_of_death(&self) -> Option<DateTime<Utc>> {
+        self.date_of_death
+    }
+
+    /// Returns whether the `Person` is alive.
+    /// ## Example
+    /// ```rust
+    /// use person::{GenerationConfig, Person};
+    /// let config = GenerationConfig {
+    ///     deceased_probability: 1.0,
+    ///     ..GenerationConfig::default()
+    /// };
+    /// let person = Person::random_with_config(&config);
+    /// assert!(!person.is_alive());
+    /// assert!(person.get_date_of_death().unwrap() >= person.get_date_of_birth());
+    /// ```
+    pub fn is_alive(&self) -> bool {
+        self.date_of_death.is_none()
+    }
+
+    /// Returns the generational cohort the `Person` was born in, or `None` if they were born before 1901.
+    pub fn get_generation(&self) -> Option<Generation> {
+        Generation::from_birth_year(self.date_of_birth.year())
+    }
+
+    /// Returns the start of the `Person`'s next birthday on or after the given date.
+    /// Birthdays on February 29 are celebrated on February 28 in non-leap years,
+    /// see [`Person::next_birthday_with_policy`] to change this.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::Person;
+    /// let dob = Utc.with_ymd_and_hms(1990, 1, 5, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("Jane", None, "Doe", dob);
+    /// let after = Utc.with_ymd_and_hms(2023, 12, 30, 12, 0, 0).unwrap();
+    /// assert_eq!(person.next_birthday(after), Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap());
+    /// assert!(person.is_birthday(Utc.with_ymd_and_hms(2024, 1, 5, 18, 0, 0).unwrap()));
+    /// ```
+    pub fn next_birthday(&self, after: DateTime<Utc>) -> DateTime<Utc> {
+        self.next_birthday_with_policy(after, LeapDayPolicy::default())
+    }
+
+    /// Same as [`Person::next_birthday`], but uses the given policy for birthdays on February 29.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{LeapDayPolicy, Person};
+    /// let dob = Utc.with_ymd_and_hms(2000, 2, 29, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("Jane", None, "Doe", dob);
+    /// assert!(person.is_leap_day_birthday());
+    /// let after = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    /// let birthday = person.next_birthday_with_policy(after, LeapDayPolicy::March1);
+    /// assert_eq!(birthday, Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap());
+    /// let birthday = person.next_birthday_with_policy(after, LeapDayPolicy::February28);
+    /// assert_eq!(birthday, Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap());
+    /// ```
+    pub fn next_birthday_with_policy(
+        &self,
+        after: DateTime<Utc>,
+        policy: LeapDayPolicy,
+    ) -> DateTime<Utc> {
+        let date = after.date_naive();
+        let birthday = match self.birthday_in(date.year(), policy) {
+            birthday if birthday >= date => birthday,
+            _ => self.birthday_in(date.year() + 1, policy),
+        };
+        birthday.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// Returns the number of days until the `Person`'s next birthday, which is 0 on their birthday.
+    pub fn days_until_birthday(&self) -> u32 {
+        let now = Utc::now();
+        (self.next_birthday(now).date_naive() - now.date_naive()).num_days() as u32
+    }
+
+    /// Returns whether the given date is the `Person`'s birthday.
+    pub fn is_birthday(&self, date: DateTime<Utc>) -> bool {
+        self.is_birthday_with_policy(date, LeapDayPolicy::default())
+    }
+
+    /// Same as [`Person::is_birthday`], but uses the given policy for birthdays on February 29.
+    pub fn is_birthday_with_policy(&self, date: DateTime<Utc>, policy: LeapDayPolicy) -> bool {
+        let date = date.date_naive();
+        self.birthday_in(date.year(), policy) == date
+    }
+
+    /// Returns whether the `Person` was born on February 29.
+    pub fn is_leap_day_birthday(&self) -> bool {
+        self.date_of_birth.month() == 2 && self.date_of_birth.day() == 29
+    }
+
+    /// Returns the date of the `Person`'s birthday in the given year.
+    fn birthday_in(&self, year: i32, policy: LeapDayPolicy) -> NaiveDate {
+        self.date_of_birth
+            .date_naive()
+            .with_year(year)
+            .unwrap_or_else(|| policy.birthday_in(year))
+    }
+
+    /// Returns whether the `Person` is at least the given number of years old.
+    pub fn is_at_least(&self, years: u32) -> bool {
+        self.get_age() >= years
+    }
+
+    /// Returns whether the `Person` has reached the age of majority in the given jurisdiction.
+    pub fn is_adult(&self, jurisdiction: Jurisdiction) -> bool {
+        self.is_at_least(jurisdiction.age_of_majority())
+    }
+
+    /// Returns the western zodiac sign of the `Person`'s date of birth.
+    pub fn get_zodiac_sign(&self) -> ZodiacSign {
+        ZodiacSign::from_date(self.date_of_birth)
+    }
+
+    /// Returns the Chinese zodiac animal of the `Person`'s year of birth, see [`ChineseZodiac::from_year`].
+    pub fn get_chinese_zodiac(&self) -> ChineseZodiac {
+        ChineseZodiac::from_year(self.date_of_birth.year())
+    }
+
+    /// Returns the elapsed years since the `Person`'s date of birth
+    pub fn get_age(&self) -> u32 {
+        self.age_at(Utc::now())
+    }
+
+    /// Returns the elapsed years between the `Person`'s date of birth and the given reference date,
+    /// or 0 if the reference date is before the date of birth. The age stops at the date of death.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::Person;
+    /// let person = Person::random().modify(|p| {
+    ///     p.set_date_of_birth(Utc.with_ymd_and_hms(2000, 6, 15, 0, 0, 0).unwrap())
+    /// });
+    /// assert_eq!(person.age_at(Utc.with_ymd_and_hms(2020, 6, 14, 0, 0, 0).unwrap()), 19);
+    /// assert_eq!(person.age_at(Utc.with_ymd_and_hms(2020, 6, 15, 0, 0, 0).unwrap()), 20);
+    /// ```
+    pub fn age_at(&self, reference: DateTime<Utc>) -> u32 {
+        let reference = self
+            .date_of_death
+            .map_or(reference, |date_of_death| date_of_death.min(reference));
+        reference.years_since(self.date_of_birth).unwrap_or(0)
+    }
+
+    /// Returns the person's full name, including the middle names and suffix.
+    /// ## Example
+    /// ```rust
+    /// use person::{Person, Suffix};
+    /// let person: Person = "John Quincy Adams Jr.".parse().unwrap();
+    /// assert_eq!(person.get_suffix(), Some(Suffix::Jr));
+    /// assert_eq!(person.get_last_name(), "Adams");
+    /// assert_eq!(person.get_full_name(), "John Quincy Adams Jr.");
+    /// ```
+    pub fn get_full_name(&self) -> String {
+        let mut names = vec![self.first_name.as_str()];
+        names.extend(self.middle_names.iter().map(String::as_str));
+        if !self.is_mononym() {
+            names.push(&self.last_name);
+        }
+        if let Some(suffix) = self.suffix {
+            names.push(suffix.as_str());
+        }
+        names.join(" ")
+    }
+
+    /// Returns the person's full name prefixed with their title, if they have one.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Person, Title};
+    /// let person = Person::from_parts("Jane", None, "Doe", Utc::now());
+    /// assert_eq!(person.get_full_name_with_title(), "Jane Doe");
+    /// let person = person.modify(|p| p.set_title(Some(Title::Dr)));
+    /// assert_eq!(person.get_full_name_with_title(), "Dr. Jane Doe");
+    /// ```
+    pub fn get_full_name_with_title(&self) -> String {
+        match self.title {
+            Some(title) => format!("{title} {}", self.get_full_name()),
+            None => self.get_full_name(),
+        }
+    }
+
+    /// Returns the person's full name with shortened middle names.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("Jane", None, "Doe", Utc::now())
+    ///     .with_middle_names(["Quinn", "Rose"]);
+    /// assert_eq!(person.get_full_name(), "Jane Quinn Rose Doe");
+    /// assert_eq!(person.get_short_full_name(), "Jane Q. R. Doe");
+    /// ```
+    pub fn get_short_full_name(&self) -> String {
+        let mut names = vec![self.given_names_with_initials()];
+        if !self.is_mononym() {
+            names.push(self.last_name.clone());
+        }
+        if let Some(suffix) = self.suffix {
+            names.push(suffix.to_string());
+        }
+        names.join(" ")
+    }
+
+    /// Returns a view of the `Person` that masks names and the date of birth when formatted,
+    /// for logging where even synthetic identities must not appear in clear text.
+    pub fn redacted(&self) -> Redacted<'_> {
+        Redacted(self)
+    }
+
+    /// Returns the name in the given layout.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{NameFormat, Person};
+    /// let person = Person::from_parts("Jane", Some("Quinn"), "Doe", Utc::now());
+    /// assert_eq!(person.format_name(NameFormat::FirstLast), "Jane Doe");
+    /// assert_eq!(person.format_name(NameFormat::UpperLastFirst), "DOE, Jane");
+    /// assert_eq!(person.format_name(NameFormat::InitialsLast), "J. Q. Doe");
+    /// assert_eq!(person.format_name(NameFormat::Initials), "J. Q. D.");
+    /// ```
+    pub fn format_name(&self, format: NameFormat) -> String {
+        let with_suffix = |name: String, separator: &str| match self.suffix {
+            Some(suffix) => format!("{name}{separator}{suffix}"),
+            None => name,
+        };
+        match format {
+            NameFormat::Full => self.get_full_name(),
+            NameFormat::FullWithTitle => self.get_full_name_with_title(),
+            NameFormat::Short => self.get_short_full_name(),
+            NameFormat::LastFirstInitial => self.get_sortable_name(),
+            NameFormat::FirstLast if self.is_mononym() => with_suffix(self.first_name.clone(), " "),
+            NameFormat::FirstLast => {
+                with_suffix(format!("{} {}", self.first_name, self.last_name), " ")
+            }
+            NameFormat::LastFirst | NameFormat::UpperLastFirst if self.is_mononym() => {
+                with_suffix(self.first_name.clone(), ", ")
+            }
+            NameFormat::LastFirst => {
+                with_suffix(format!("{}, {}", self.last_name, self.first_name), ", ")
+            }
+            NameFormat::UpperLastFirst => with_suffix(
+                format!("{}, {}", self.last_name.to_uppercase(), self.first_name),
+                ", ",
+            ),
+            NameFormat::InitialsLast => {
+                let mut names = vec![initials(&self.first_name)];
+                names.extend(self.middle_names.iter().map(|mn| initials(mn)));
+                if !self.is_mononym() {
+                    names.push(self.last_name.clone());
+                }
+                with_suffix(names.join(" "), " ")
+            }
+            NameFormat::Initials => {
+                let mut names = vec![self.first_name.as_str()];
+                names.extend(self.middle_names.iter().map(String::as_str));
+                names.extend(self.last_name.split([' ', '-']));
+                names
+                    .into_iter()
+                    .filter(|name| !name.is_empty())
+                    .map(initials)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+        }
+    }
+
+    /// Returns the full name with accented letters replaced by their closest ASCII equivalent,
+    /// for systems that can't handle diacritics.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("José", None, "Müller-Øster", Utc::now());
+    /// assert_eq!(person.get_ascii_name(), "Jose Muller-Oster");
+    /// ```
+    pub fn get_ascii_name(&self) -> String {
+        transliterate::to_ascii(&self.get_full_name())
+    }
+
+    /// Returns a lowercase, URL-safe slug of the short full name, e.g. "jane-q-doe" for profile URLs.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Person, SlugCase};
+    /// let person = Person::from_parts("Zoë", Some("Quinn"), "O'Brien-Smith", Utc::now());
+    /// assert_eq!(person.get_slug(), "zoe-q-obrien-smith");
+    /// assert_eq!(person.get_slug_with("_", SlugCase::Preserve), "Zoe_Q_OBrien_Smith");
+    /// ```
+    pub fn get_slug(&self) -> String {
+        self.get_slug_with("-", SlugCase::Lower)
+    }
+
+    /// Same as [`Person::get_slug`], but joins the words with the given separator and uses the given case.
+    pub fn get_slug_with(&self, separator: &str, case: SlugCase) -> String {
+        slug::slugify(&self.get_short_full_name(), separator, case)
+    }
+
+    /// Returns the [`soundex`] codes of the first and last name separated by a space, e.g. "J500 D000",
+    /// for testing fuzzy matching. Mononyms only have a single code.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("Jon", None, "Smith", Utc::now());
+    /// assert_eq!(person.get_soundex(), "J500 S530");
+    /// assert_eq!(person.get_soundex(), person.with_first_name("John").with_last_name("Smyth").get_soundex());
+    /// ```
+    pub fn get_soundex(&self) -> String {
+        self.phonetic_key(soundex)
+    }
+
+    /// Returns the [`metaphone`] codes of the first and last name separated by a space, e.g. "JN SM0".
+    /// Mononyms only have a single code.
+    pub fn get_metaphone(&self) -> String {
+        self.phonetic_key(metaphone)
+    }
+
+    fn phonetic_key(&self, encode: fn(&str) -> String) -> String {
+        if self.is_mononym() {
+            encode(&self.first_name)
+        } else {
+            format!("{} {}", encode(&self.first_name), encode(&self.last_name))
+        }
+    }
+
+    /// Returns the name in "Last, First M." order, as used in address books and bibliographies.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Person, Suffix};
+    /// let person = Person::from_parts("Jane", Some("Quinn"), "Doe", Utc::now());
+    /// assert_eq!(person.get_sortable_name(), "Doe, Jane Q.");
+    /// let person = person.modify(|p| p.set_suffix(Some(Suffix::Jr)));
+    /// assert_eq!(person.get_sortable_name(), "Doe, Jane Q., Jr.");
+    /// ```
+    pub fn get_sortable_name(&self) -> String {
+        let given_names = self.given_names_with_initials();
+        let mut name = if self.is_mononym() {
+            given_names
+        } else {
+            format!("{}, {given_names}", self.last_name)
+        };
+        if let Some(suffix) = self.suffix {
+            name = format!("{name}, {suffix}");
+        }
+        name
+    }
+
+    /// Returns a case-insensitive key for sorting `Person`s by their sortable name,
+    /// following the given collation rules.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Collation, Person};
+    /// let mut persons = vec![
+    ///     Person::from_parts("Anna", None, "van der Berg", Utc::now()),
+    ///     Person::from_parts("Jane", None, "Doe", Utc::now()),
+    /// ];
+    /// persons.sort_by_key(|p| p.get_index_name(Collation::IgnoreParticles));
+    /// assert_eq!(persons[0].get_last_name(), "van der Berg");
+    /// assert_eq!(persons[0].get_index_name(Collation::IgnoreParticles), "berg, anna van der");
+    /// assert_eq!(persons[0].get_index_name(Collation::Literal), "van der berg, anna");
+    /// ```
+    pub fn get_index_name(&self, collation: Collation) -> String {
+        match collation {
+            Collation::Literal => self.get_sortable_name().to_lowercase(),
+            Collation::IgnoreParticles => {
+                let words: Vec<_> = self.last_name.split(' ').collect();
+                let particles = words
+                    .iter()
+                    .take(words.len().saturating_sub(1))
+                    .take_while(|word| is_surname_particle(word))
+                    .count();
+                if particles == 0 {
+                    return self.get_sortable_name().to_lowercase();
+                }
+                let mut key = format!(
+                    "{}, {} {}",
+                    words[particles..].join(" "),
+                    self.given_names_with_initials(),
+                    words[..particles].join(" ")
+                );
+                if let Some(suffix) = self.suffix {
+                    key = format!("{key}, {suffix}");
+                }
+                key.to_lowercase()
+            }
+            Collation::Custom(key) => key(self),
+        }
+    }
+
+    /// Returns the first name followed by the middle initials, e.g. "Jane Q.".
+    fn given_names_with_initials(&self) -> String {
+        let mut names = vec![self.first_name.clone()];
+        names.extend(
+            self.middle_names
+                .iter()
+                .filter(|mn| !mn.is_empty())
+                .map(|mn| initials(mn)),
+        );
+        names.join(" ")
+    }
+
+    /// Returns a greeting for the `Person` in the given style, e.g. for email templates.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Gender, Person, SalutationStyle, Title};
+    /// let person = Person::from_parts("William", None, "Doe", Utc::now());
+    /// assert_eq!(person.get_salutation(SalutationStyle::Formal), "Dear William Doe,");
+    /// assert_eq!(person.get_salutation(SalutationStyle::Informal), "Hi William,");
+    /// assert_eq!(person.get_salutation(SalutationStyle::Casual), "Hey Will!");
+    /// let person = person.with_gender(Some(Gender::Male));
+    /// assert_eq!(person.get_salutation(SalutationStyle::Formal), "Dear Mr. Doe,");
+    /// let person = person.modify(|p| p.set_title(Some(Title::Dr)));
+    /// assert_eq!(person.get_salutation(SalutationStyle::Formal), "Dear Dr. Doe,");
+    /// ```
+    pub fn get_salutation(&self, style: SalutationStyle) -> String {
+        match style {
+            SalutationStyle::Formal => {
+                let title = self.title.or(match self.gender {
+                    Some(Gender::Female) => Some(Title::Ms),
+                    Some(Gender::Male) => Some(Title::Mr),
+                    Some(Gender::NonBinary) => Some(Title::Mx),
+                    None => None,
+                });
+                match title {
+                    Some(title) if !self.is_mononym() => {
+                        format!("Dear {title} {},", self.last_name)
+                    }
+                    _ if self.is_mononym() => format!("Dear {},", self.first_name),
+                    _ => format!("Dear {} {},", self.first_name, self.last_name),
+                }
+            }
+            SalutationStyle::Personal => format!("Dear {},", self.first_name),
+            SalutationStyle::Informal => format!("Hi {},", self.first_name),
+            SalutationStyle::Casual => format!("Hey {}!", self.get_nickname()),
+        }
+    }
+
+    /// Returns the most common nickname for the `Person`'s first name, e.g. "Will" for "William".
+    /// Names without a known nickname are shortened instead, e.g. "Maximilian" becomes "Max".
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("William", None, "Doe", Utc::now());
+    /// assert_eq!(person.get_nickname(), "Will");
+    /// assert!(person.get_nicknames().contains(&"Bill".to_string()));
+    /// assert_eq!(person.with_first_name("Maximilian").get_nickname(), "Max");
+    /// ```
+    pub fn get_nickname(&self) -> String {
+        self.get_nicknames().swap_remove(0)
+    }
+
+    /// Returns all known nicknames for the `Person`'s first name, falling back to the shortened name.
+    pub fn get_nicknames(&self) -> Vec<String> {
+        match nickname::nicknames_for(&self.first_name) {
+            [] => vec![nickname::truncate_name(&self.first_name)],
+            nicknames => nicknames.iter().map(|n| n.to_string()).collect(),
+        }
+    }
+
+    /// Returns a random nickname out of [`Person::get_nicknames`].
+    pub fn get_random_nickname(&self) -> String {
+        self.get_random_nickname_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_nickname`], but uses the given random number generator.
+    pub fn get_random_nickname_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.get_nicknames().choose(rng).unwrap().clone()
+    }
+
+    /// Generates a random username by using random separators, numbers and the person's identity.
+    pub fn get_random_username(&self) -> String {
+        self.get_random_username_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Generates a reproducible username: the same `Person` and seed always produce the same username
+    /// (as long as the `Person`'s age doesn't change).
+    pub fn get_seeded_username(&self, seed: u64) -> String {
+        self.get_random_username_custom_rng(&mut Self::seeded_rng(seed))
+    }
+
+    /// Same as [`Person::get_random_username`], but uses the given random number generator.
+    pub fn get_random_username_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.get_random_username_with_config_custom_rng(rng, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::get_random_username`], but uses the number styles, separators and
+    /// leetification rate of the given config.
+    pub fn get_random_username_with_config(&self, config: &GenerationConfig) -> String {
+        self.get_random_username_with_config_custom_rng(&mut rand::thread_rng(), config)
+    }
+
+    /// Same as [`Person::get_random_username_with_config`], but uses the given random number generator.
+    pub fn get_random_username_with_config_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        config: &GenerationConfig,
+    ) -> String {
+        self.random_username_at(rng, config, Utc::now())
+    }
+
+    /// Generates a random username, using the age at the given reference date as its number.
+    fn random_username_at<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        config: &GenerationConfig,
+        now: DateTime<Utc>,
+    ) -> String {
+        let number = match config.number_styles.choose(rng) {
+            Some(UsernameNumber::Random) => rng.gen_range(0..9999).to_string(),
+            Some(UsernameNumber::Age) => self.age_at(now).to_string(),
+            Some(UsernameNumber::BirthYear) => self.date_of_birth.year().to_string(),
+            Some(UsernameNumber::None) | None => "".into(),
+        };
+        let divisor = match config.separators.choose(rng) {
+            Some(UsernameSeparator::Hyphen) => "-".into(),
+            Some(UsernameSeparator::Underscore) => "_".into(),
+            Some(UsernameSeparator::Dot) => ".".into(),
+            Some(UsernameSeparator::MiddleInitial) => self
+                .get_middle_name()
+                .and_then(|middle_name| middle_name.chars().next())
+                .map_or(".".into(), |initial| initial.to_string()),
+            Some(UsernameSeparator::None) | None => "".into(),
+        };
+
+        let first_name = if generator::chance(rng, config.username_nickname_probability) {
+            self.get_random_nickname_custom_rng(rng)
+        } else {
+            self.first_name.clone()
+        };
+        let first_name = username_part(&first_name, config.ascii_usernames);
+        let last_name = username_part(&self.last_name, config.ascii_usernames);
+        let mut parts = vec![];
+        if self.is_mononym() {
+            parts.push(repeat_last_char(&first_name, rng.gen_range(0..2)));
+        } else if rng.gen_bool(0.70) {
+            parts.push(repeat_last_char(&first_name, rng.gen_range(0..2)));
+            parts.push(divisor);
+            parts.push(repeat_last_char(&last_name, rng.gen_range(0..2)));
+        } else {
+            parts.push(repeat_last_char(&last_name, rng.gen_range(0..2)));
+            parts.push(divisor);
+            parts.push(repeat_last_char(&first_name, rng.gen_range(0..2)));
+        }
+        parts.push(number);
+
+        leetify_string(
+            rng,
+            &parts.join(""),
+            &config.leet_map,
+            config.leet_probability,
+        )
+    }
+}
+/// Allows generating random `Person`s with `rng.gen::<Person>()`, same as [`Person::random_custom_rng`].
+/// ## Example
+/// ```rust
+/// use person::Person;
+/// use rand::{distributions::Standard, Rng};
+/// let mut rng = rand::thread_rng();
+/// let person: Person = rng.gen();
+/// let persons: Vec<Person> = rng.sample_iter(Standard).take(10).collect();
+/// ```
+impl Distribution<Person> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Person {
+        Person::random_custom_rng(rng)
+    }
+}
+/// Parses a full name with [`Person::parse_full_name`], using the Unix epoch as the date of birth.
+impl std::str::FromStr for Person {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_full_name(s, DateTime::UNIX_EPOCH)
+    }
+}
+impl TryFrom<&str> for Person {
+    type Error = ParseNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+impl Ord for Person {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.last_name
+            .cmp(&other.last_name)
+            .then_with(|| self.first_name.cmp(&other.first_name))
+            .then_with(|| self.date_of_birth.cmp(&other.date_of_birth))
+            .then_with(|| self.middle_names.cmp(&other.middle_names))
+            .then_with(|| self.gender.cmp(&other.gender))
+            .then_with(|| self.pronouns.cmp(&other.pronouns))
+            .then_with(|| self.title.cmp(&other.title))
+            .then_with(|| self.suffix.cmp(&other.suffix))
+            .then_with(|| self.former_names.cmp(&other.former_names))
+            .then_with(|| self.origin.cmp(&other.origin))
+            .then_with(|| self.date_of_death.cmp(&other.date_of_death))
+            .then_with(|| self.physique.cmp(&other.physique))
+            .then_with(|| self.phone_number.cmp(&other.phone_number))
+            .then_with(|| self.address.cmp(&other.address))
+            .then_with(|| self.place_of_birth.cmp(&other.place_of_birth))
+            .then_with(|| self.nationalities.cmp(&other.nationalities))
+            .then_with(|| self.financial_profile.cmp(&other.financial_profile))
+            .then_with(|| self.health_record.cmp(&other.health_record))
+            .then_with(|| self.occupation.cmp(&other.occupation))
+            .then_with(|| self.education.cmp(&other.education))
+            .then_with(|| self.interests.cmp(&other.interests))
+            .then_with(|| self.skills.cmp(&other.skills))
+            .then_with(|| self.personality.cmp(&other.personality))
+            .then_with(|| self.marital_status.cmp(&other.marital_status))
+            .then_with(|| self.languages.cmp(&other.languages))
+            .then_with(|| self.religion.cmp(&other.religion))
+    }
+}
+impl PartialOrd for Person {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// Displays the short full name and age like "Jane Q. Doe, 34".
+/// The alternate form (`{:#}`) uses the full name with title instead, like "Dr. Jane Quinn Doe, 34".
+impl std::fmt::Display for Person {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = if f.alternate() {
+            self.get_full_name_with_title()
+        } else {
+            self.get_short_full_name()
+        };
+        write!(f, "{name}, {}", self.get_age())
+    }
+}
+
+fn random_date_between<R: Rng + ?Sized>(
+    rng: &mut R,
+    min: DateTime<Utc>,
+    max: DateTime<Utc>,
+) -> Result<DateTime<Utc>, PersonError> {
+    let range_millis = (max - min).num_milliseconds();
+    if range_millis <= 0 {
+        return Err(PersonError::InvalidDobRange { min, max });
+    }
+    Ok(min + Duration::milliseconds(rng.gen_range(0..range_millis)))
+}
+
+/// Abbreviates a name to its initials, keeping hyphens, e.g. "Anne-Marie" becomes "A.-M.".
+fn initials(name: &str) -> String {
+    name.split('-')
+        .filter_map(|part| part.chars().next())
+        .map(|initial| format!("{initial}."))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Removes spaces, hyphens and other punctuation from a name so it can be used in a username,
+/// optionally transliterating it to ASCII first.
+fn username_part(name: &str, ascii: bool) -> String {
+    let name = if ascii {
+        transliterate::to_ascii(name)
+    } else {
+        name.to_string()
+    };
+    name.chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+/// Returns whether the word is part of a surname particle, like "van" or "der".
+fn is_surname_particle(word: &str) -> bool {
+    list::SURNAME_PARTICLES
+        .iter()
+        .flat_map(|particle| particle.split(' '))
+        .any(|part| part == word)
+}
+
+/// Returns a string of `n` random decimal digits.
+fn random_digits<R: Rng + ?Sized>(rng: &mut R, n: usize) -> String {
+    (0..n)
+        .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+        .collect()
+}
+
+/// Samples a normal distribution with the Box-Muller transform.
+fn sample_normal<R: Rng + ?Sized>(rng: &mut R, mean: f64, std_dev: f64) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    mean + std_dev * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+fn repeat_last_char(s: &str, times: usize) -> String {
+    let mut result = s.to_string();
+    if let Some(last_char) = s.chars().last() {
+        for _ in 0..times {
+            result.push(last_char);
+        }
+    }
+    result
+}
+
+fn leetify_string<R: Rng + ?Sized>(
+    rng: &mut R,
+    input: &str,
+    leet_map: &LeetMap,
+    probability: f64,
+) -> String {
+    let mut result = String::new();
+
+    for (i, c) in input.chars().enumerate() {
+        if i == 0 || !generator::chance(rng, probability) {
+            result.push(c);
+        } else {
+            result.push(leet_map.get(c));
+        }
+    }
+
+    result
 }
@@ -92565,7 +92565,7 @@ pub static NAMES: [&str; 92566] = [
     "Zoyah",
     "Zuheyla",
     "Zuleikha",
-    "Zuriela"
+    "Zuriela",
 ];
 
 /// A long list of surnames.
@@ -244224,5 +244224,427 @@ pub static SURNAMES: [&str; 151655] = [
     "Zinnanti",
     "Zitterich",
     "Zulu",
-    "Zusi"
-];
\ No newline at end of file
+    "Zusi",
+];
+
+/// A list of common female first names.
+pub static FEMALE_NAMES: [&str; 206] = [
+    "Mary",
+    "Patricia",
+    "Jennifer",
+    "Linda",
+    "Elizabeth",
+    "Barbara",
+    "Susan",
+    "Jessica",
+    "Sarah",
+    "Karen",
+    "Lisa",
+    "Nancy",
+    "Betty",
+    "Margaret",
+    "Sandra",
+    "Ashley",
+    "Kimberly",
+    "Emily",
+    "Donna",
+    "Michelle",
+    "Carol",
+    "Amanda",
+    "Dorothy",
+    "Melissa",
+    "Deborah",
+    "Stephanie",
+    "Rebecca",
+    "Sharon",
+    "Laura",
+    "Cynthia",
+    "Kathleen",
+    "Amy",
+    "Angela",
+    "Shirley",
+    "Anna",
+    "Brenda",
+    "Pamela",
+    "Emma",
+    "Nicole",
+    "Helen",
+    "Samantha",
+    "Katherine",
+    "Christine",
+    "Debra",
+    "Rachel",
+    "Carolyn",
+    "Janet",
+    "Catherine",
+    "Maria",
+    "Heather",
+    "Diane",
+    "Ruth",
+    "Julie",
+    "Olivia",
+    "Joyce",
+    "Virginia",
+    "Victoria",
+    "Kelly",
+    "Lauren",
+    "Christina",
+    "Joan",
+    "Evelyn",
+    "Judith",
+    "Megan",
+    "Andrea",
+    "Cheryl",
+    "Hannah",
+    "Jacqueline",
+    "Martha",
+    "Gloria",
+    "Teresa",
+    "Ann",
+    "Sara",
+    "Madison",
+    "Frances",
+    "Kathryn",
+    "Janice",
+    "Jean",
+    "Abigail",
+    "Alice",
+    "Judy",
+    "Sophia",
+    "Grace",
+    "Denise",
+    "Amber",
+    "Doris",
+    "Marilyn",
+    "Danielle",
+    "Beverly",
+    "Isabella",
+    "Theresa",
+    "Diana",
+    "Natalie",
+    "Brittany",
+    "Charlotte",
+    "Marie",
+    "Kayla",
+    "Alexis",
+    "Lori",
+    "Ava",
+    "Mia",
+    "Harper",
+    "Amelia",
+    "Ella",
+    "Chloe",
+    "Lily",
+    "Zoe",
+    "Nora",
+    "Aria",
+    "Scarlett",
+    "Penelope",
+    "Layla",
+    "Ellie",
+    "Stella",
+    "Hazel",
+    "Violet",
+    "Aurora",
+    "Savannah",
+    "Audrey",
+    "Brooklyn",
+    "Claire",
+    "Lucy",
+    "Paisley",
+    "Everly",
+    "Naomi",
+    "Caroline",
+    "Eleanor",
+    "Gertrude",
+    "Mildred",
+    "Edna",
+    "Ethel",
+    "Florence",
+    "Irene",
+    "Agnes",
+    "Bertha",
+    "Clara",
+    "Louise",
+    "Rose",
+    "Lillian",
+    "Josephine",
+    "Eva",
+    "Hattie",
+    "Minnie",
+    "Bessie",
+    "Mabel",
+    "Pearl",
+    "Esther",
+    "Ida",
+    "Viola",
+    "Lucille",
+    "Thelma",
+    "Vera",
+    "Wanda",
+    "Gladys",
+    "Norma",
+    "Peggy",
+    "Rita",
+    "Phyllis",
+    "Bonnie",
+    "Tammy",
+    "Tina",
+    "Wendy",
+    "Dawn",
+    "Tracy",
+    "Crystal",
+    "Erin",
+    "Monica",
+    "Vanessa",
+    "Erica",
+    "Tiffany",
+    "Courtney",
+    "Whitney",
+    "Brianna",
+    "Jasmine",
+    "Destiny",
+    "Haley",
+    "Sydney",
+    "Mackenzie",
+    "Gabriella",
+    "Valentina",
+    "Camila",
+    "Luna",
+    "Sofia",
+    "Isla",
+    "Freya",
+    "Ingrid",
+    "Astrid",
+    "Greta",
+    "Ines",
+    "Lucia",
+    "Carmen",
+    "Elena",
+    "Paola",
+    "Giulia",
+    "Chiara",
+    "Francesca",
+    "Anika",
+    "Priya",
+    "Aisha",
+    "Fatima",
+    "Leila",
+    "Yasmin",
+    "Mei",
+    "Yuki",
+    "Sakura",
+    "Hana",
+];
+
+/// A list of common male first names.
+pub static MALE_NAMES: [&str; 188] = [
+    "James",
+    "Robert",
+    "John",
+    "Michael",
+    "David",
+    "William",
+    "Richard",
+    "Joseph",
+    "Thomas",
+    "Charles",
+    "Christopher",
+    "Daniel",
+    "Matthew",
+    "Anthony",
+    "Mark",
+    "Donald",
+    "Steven",
+    "Paul",
+    "Andrew",
+    "Joshua",
+    "Kenneth",
+    "Kevin",
+    "Brian",
+    "George",
+    "Timothy",
+    "Ronald",
+    "Edward",
+    "Jason",
+    "Jeffrey",
+    "Ryan",
+    "Jacob",
+    "Gary",
+    "Nicholas",
+    "Eric",
+    "Jonathan",
+    "Stephen",
+    "Larry",
+    "Justin",
+    "Scott",
+    "Brandon",
+    "Benjamin",
+    "Samuel",
+    "Gregory",
+    "Alexander",
+    "Frank",
+    "Patrick",
+    "Raymond",
+    "Jack",
+    "Dennis",
+    "Jerry",
+    "Tyler",
+    "Aaron",
+    "Jose",
+    "Adam",
+    "Nathan",
+    "Henry",
+    "Douglas",
+    "Zachary",
+    "Peter",
+    "Kyle",
+    "Ethan",
+    "Walter",
+    "Noah",
+    "Jeremy",
+    "Christian",
+    "Keith",
+    "Roger",
+    "Terry",
+    "Gerald",
+    "Harold",
+    "Sean",
+    "Austin",
+    "Carl",
+    "Arthur",
+    "Lawrence",
+    "Dylan",
+    "Jesse",
+    "Jordan",
+    "Bryan",
+    "Billy",
+    "Joe",
+    "Bruce",
+    "Gabriel",
+    "Logan",
+    "Albert",
+    "Willie",
+    "Alan",
+    "Juan",
+    "Wayne",
+    "Elijah",
+    "Randy",
+    "Roy",
+    "Vincent",
+    "Ralph",
+    "Eugene",
+    "Russell",
+    "Bobby",
+    "Mason",
+    "Philip",
+    "Louis",
+    "Liam",
+    "Oliver",
+    "Lucas",
+    "Levi",
+    "Sebastian",
+    "Mateo",
+    "Owen",
+    "Theodore",
+    "Wyatt",
+    "Luke",
+    "Grayson",
+    "Leo",
+    "Jayden",
+    "Lincoln",
+    "Isaac",
+    "Hudson",
+    "Miles",
+    "Asher",
+    "Caleb",
+    "Ezra",
+    "Jaxon",
+    "Carter",
+    "Julian",
+    "Hunter",
+    "Eli",
+    "Landon",
+    "Jonah",
+    "Silas",
+    "Atlas",
+    "Rowan",
+    "Everett",
+    "Ernest",
+    "Herbert",
+    "Clarence",
+    "Elmer",
+    "Earl",
+    "Howard",
+    "Fred",
+    "Herman",
+    "Otto",
+    "Leroy",
+    "Lester",
+    "Floyd",
+    "Chester",
+    "Milton",
+    "Clyde",
+    "Homer",
+    "Horace",
+    "Wilbur",
+    "Virgil",
+    "Marvin",
+    "Leonard",
+    "Norman",
+    "Melvin",
+    "Glenn",
+    "Dale",
+    "Lloyd",
+    "Curtis",
+    "Darrell",
+    "Duane",
+    "Travis",
+    "Derek",
+    "Shane",
+    "Cody",
+    "Corey",
+    "Marcus",
+    "Dustin",
+    "Trevor",
+    "Hans",
+    "Klaus",
+    "Lars",
+    "Sven",
+    "Pierre",
+    "Jacques",
+    "Luca",
+    "Marco",
+    "Giovanni",
+    "Pablo",
+    "Diego",
+    "Rafael",
+    "Arjun",
+    "Rahul",
+    "Omar",
+    "Hassan",
+    "Yusuf",
+    "Hiroshi",
+    "Kenji",
+    "Wei",
+];
+
+/// A list of common gender-neutral first names.
+pub static UNISEX_NAMES: [&str; 95] = [
+    "Alex", "Avery", "Bailey", "Blake", "Cameron", "Casey", "Charlie", "Dakota", "Drew", "Eden",
+    "Elliot", "Emerson", "Finley", "Frankie", "Harley", "Hayden", "Jamie", "Jessie", "Jordan",
+    "Jules", "Kai", "Kendall", "Kennedy", "Lane", "Logan", "Marley", "Morgan", "Oakley", "Parker",
+    "Peyton", "Phoenix", "Quinn", "Reagan", "Reese", "Remy", "River", "Riley", "Robin", "Rory",
+    "Rowan", "Ryan", "Sage", "Sam", "Sawyer", "Skyler", "Spencer", "Stevie", "Sydney", "Taylor",
+    "Terry", "Tracy", "Val", "Wren", "Adrian", "Angel", "Ari", "Ash", "Billie", "Brooke", "Carson",
+    "Dallas", "Darcy", "Devon", "Ellis", "Emery", "Hollis", "Hunter", "Indigo", "Jaden", "Jody",
+    "Justice", "Kerry", "Lee", "Leslie", "Lou", "Marlowe", "Micah", "Nico", "Noel", "Pat",
+    "Presley", "Raven", "Rene", "Sasha", "Shannon", "Shawn", "Shiloh", "Sloane", "Sutton", "Tatum",
+    "Tegan", "Toby", "Tyler", "Wynn", "Zion",
+];
+
+/// Particles prefixed to compound surnames, like "van der" in "van der Berg".
+pub static SURNAME_PARTICLES: [&str; 16] = [
+    "van", "van der", "van den", "von", "von der", "de", "de la", "del", "della", "da", "di", "du",
+    "le", "la", "ter", "ten",
+];
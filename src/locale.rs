@@ -0,0 +1,82 @@
+use crate::Country;
+
+/// A language and country, used to generate `Person`s whose names, address, phone number,
+/// nationality and time zone all agree, see [`GenerationConfig::locale`](crate::GenerationConfig::locale).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Locale {
+    EnUS,
+    EnCA,
+    EnGB,
+    DeDE,
+    FrFR,
+    EsES,
+    ItIT,
+    NlNL,
+    EnAU,
+    JaJP,
+}
+impl Locale {
+    /// All locales.
+    pub const ALL: [Locale; 10] = [
+        Locale::EnUS,
+        Locale::EnCA,
+        Locale::EnGB,
+        Locale::DeDE,
+        Locale::FrFR,
+        Locale::EsES,
+        Locale::ItIT,
+        Locale::NlNL,
+        Locale::EnAU,
+        Locale::JaJP,
+    ];
+
+    pub fn country(self) -> Country {
+        match self {
+            Self::EnUS => Country::UnitedStates,
+            Self::EnCA => Country::Canada,
+            Self::EnGB => Country::UnitedKingdom,
+            Self::DeDE => Country::Germany,
+            Self::FrFR => Country::France,
+            Self::EsES => Country::Spain,
+            Self::ItIT => Country::Italy,
+            Self::NlNL => Country::Netherlands,
+            Self::EnAU => Country::Australia,
+            Self::JaJP => Country::Japan,
+        }
+    }
+
+    /// Returns the BCP 47 language tag, like "de-DE".
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::EnUS => "en-US",
+            Self::EnCA => "en-CA",
+            Self::EnGB => "en-GB",
+            Self::DeDE => "de-DE",
+            Self::FrFR => "fr-FR",
+            Self::EsES => "es-ES",
+            Self::ItIT => "it-IT",
+            Self::NlNL => "nl-NL",
+            Self::EnAU => "en-AU",
+            Self::JaJP => "ja-JP",
+        }
+    }
+
+    /// Returns the locale with the given language tag, ignoring case and accepting "_" as separator.
+    /// ## Example
+    /// ```rust
+    /// use person::Locale;
+    /// assert_eq!(Locale::from_tag("de_DE"), Some(Locale::DeDE));
+    /// assert_eq!(Locale::from_tag("xx-XX"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.replace('_', "-");
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(&tag))
+    }
+}
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tag())
+    }
+}
@@ -0,0 +1,214 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, transliterate, Person};
+
+/// The salt of the random number generator that derives frequent-flyer numbers.
+const FREQUENT_FLYER_SALT: u64 = 0x4646_4e00;
+/// The salt of the random number generator that derives gym membership IDs.
+const GYM_SALT: u64 = 0x4759_4d00;
+/// The characters of AAdvantage numbers, which leave out "I" and "O".
+const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// An airline with a frequent-flyer program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Airline {
+    AmericanAirlines,
+    Delta,
+    United,
+    BritishAirways,
+    Lufthansa,
+    AirFrance,
+    Qantas,
+    JapanAirlines,
+}
+impl Airline {
+    /// All airlines.
+    pub const ALL: [Airline; 8] = [
+        Airline::AmericanAirlines,
+        Airline::Delta,
+        Airline::United,
+        Airline::BritishAirways,
+        Airline::Lufthansa,
+        Airline::AirFrance,
+        Airline::Qantas,
+        Airline::JapanAirlines,
+    ];
+
+    /// Returns the two-letter IATA code, like "DL".
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::AmericanAirlines => "AA",
+            Self::Delta => "DL",
+            Self::United => "UA",
+            Self::BritishAirways => "BA",
+            Self::Lufthansa => "LH",
+            Self::AirFrance => "AF",
+            Self::Qantas => "QF",
+            Self::JapanAirlines => "JL",
+        }
+    }
+
+    /// Returns the name of the frequent-flyer program, like "SkyMiles".
+    pub fn program(self) -> &'static str {
+        match self {
+            Self::AmericanAirlines => "AAdvantage",
+            Self::Delta => "SkyMiles",
+            Self::United => "MileagePlus",
+            Self::BritishAirways => "Executive Club",
+            Self::Lufthansa => "Miles & More",
+            Self::AirFrance => "Flying Blue",
+            Self::Qantas => "Qantas Frequent Flyer",
+            Self::JapanAirlines => "JAL Mileage Bank",
+        }
+    }
+
+    /// Generates a random membership number in the format of the program: seven letters and digits
+    /// for AAdvantage, three letters and five digits for MileagePlus, 15 digits starting with "992"
+    /// for Miles & More, and 7 to 10 digits for the other programs.
+    pub fn random_member_number(self) -> String {
+        self.random_member_number_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Airline::random_member_number`], but uses the given random number generator.
+    pub fn random_member_number_custom_rng<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+        match self {
+            Self::AmericanAirlines => (0..7)
+                .map(|_| *ALPHANUMERIC.choose(rng).unwrap() as char)
+                .collect(),
+            Self::United => {
+                let letters: String = (0..3)
+                    .map(|_| *LETTERS.choose(rng).unwrap() as char)
+                    .collect();
+                format!("{letters}{}", random_digits(rng, 5))
+            }
+            Self::Lufthansa => format!("992{}", random_digits(rng, 12)),
+            Self::Delta | Self::AirFrance => random_digits(rng, 10),
+            Self::BritishAirways => random_digits(rng, 8),
+            Self::JapanAirlines => random_digits(rng, 9),
+            Self::Qantas => {
+                let length = rng.gen_range(7..=10);
+                format!("{}{}", rng.gen_range(1..=9), random_digits(rng, length - 1))
+            }
+        }
+    }
+}
+impl std::fmt::Display for Airline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::AmericanAirlines => "American Airlines",
+            Self::Delta => "Delta Air Lines",
+            Self::United => "United Airlines",
+            Self::BritishAirways => "British Airways",
+            Self::Lufthansa => "Lufthansa",
+            Self::AirFrance => "Air France",
+            Self::Qantas => "Qantas",
+            Self::JapanAirlines => "Japan Airlines",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A membership of a frequent-flyer program. Displays as the airline code and the number,
+/// like "DL 9012345678".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FrequentFlyerNumber {
+    pub airline: Airline,
+    pub number: String,
+}
+impl std::fmt::Display for FrequentFlyerNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.airline.code(), self.number)
+    }
+}
+
+/// Returns whether the number is a valid EAN-13 barcode, the format of store loyalty cards.
+/// ## Example
+/// ```rust
+/// use person::is_valid_ean13;
+/// assert!(is_valid_ean13("4006381333931"));
+/// assert!(!is_valid_ean13("4006381333932"));
+/// ```
+pub fn is_valid_ean13(number: &str) -> bool {
+    number.len() == 13
+        && number.bytes().all(|b| b.is_ascii_digit())
+        && number.as_bytes()[12] == ean13_check_digit(&number[..12])
+}
+
+impl Person {
+    /// Returns the number of the `Person`'s loyalty card of the store, which is always the same for
+    /// the same store, name and date of birth. Numbers are EAN-13 barcodes in the range "20" to "29"
+    /// that retailers use for their own cards.
+    /// ## Example
+    /// ```rust
+    /// use person::{is_valid_ean13, Person};
+    /// let person = Person::random();
+    /// let number = person.get_loyalty_card_number("Corner Grocery");
+    /// assert!(number.starts_with('2'));
+    /// assert!(is_valid_ean13(&number));
+    /// assert_eq!(number, person.get_loyalty_card_number("Corner Grocery"));
+    /// ```
+    pub fn get_loyalty_card_number(&self, store: &str) -> String {
+        let salt = store
+            .bytes()
+            .fold(0, |salt: u64, byte| salt.rotate_left(8) ^ byte as u64);
+        let rng = &mut self.identity_rng(salt);
+        let mut number = format!("2{}{}", rng.gen_range(0..=9), random_digits(rng, 10));
+        number.push(ean13_check_digit(&number) as char);
+        number
+    }
+
+    /// Returns the `Person`'s membership of the frequent-flyer program of the airline, which is always
+    /// the same for the same name and date of birth.
+    /// ## Example
+    /// ```rust
+    /// use person::{Airline, Person};
+    /// let person = Person::random();
+    /// let membership = person.get_frequent_flyer_number(Airline::Lufthansa);
+    /// assert_eq!(membership.number.len(), 15);
+    /// assert!(membership.to_string().starts_with("LH 992"));
+    /// assert_eq!(membership, person.get_frequent_flyer_number(Airline::Lufthansa));
+    /// ```
+    pub fn get_frequent_flyer_number(&self, airline: Airline) -> FrequentFlyerNumber {
+        let rng = &mut self.identity_rng(FREQUENT_FLYER_SALT + airline as u64);
+        FrequentFlyerNumber {
+            airline,
+            number: airline.random_member_number_custom_rng(rng),
+        }
+    }
+
+    /// Returns the `Person`'s gym membership ID: the first three letters of the last name and seven
+    /// digits, like "SMI0012345", which is always the same for the same name and date of birth.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("Zoë", None, "Müller", Utc::now());
+    /// let id = person.get_gym_membership_id();
+    /// assert!(id.starts_with("MUL"));
+    /// assert_eq!(id.len(), 10);
+    /// ```
+    pub fn get_gym_membership_id(&self) -> String {
+        let mut prefix: String = transliterate::to_ascii(&self.last_name)
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .take(3)
+            .collect::<String>()
+            .to_uppercase();
+        while prefix.len() < 3 {
+            prefix.push('X');
+        }
+        let rng = &mut self.identity_rng(GYM_SALT);
+        format!("{prefix}{}", random_digits(rng, 7))
+    }
+}
+
+/// Computes the EAN-13 check digit of the first twelve digits, which are weighted 1 and 3 in turn.
+fn ean13_check_digit(digits: &str) -> u8 {
+    let sum: u32 = digits
+        .chars()
+        .zip([1, 3].into_iter().cycle())
+        .map(|(c, weight)| c.to_digit(10).unwrap() * weight)
+        .sum();
+    b'0' + ((10 - sum % 10) % 10) as u8
+}
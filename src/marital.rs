@@ -0,0 +1,72 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+/// The youngest age at which a `Person` can be anything but single.
+pub const MARRIAGE_AGE: u32 = 18;
+
+/// The marital status of a `Person`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MaritalStatus {
+    Single,
+    Married,
+    Separated,
+    Divorced,
+    Widowed,
+}
+impl MaritalStatus {
+    /// All marital statuses.
+    pub const ALL: [MaritalStatus; 5] = [
+        MaritalStatus::Single,
+        MaritalStatus::Married,
+        MaritalStatus::Separated,
+        MaritalStatus::Divorced,
+        MaritalStatus::Widowed,
+    ];
+
+    /// Returns the approximate share of people of the given age with the status, in percent,
+    /// based on the US census. Everyone younger than [`MARRIAGE_AGE`] is single.
+    pub fn share(self, age: u32) -> f64 {
+        // Single, married, separated, divorced and widowed.
+        let shares = match age {
+            _ if age < MARRIAGE_AGE => [100.0, 0.0, 0.0, 0.0, 0.0],
+            18..=24 => [88.0, 10.0, 1.0, 1.0, 0.0],
+            25..=34 => [50.0, 42.0, 2.0, 5.5, 0.5],
+            35..=44 => [25.0, 60.0, 3.0, 11.0, 1.0],
+            45..=54 => [15.0, 63.0, 3.0, 16.0, 3.0],
+            55..=64 => [10.0, 64.0, 2.0, 17.0, 7.0],
+            65..=74 => [6.0, 62.0, 1.0, 15.0, 16.0],
+            _ => [4.0, 48.0, 1.0, 9.0, 38.0],
+        };
+        shares[self as usize]
+    }
+
+    /// Picks a marital status according to its share at the age, so `Person`s younger than
+    /// [`MARRIAGE_AGE`] are always single and widows are mostly old.
+    /// ## Example
+    /// ```rust
+    /// use person::MaritalStatus;
+    /// assert_eq!(MaritalStatus::random_for_age(7), MaritalStatus::Single);
+    /// let status = MaritalStatus::random_for_age(40);
+    /// assert!(MaritalStatus::ALL.contains(&status));
+    /// ```
+    pub fn random_for_age(age: u32) -> Self {
+        Self::random_for_age_custom_rng(&mut rand::thread_rng(), age)
+    }
+
+    /// Same as [`MaritalStatus::random_for_age`], but uses the given random number generator.
+    pub fn random_for_age_custom_rng<R: Rng + ?Sized>(rng: &mut R, age: u32) -> Self {
+        let weights = WeightedIndex::new(Self::ALL.map(|status| status.share(age))).unwrap();
+        Self::ALL[weights.sample(rng)]
+    }
+}
+impl std::fmt::Display for MaritalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Single => "single",
+            Self::Married => "married",
+            Self::Separated => "separated",
+            Self::Divorced => "divorced",
+            Self::Widowed => "widowed",
+        };
+        write!(f, "{name}")
+    }
+}
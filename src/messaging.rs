@@ -0,0 +1,57 @@
+use rand::Rng;
+
+use crate::{Person, Platform};
+
+impl Person {
+    /// Generates a random legacy Discord tag made of a username and a four digit discriminator,
+    /// like "janedoe#0420". Current Discord handles are generated with
+    /// [`Person::get_handle_for`] and [`Platform::Discord`].
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let tag = Person::random().get_discord_tag();
+    /// let (username, discriminator) = tag.split_once('#').unwrap();
+    /// assert!((2..=32).contains(&username.len()));
+    /// assert_eq!(discriminator.len(), 4);
+    /// ```
+    pub fn get_discord_tag(&self) -> String {
+        self.get_discord_tag_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_discord_tag`], but uses the given random number generator.
+    pub fn get_discord_tag_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let username = self.get_handle_for_custom_rng(rng, Platform::Discord);
+        format!("{username}#{:04}", rng.gen_range(1..10000))
+    }
+
+    /// Generates a random Telegram handle including the "@", like "@jane_doe".
+    pub fn get_telegram_handle(&self) -> String {
+        self.get_telegram_handle_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_telegram_handle`], but uses the given random number generator.
+    pub fn get_telegram_handle_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        format!(
+            "@{}",
+            self.get_handle_for_custom_rng(rng, Platform::Telegram)
+        )
+    }
+
+    /// Generates a random Matrix user ID on the given server, like "@jane.doe:matrix.org".
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let id = Person::random().get_matrix_id("example.org");
+    /// assert!(id.starts_with('@') && id.ends_with(":example.org"));
+    /// ```
+    pub fn get_matrix_id(&self, server: &str) -> String {
+        self.get_matrix_id_custom_rng(&mut rand::thread_rng(), server)
+    }
+
+    /// Same as [`Person::get_matrix_id`], but uses the given random number generator.
+    pub fn get_matrix_id_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R, server: &str) -> String {
+        // Discord handles only use characters Matrix allows in user IDs.
+        let localpart = self.get_handle_for_custom_rng(rng, Platform::Discord);
+        format!("@{localpart}:{server}")
+    }
+}
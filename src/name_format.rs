@@ -0,0 +1,24 @@
+/// A layout for the name returned by [`Person::format_name`](crate::Person::format_name).
+/// The examples show "Dr. Jane Quinn Doe" without a suffix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NameFormat {
+    /// "Jane Quinn Doe", same as [`Person::get_full_name`](crate::Person::get_full_name).
+    #[default]
+    Full,
+    /// "Dr. Jane Quinn Doe", same as [`Person::get_full_name_with_title`](crate::Person::get_full_name_with_title).
+    FullWithTitle,
+    /// "Jane Q. Doe", same as [`Person::get_short_full_name`](crate::Person::get_short_full_name).
+    Short,
+    /// "Jane Doe".
+    FirstLast,
+    /// "Doe, Jane".
+    LastFirst,
+    /// "Doe, Jane Q.", same as [`Person::get_sortable_name`](crate::Person::get_sortable_name).
+    LastFirstInitial,
+    /// "DOE, Jane".
+    UpperLastFirst,
+    /// "J. Q. Doe".
+    InitialsLast,
+    /// "J. Q. D.".
+    Initials,
+}
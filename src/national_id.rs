@@ -0,0 +1,370 @@
+use chrono::Datelike;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, transliterate, Country, Gender, Person};
+
+/// The check letters of Spanish DNI and NIE numbers, indexed by the number modulo 23.
+const DNI_LETTERS: &[u8; 23] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+/// The first letters National Insurance numbers can start with.
+const NINO_FIRST_LETTERS: &[u8] = b"ABCEGHJKLMNOPRSTWXYZ";
+/// The second letters National Insurance numbers can have.
+const NINO_SECOND_LETTERS: &[u8] = b"ABCEGHJKLMNPRSTWXYZ";
+/// The prefixes that are never allocated to National Insurance numbers.
+const NINO_EXCLUDED_PREFIXES: [&str; 7] = ["BG", "GB", "KN", "NK", "NT", "TN", "ZZ"];
+/// The letters of the months in a Codice Fiscale.
+const CODICE_FISCALE_MONTHS: &[u8; 12] = b"ABCDEHLMPRST";
+/// The values of the characters in odd positions of a Codice Fiscale, for the check letter.
+const CODICE_FISCALE_ODD_VALUES: [u32; 26] = [
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+/// The cadastral codes of the Italian cities of birth.
+const ITALIAN_MUNICIPALITIES: [(&str, &str); 7] = [
+    ("Roma", "H501"),
+    ("Milano", "F205"),
+    ("Napoli", "F839"),
+    ("Torino", "L219"),
+    ("Palermo", "G273"),
+    ("Bologna", "A944"),
+    ("Firenze", "D612"),
+];
+
+/// A kind of national identification number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NationalIdKind {
+    /// The Brazilian individual taxpayer number, like "123.456.789-09".
+    Cpf,
+    /// The United Kingdom National Insurance number, like "AB 12 34 56 C".
+    Nino,
+    /// The Spanish national identity document number, like "12345678Z".
+    Dni,
+    /// The Spanish foreigner identity number, like "X1234567L".
+    Nie,
+    /// The Italian tax code, derived from the name, date of birth, sex and place of birth.
+    CodiceFiscale,
+    /// The Dutch citizen service number, like "111222333".
+    Bsn,
+}
+impl NationalIdKind {
+    /// All kinds of national identification numbers.
+    pub const ALL: [NationalIdKind; 6] = [
+        NationalIdKind::Cpf,
+        NationalIdKind::Nino,
+        NationalIdKind::Dni,
+        NationalIdKind::Nie,
+        NationalIdKind::CodiceFiscale,
+        NationalIdKind::Bsn,
+    ];
+
+    /// Returns the kind of number the citizens of the country have, if it is supported.
+    pub fn for_country(country: Country) -> Option<Self> {
+        match country {
+            Country::UnitedKingdom => Some(Self::Nino),
+            Country::Spain => Some(Self::Dni),
+            Country::Italy => Some(Self::CodiceFiscale),
+            Country::Netherlands => Some(Self::Bsn),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the number has the format of this kind and a correct check digit or letter.
+    /// ## Example
+    /// ```rust
+    /// use person::NationalIdKind;
+    /// assert!(NationalIdKind::Cpf.is_valid("529.982.247-25"));
+    /// assert!(!NationalIdKind::Cpf.is_valid("529.982.247-26"));
+    /// assert!(NationalIdKind::Dni.is_valid("12345678Z"));
+    /// assert!(NationalIdKind::Nie.is_valid("X1234567L"));
+    /// assert!(NationalIdKind::Bsn.is_valid("111222333"));
+    /// assert!(NationalIdKind::Nino.is_valid("AB 12 34 56 C"));
+    /// assert!(!NationalIdKind::Nino.is_valid("GB 12 34 56 C"));
+    /// assert!(NationalIdKind::CodiceFiscale.is_valid("RSSMRA85T10A562S"));
+    /// ```
+    pub fn is_valid(self, id: &str) -> bool {
+        if !id.is_ascii() {
+            return false;
+        }
+        match self {
+            Self::Cpf => {
+                let digits: String = id.chars().filter(|&c| c != '.' && c != '-').collect();
+                digits.len() == 11
+                    && digits.bytes().all(|b| b.is_ascii_digit())
+                    && digits.bytes().any(|b| b != digits.as_bytes()[0])
+                    && cpf_check_digits(&digits[..9]) == digits[9..]
+            }
+            Self::Nino => {
+                let compact: String = id.chars().filter(|&c| c != ' ').collect();
+                let bytes = compact.as_bytes();
+                bytes.len() == 9
+                    && NINO_FIRST_LETTERS.contains(&bytes[0])
+                    && NINO_SECOND_LETTERS.contains(&bytes[1])
+                    && !NINO_EXCLUDED_PREFIXES.contains(&&compact[..2])
+                    && bytes[2..8].iter().all(u8::is_ascii_digit)
+                    && (b'A'..=b'D').contains(&bytes[8])
+            }
+            Self::Dni => {
+                id.len() == 9
+                    && id[..8].bytes().all(|b| b.is_ascii_digit())
+                    && id[8..] == dni_letter(id[..8].parse().unwrap()).to_string()
+            }
+            Self::Nie => {
+                let Some(prefix) = id.chars().next().and_then(|c| "XYZ".find(c)) else {
+                    return false;
+                };
+                id.len() == 9
+                    && id[1..8].bytes().all(|b| b.is_ascii_digit())
+                    && id[8..] == nie_letter(prefix, &id[1..8]).to_string()
+            }
+            Self::CodiceFiscale => {
+                id.len() == 16
+                    && id
+                        .bytes()
+                        .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+                    && id[15..] == italian_check_letter(&id[..15]).to_string()
+            }
+            Self::Bsn => {
+                id.len() == 9 && id.bytes().all(|b| b.is_ascii_digit()) && bsn_sum(id) % 11 == 0
+            }
+        }
+    }
+}
+impl std::fmt::Display for NationalIdKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Cpf => "CPF",
+            Self::Nino => "National Insurance number",
+            Self::Dni => "DNI",
+            Self::Nie => "NIE",
+            Self::CodiceFiscale => "codice fiscale",
+            Self::Bsn => "BSN",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Person {
+    /// Generates a national identification number of the given kind, which passes
+    /// [`NationalIdKind::is_valid`]. A Codice Fiscale is derived from the `Person`'s name,
+    /// date of birth, gender and place of birth, the other kinds are random.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{Gender, NationalIdKind, Person};
+    /// let dob = Utc.with_ymd_and_hms(1985, 12, 10, 0, 0, 0).unwrap();
+    /// let person = Person::builder()
+    ///     .first_name("Maria")
+    ///     .last_name("Rossi")
+    ///     .date_of_birth(dob)
+    ///     .gender(Gender::Female)
+    ///     .build();
+    /// let code = person.get_random_national_id(NationalIdKind::CodiceFiscale);
+    /// assert!(code.starts_with("RSSMRA85T50"));
+    ///
+    /// for kind in NationalIdKind::ALL {
+    ///     assert!(kind.is_valid(&person.get_random_national_id(kind)));
+    /// }
+    /// ```
+    pub fn get_random_national_id(&self, kind: NationalIdKind) -> String {
+        self.get_random_national_id_custom_rng(&mut rand::thread_rng(), kind)
+    }
+
+    /// Same as [`Person::get_random_national_id`], but uses the given random number generator.
+    pub fn get_random_national_id_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        kind: NationalIdKind,
+    ) -> String {
+        match kind {
+            NationalIdKind::Cpf => {
+                let base = loop {
+                    let base = random_digits(rng, 9);
+                    // Numbers with only one repeated digit are reserved.
+                    if base.bytes().any(|b| b != base.as_bytes()[0]) {
+                        break base;
+                    }
+                };
+                let digits = format!("{base}{}", cpf_check_digits(&base));
+                format!(
+                    "{}.{}.{}-{}",
+                    &digits[..3],
+                    &digits[3..6],
+                    &digits[6..9],
+                    &digits[9..]
+                )
+            }
+            NationalIdKind::Nino => {
+                let prefix = loop {
+                    let prefix: String = [NINO_FIRST_LETTERS, NINO_SECOND_LETTERS]
+                        .iter()
+                        .map(|letters| *letters.choose(rng).unwrap() as char)
+                        .collect();
+                    if !NINO_EXCLUDED_PREFIXES.contains(&prefix.as_str()) {
+                        break prefix;
+                    }
+                };
+                let digits = random_digits(rng, 6);
+                format!(
+                    "{prefix} {} {} {} {}",
+                    &digits[..2],
+                    &digits[2..4],
+                    &digits[4..],
+                    rng.gen_range('A'..='D')
+                )
+            }
+            NationalIdKind::Dni => {
+                let number = rng.gen_range(0..100_000_000);
+                format!("{number:08}{}", dni_letter(number))
+            }
+            NationalIdKind::Nie => {
+                let prefix = rng.gen_range(0..3);
+                let digits = random_digits(rng, 7);
+                format!(
+                    "{}{digits}{}",
+                    "XYZ".as_bytes()[prefix] as char,
+                    nie_letter(prefix, &digits)
+                )
+            }
+            NationalIdKind::CodiceFiscale => {
+                let offset = if self.gender == Some(Gender::Female) {
+                    40
+                } else {
+                    0
+                };
+                let base = format!(
+                    "{}{}{:02}{}{:02}{}",
+                    codice_fiscale_surname(&self.last_name),
+                    codice_fiscale_first_name(&self.first_name),
+                    self.date_of_birth.year() % 100,
+                    CODICE_FISCALE_MONTHS[self.date_of_birth.month0() as usize] as char,
+                    self.date_of_birth.day() + offset,
+                    self.municipality_code(rng)
+                );
+                format!("{base}{}", italian_check_letter(&base))
+            }
+            NationalIdKind::Bsn => loop {
+                let number = random_digits(rng, 9);
+                // The first digit of current numbers is never 0.
+                if !number.starts_with('0') && bsn_sum(&number) % 11 == 0 {
+                    break number;
+                }
+            },
+        }
+    }
+
+    /// Returns the cadastral code of the place of birth for a Codice Fiscale, which for people born
+    /// abroad is the code of the country. Without a place of birth, a random Italian city is used.
+    fn municipality_code<R: Rng + ?Sized>(&self, rng: &mut R) -> &'static str {
+        let foreign_code = |country| match country {
+            Country::UnitedStates => Some("Z404"),
+            Country::Canada => Some("Z401"),
+            Country::UnitedKingdom => Some("Z114"),
+            Country::Germany => Some("Z112"),
+            Country::France => Some("Z110"),
+            Country::Spain => Some("Z131"),
+            Country::Netherlands => Some("Z126"),
+            Country::Australia => Some("Z700"),
+            Country::Japan => Some("Z219"),
+            Country::Italy => None,
+        };
+        self.place_of_birth
+            .as_ref()
+            .and_then(|place| {
+                foreign_code(place.country).or_else(|| {
+                    ITALIAN_MUNICIPALITIES
+                        .iter()
+                        .find(|(city, _)| *city == place.city)
+                        .map(|(_, code)| *code)
+                })
+            })
+            .unwrap_or_else(|| ITALIAN_MUNICIPALITIES.choose(rng).unwrap().1)
+    }
+}
+
+/// Computes the two check digits of the first nine digits of a CPF.
+fn cpf_check_digits(base: &str) -> String {
+    let mut digits: Vec<u32> = base.chars().filter_map(|c| c.to_digit(10)).collect();
+    for _ in 0..2 {
+        let weight = digits.len() as u32 + 1;
+        let sum: u32 = digits
+            .iter()
+            .enumerate()
+            .map(|(i, d)| d * (weight - i as u32))
+            .sum();
+        digits.push(sum * 10 % 11 % 10);
+    }
+    digits[9..].iter().map(u32::to_string).collect()
+}
+
+/// Returns the check letter of a DNI number.
+pub(crate) fn dni_letter(number: u32) -> char {
+    DNI_LETTERS[(number % 23) as usize] as char
+}
+
+/// Returns the check letter of a NIE, with the index of its first letter in "XYZ" and its seven digits.
+fn nie_letter(prefix: usize, digits: &str) -> char {
+    dni_letter(format!("{prefix}{digits}").parse().unwrap())
+}
+
+/// Computes the weighted sum of a BSN, which is divisible by 11 for valid numbers.
+pub(crate) fn bsn_sum(number: &str) -> i32 {
+    number
+        .bytes()
+        .zip([9, 8, 7, 6, 5, 4, 3, 2, -1])
+        .map(|(b, weight)| (b - b'0') as i32 * weight)
+        .sum()
+}
+
+/// Splits a name into its uppercase ASCII consonants and vowels.
+fn consonants_and_vowels(name: &str) -> (Vec<char>, Vec<char>) {
+    transliterate::to_ascii(name)
+        .to_ascii_uppercase()
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .partition(|c| !"AEIOU".contains(*c))
+}
+
+/// Encodes a surname with its first three consonants, followed by its vowels and "X" if it is shorter.
+fn codice_fiscale_surname(surname: &str) -> String {
+    let (consonants, vowels) = consonants_and_vowels(surname);
+    consonants
+        .into_iter()
+        .chain(vowels)
+        .chain(std::iter::repeat('X'))
+        .take(3)
+        .collect()
+}
+
+/// Encodes a first name like a surname, except that names with four or more consonants
+/// use the first, third and fourth consonant.
+fn codice_fiscale_first_name(first_name: &str) -> String {
+    let (consonants, _) = consonants_and_vowels(first_name);
+    if consonants.len() >= 4 {
+        [consonants[0], consonants[2], consonants[3]]
+            .iter()
+            .collect()
+    } else {
+        codice_fiscale_surname(first_name)
+    }
+}
+
+/// Computes the check letter of a Codice Fiscale from its first fifteen characters. Italian bank
+/// accounts compute their CIN from the bank, branch and account number the same way.
+pub(crate) fn italian_check_letter(base: &str) -> char {
+    let sum: u32 = base
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| {
+            let index = if b.is_ascii_digit() {
+                b - b'0'
+            } else {
+                b - b'A'
+            };
+            // Positions are counted from one, so even indices are odd positions.
+            if i % 2 == 0 {
+                CODICE_FISCALE_ODD_VALUES[index as usize]
+            } else {
+                index as u32
+            }
+        })
+        .sum();
+    (b'A' + (sum % 26) as u8) as char
+}
@@ -0,0 +1,106 @@
+/// Common nicknames for first names, the first one being the most common.
+static NICKNAMES: &[(&str, &[&str])] = &[
+    ("Abigail", &["Abby", "Gail"]),
+    ("Albert", &["Al", "Bert"]),
+    ("Alexander", &["Alex", "Xander", "Sasha"]),
+    ("Alexandra", &["Alex", "Sandra", "Lexi"]),
+    ("Alfred", &["Alf", "Fred"]),
+    ("Andrew", &["Andy", "Drew"]),
+    ("Anthony", &["Tony", "Ant"]),
+    ("Arthur", &["Art", "Artie"]),
+    ("Barbara", &["Barb", "Babs"]),
+    ("Benjamin", &["Ben", "Benny", "Benji"]),
+    ("Bernard", &["Bernie"]),
+    ("Catherine", &["Cathy", "Kate", "Cat"]),
+    ("Charles", &["Charlie", "Chuck", "Chaz"]),
+    ("Charlotte", &["Charlie", "Lottie"]),
+    ("Christina", &["Chris", "Tina"]),
+    ("Christine", &["Chris", "Chrissy"]),
+    ("Christopher", &["Chris", "Kit", "Topher"]),
+    ("Cynthia", &["Cindy"]),
+    ("Daniel", &["Dan", "Danny"]),
+    ("David", &["Dave", "Davy"]),
+    ("Deborah", &["Debbie", "Deb"]),
+    ("Donald", &["Don", "Donnie"]),
+    ("Dorothy", &["Dot", "Dottie"]),
+    ("Edward", &["Ed", "Eddie", "Ted", "Ned"]),
+    ("Elizabeth", &["Liz", "Beth", "Betty", "Eliza", "Lizzie"]),
+    ("Eleanor", &["Ellie", "Nora"]),
+    ("Emily", &["Em", "Emmy"]),
+    ("Eugene", &["Gene"]),
+    ("Frances", &["Fran", "Frankie"]),
+    ("Francis", &["Frank", "Frankie"]),
+    ("Frederick", &["Fred", "Freddie"]),
+    ("Gabriel", &["Gabe"]),
+    ("Gerald", &["Gerry", "Jerry"]),
+    ("Gregory", &["Greg"]),
+    ("Harold", &["Harry", "Hal"]),
+    ("Henry", &["Hank", "Harry"]),
+    ("Isabella", &["Bella", "Izzy"]),
+    ("Jacob", &["Jake"]),
+    ("James", &["Jim", "Jimmy", "Jamie"]),
+    ("Jennifer", &["Jen", "Jenny"]),
+    ("Jessica", &["Jess", "Jessie"]),
+    ("John", &["Johnny", "Jack"]),
+    ("Jonathan", &["Jon", "Johnny"]),
+    ("Joseph", &["Joe", "Joey"]),
+    ("Joshua", &["Josh"]),
+    ("Katherine", &["Kate", "Katie", "Kathy", "Kat"]),
+    ("Kathleen", &["Kathy", "Kath"]),
+    ("Kenneth", &["Ken", "Kenny"]),
+    ("Lawrence", &["Larry"]),
+    ("Leonard", &["Leo", "Len", "Lenny"]),
+    ("Margaret", &["Maggie", "Meg", "Peggy", "Daisy"]),
+    ("Matthew", &["Matt", "Matty"]),
+    ("Michael", &["Mike", "Mikey", "Mick"]),
+    ("Nathan", &["Nate"]),
+    ("Nathaniel", &["Nate", "Nat"]),
+    ("Nicholas", &["Nick", "Nicky"]),
+    ("Olivia", &["Liv", "Livvy"]),
+    ("Patricia", &["Pat", "Patty", "Trish"]),
+    ("Patrick", &["Pat", "Paddy"]),
+    ("Peter", &["Pete"]),
+    ("Philip", &["Phil"]),
+    ("Rebecca", &["Becky", "Becca"]),
+    ("Richard", &["Rick", "Dick", "Rich", "Richie"]),
+    ("Robert", &["Bob", "Rob", "Bobby", "Robbie"]),
+    ("Ronald", &["Ron", "Ronnie"]),
+    ("Samantha", &["Sam", "Sammy"]),
+    ("Samuel", &["Sam", "Sammy"]),
+    ("Sarah", &["Sally", "Sadie"]),
+    ("Stephanie", &["Steph"]),
+    ("Stephen", &["Steve"]),
+    ("Steven", &["Steve"]),
+    ("Susan", &["Sue", "Suzy"]),
+    ("Theodore", &["Ted", "Teddy", "Theo"]),
+    ("Thomas", &["Tom", "Tommy"]),
+    ("Timothy", &["Tim", "Timmy"]),
+    ("Victoria", &["Vicky", "Tori"]),
+    ("Walter", &["Walt", "Wally"]),
+    ("William", &["Will", "Bill", "Billy", "Liam"]),
+    ("Zachary", &["Zach", "Zack"]),
+];
+
+/// Returns the known nicknames for the given first name, most common first.
+pub(crate) fn nicknames_for(name: &str) -> &'static [&'static str] {
+    NICKNAMES
+        .iter()
+        .find(|(full, _)| full.eq_ignore_ascii_case(name))
+        .map_or(&[], |(_, nicknames)| *nicknames)
+}
+
+/// Shortens a name to its first syllable, e.g. "Maximilian" to "Max".
+/// Names with up to four characters are returned as is.
+pub(crate) fn truncate_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    if chars.len() <= 4 {
+        return name.to_string();
+    }
+    let is_vowel = |c: &char| "aeiouyAEIOUY".contains(*c);
+    let first_vowel = chars.iter().position(is_vowel).unwrap_or(0);
+    let end = chars[first_vowel..]
+        .iter()
+        .position(|c| !is_vowel(c))
+        .map_or(chars.len(), |i| first_vowel + i + 1);
+    chars[..end.max(2)].iter().collect()
+}
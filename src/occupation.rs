@@ -0,0 +1,418 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, seq::SliceRandom, Rng};
+
+use crate::{salary::MIN_WORKING_AGE, Person, Salary, SalaryRange};
+
+/// The age from which `Person`s are retired and have no occupation.
+pub(crate) const RETIREMENT_AGE: u32 = 67;
+/// The age of majority, before which `Person`s only have entry-level jobs in retail and hospitality.
+const ADULT_AGE: u32 = 18;
+/// The chance of a job being in the same industry as the previous one.
+const CAREER_INDUSTRY_LOYALTY: f64 = 0.7;
+/// The industry, seniority and title of the occupations `Person`s are generated with.
+const OCCUPATIONS: [(Industry, Seniority, &str); 67] = [
+    (
+        Industry::Technology,
+        Seniority::Entry,
+        "Junior Software Engineer",
+    ),
+    (
+        Industry::Technology,
+        Seniority::Entry,
+        "IT Support Technician",
+    ),
+    (Industry::Technology, Seniority::Mid, "Software Engineer"),
+    (Industry::Technology, Seniority::Mid, "Data Analyst"),
+    (
+        Industry::Technology,
+        Seniority::Senior,
+        "Senior Software Engineer",
+    ),
+    (Industry::Technology, Seniority::Lead, "Tech Lead"),
+    (
+        Industry::Technology,
+        Seniority::Manager,
+        "Engineering Manager",
+    ),
+    (
+        Industry::Technology,
+        Seniority::Director,
+        "Director of Engineering",
+    ),
+    (
+        Industry::Technology,
+        Seniority::VicePresident,
+        "VP of Engineering",
+    ),
+    (
+        Industry::Technology,
+        Seniority::Executive,
+        "Chief Technology Officer",
+    ),
+    (Industry::Healthcare, Seniority::Entry, "Medical Assistant"),
+    (Industry::Healthcare, Seniority::Mid, "Registered Nurse"),
+    (Industry::Healthcare, Seniority::Mid, "Pharmacist"),
+    (
+        Industry::Healthcare,
+        Seniority::Senior,
+        "Nurse Practitioner",
+    ),
+    (Industry::Healthcare, Seniority::Lead, "Charge Nurse"),
+    (Industry::Healthcare, Seniority::Manager, "Nurse Manager"),
+    (
+        Industry::Healthcare,
+        Seniority::Director,
+        "Director of Nursing",
+    ),
+    (
+        Industry::Healthcare,
+        Seniority::VicePresident,
+        "VP of Clinical Operations",
+    ),
+    (
+        Industry::Healthcare,
+        Seniority::Executive,
+        "Chief Medical Officer",
+    ),
+    (Industry::Finance, Seniority::Entry, "Bank Teller"),
+    (Industry::Finance, Seniority::Mid, "Financial Analyst"),
+    (Industry::Finance, Seniority::Mid, "Accountant"),
+    (Industry::Finance, Seniority::Senior, "Senior Accountant"),
+    (Industry::Finance, Seniority::Lead, "Lead Auditor"),
+    (Industry::Finance, Seniority::Manager, "Branch Manager"),
+    (Industry::Finance, Seniority::Director, "Finance Director"),
+    (Industry::Finance, Seniority::VicePresident, "VP of Finance"),
+    (
+        Industry::Finance,
+        Seniority::Executive,
+        "Chief Financial Officer",
+    ),
+    (Industry::Education, Seniority::Entry, "Teaching Assistant"),
+    (Industry::Education, Seniority::Mid, "Teacher"),
+    (Industry::Education, Seniority::Senior, "Senior Lecturer"),
+    (Industry::Education, Seniority::Lead, "Head of Department"),
+    (
+        Industry::Education,
+        Seniority::Manager,
+        "Assistant Principal",
+    ),
+    (Industry::Education, Seniority::Director, "Principal"),
+    (Industry::Education, Seniority::Executive, "Superintendent"),
+    (Industry::Retail, Seniority::Entry, "Cashier"),
+    (Industry::Retail, Seniority::Entry, "Sales Associate"),
+    (Industry::Retail, Seniority::Entry, "Stock Clerk"),
+    (Industry::Retail, Seniority::Mid, "Visual Merchandiser"),
+    (Industry::Retail, Seniority::Senior, "Senior Buyer"),
+    (Industry::Retail, Seniority::Lead, "Shift Supervisor"),
+    (Industry::Retail, Seniority::Manager, "Store Manager"),
+    (Industry::Retail, Seniority::Director, "Regional Director"),
+    (
+        Industry::Retail,
+        Seniority::VicePresident,
+        "VP of Merchandising",
+    ),
+    (Industry::Hospitality, Seniority::Entry, "Barista"),
+    (Industry::Hospitality, Seniority::Entry, "Server"),
+    (Industry::Hospitality, Seniority::Entry, "Dishwasher"),
+    (Industry::Hospitality, Seniority::Mid, "Line Cook"),
+    (Industry::Hospitality, Seniority::Senior, "Sous Chef"),
+    (Industry::Hospitality, Seniority::Lead, "Head Chef"),
+    (
+        Industry::Hospitality,
+        Seniority::Manager,
+        "Restaurant Manager",
+    ),
+    (
+        Industry::Hospitality,
+        Seniority::Director,
+        "Director of Operations",
+    ),
+    (Industry::Manufacturing, Seniority::Entry, "Assembler"),
+    (Industry::Manufacturing, Seniority::Mid, "Machinist"),
+    (
+        Industry::Manufacturing,
+        Seniority::Senior,
+        "Quality Engineer",
+    ),
+    (
+        Industry::Manufacturing,
+        Seniority::Lead,
+        "Production Supervisor",
+    ),
+    (Industry::Manufacturing, Seniority::Manager, "Plant Manager"),
+    (
+        Industry::Manufacturing,
+        Seniority::VicePresident,
+        "VP of Operations",
+    ),
+    (Industry::Construction, Seniority::Entry, "Laborer"),
+    (Industry::Construction, Seniority::Mid, "Electrician"),
+    (Industry::Construction, Seniority::Mid, "Carpenter"),
+    (Industry::Construction, Seniority::Senior, "Master Plumber"),
+    (Industry::Construction, Seniority::Lead, "Foreman"),
+    (
+        Industry::Construction,
+        Seniority::Manager,
+        "Project Manager",
+    ),
+    (Industry::Government, Seniority::Entry, "Clerk"),
+    (Industry::Government, Seniority::Mid, "Policy Analyst"),
+    (Industry::Government, Seniority::Director, "Agency Director"),
+];
+
+/// The industry an `Occupation` is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Industry {
+    Technology,
+    Healthcare,
+    Finance,
+    Education,
+    Retail,
+    Hospitality,
+    Manufacturing,
+    Construction,
+    Government,
+}
+impl Industry {
+    /// All industries.
+    pub const ALL: [Industry; 9] = [
+        Industry::Technology,
+        Industry::Healthcare,
+        Industry::Finance,
+        Industry::Education,
+        Industry::Retail,
+        Industry::Hospitality,
+        Industry::Manufacturing,
+        Industry::Construction,
+        Industry::Government,
+    ];
+
+    /// Returns how much the industry pays compared to the average.
+    fn pay_factor(self) -> f64 {
+        match self {
+            Self::Technology | Self::Finance => 1.3,
+            Self::Healthcare => 1.1,
+            Self::Manufacturing | Self::Construction | Self::Government => 1.0,
+            Self::Education => 0.85,
+            Self::Retail | Self::Hospitality => 0.7,
+        }
+    }
+}
+impl std::fmt::Display for Industry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// The seniority of an `Occupation`, from the lowest to the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Seniority {
+    Entry,
+    Mid,
+    Senior,
+    Lead,
+    Manager,
+    Director,
+    VicePresident,
+    Executive,
+}
+impl Seniority {
+    /// All seniorities, from the lowest to the highest.
+    pub const ALL: [Seniority; 8] = [
+        Seniority::Entry,
+        Seniority::Mid,
+        Seniority::Senior,
+        Seniority::Lead,
+        Seniority::Manager,
+        Seniority::Director,
+        Seniority::VicePresident,
+        Seniority::Executive,
+    ];
+
+    /// Returns the youngest age at which a `Person` plausibly reaches the seniority.
+    pub fn min_age(self) -> u32 {
+        match self {
+            Self::Entry => MIN_WORKING_AGE,
+            Self::Mid => 23,
+            Self::Senior => 27,
+            Self::Lead | Self::Manager => 30,
+            Self::Director => 35,
+            Self::VicePresident => 40,
+            Self::Executive => 45,
+        }
+    }
+
+    /// Returns the share of the workforce at the seniority, among those old enough for it.
+    fn weight(self) -> f64 {
+        match self {
+            Self::Entry | Self::Mid => 30.0,
+            Self::Senior => 20.0,
+            Self::Lead | Self::Manager => 8.0,
+            Self::Director => 3.0,
+            Self::VicePresident => 0.7,
+            Self::Executive => 0.3,
+        }
+    }
+
+    /// Returns the range of annual salaries in US dollars of an average industry.
+    fn salary_range(self) -> SalaryRange {
+        match self {
+            Self::Entry => SalaryRange::new(28_000, 45_000),
+            Self::Mid => SalaryRange::new(45_000, 75_000),
+            Self::Senior => SalaryRange::new(70_000, 110_000),
+            Self::Lead => SalaryRange::new(85_000, 130_000),
+            Self::Manager => SalaryRange::new(90_000, 140_000),
+            Self::Director => SalaryRange::new(130_000, 200_000),
+            Self::VicePresident => SalaryRange::new(180_000, 280_000),
+            Self::Executive => SalaryRange::new(220_000, 400_000),
+        }
+    }
+}
+impl std::fmt::Display for Seniority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Entry => "entry level",
+            Self::Mid => "mid level",
+            Self::Senior => "senior",
+            Self::Lead => "lead",
+            Self::Manager => "manager",
+            Self::Director => "director",
+            Self::VicePresident => "vice president",
+            Self::Executive => "executive",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The job of a `Person`. Displays as the title.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Occupation {
+    pub industry: Industry,
+    pub seniority: Seniority,
+    /// The job title, like "Senior Software Engineer".
+    pub title: String,
+}
+impl Occupation {
+    /// Picks a random occupation that is plausible for the age, where higher seniorities are rarer and
+    /// need more years of experience. Returns `None` if the age is under 16 or at least the retirement
+    /// age of 67, and only picks entry-level retail and hospitality jobs for minors.
+    /// ## Example
+    /// ```rust
+    /// use person::{Industry, Occupation, Seniority};
+    /// let teenager = Occupation::random(17).unwrap();
+    /// assert_eq!(teenager.seniority, Seniority::Entry);
+    /// assert!([Industry::Retail, Industry::Hospitality].contains(&teenager.industry));
+    /// assert!(Occupation::random(30).unwrap().seniority <= Seniority::Manager);
+    /// assert_eq!(Occupation::random(12), None);
+    /// assert_eq!(Occupation::random(80), None);
+    /// ```
+    pub fn random(age: u32) -> Option<Self> {
+        Self::random_custom_rng(&mut rand::thread_rng(), age)
+    }
+
+    /// Same as [`Occupation::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, age: u32) -> Option<Self> {
+        Self::random_after_custom_rng(rng, age, None, None)
+    }
+
+    /// Picks a random occupation like [`Occupation::random`] that follows the previous one in a career:
+    /// it is at least as senior, and most likely in the same industry. It is at most as senior as
+    /// the ceiling, if any.
+    pub(crate) fn random_after_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        age: u32,
+        previous: Option<&Occupation>,
+        ceiling: Option<Seniority>,
+    ) -> Option<Self> {
+        if !(MIN_WORKING_AGE..RETIREMENT_AGE).contains(&age) {
+            return None;
+        }
+        let seniorities: Vec<Seniority> = Seniority::ALL
+            .into_iter()
+            .filter(|&seniority| {
+                seniority.min_age() <= age
+                    && previous.is_none_or(|previous| seniority >= previous.seniority)
+                    && ceiling.is_none_or(|ceiling| seniority <= ceiling)
+            })
+            .collect();
+        let weights = WeightedIndex::new(seniorities.iter().map(|s| s.weight())).ok()?;
+        let seniority = seniorities[weights.sample(rng)];
+        let candidates: Vec<_> = OCCUPATIONS
+            .iter()
+            .filter(|(industry, s, _)| {
+                *s == seniority
+                    && (age >= ADULT_AGE
+                        || [Industry::Retail, Industry::Hospitality].contains(industry))
+            })
+            .collect();
+        let same_industry: Vec<_> = candidates
+            .iter()
+            .filter(|(industry, _, _)| {
+                previous.is_some_and(|previous| *industry == previous.industry)
+            })
+            .collect();
+        let (industry, seniority, title) =
+            if !same_industry.is_empty() && rng.gen_bool(CAREER_INDUSTRY_LOYALTY) {
+                ***same_industry.choose(rng).unwrap()
+            } else {
+                **candidates.choose(rng)?
+            };
+        Some(Self {
+            industry,
+            seniority,
+            title: title.to_string(),
+        })
+    }
+
+    /// Returns the range of annual salaries in US dollars of the occupation.
+    /// ## Example
+    /// ```rust
+    /// use person::{Industry, Occupation, Seniority};
+    /// let occupation = Occupation {
+    ///     industry: Industry::Technology,
+    ///     seniority: Seniority::Senior,
+    ///     title: "Senior Software Engineer".to_string(),
+    /// };
+    /// assert_eq!(occupation.salary_range().min, 91_000);
+    /// ```
+    pub fn salary_range(&self) -> SalaryRange {
+        let range = self.seniority.salary_range();
+        // Ranges are in multiples of 500, like the salaries generated within them.
+        let scale = |amount: u32| {
+            ((amount as f64 * self.industry.pay_factor() / 500.0).round() * 500.0) as u32
+        };
+        SalaryRange::new(scale(range.min), scale(range.max))
+    }
+}
+impl std::fmt::Display for Occupation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.title)
+    }
+}
+
+impl Person {
+    /// Generates an annual salary in the [range of the occupation](Occupation::salary_range), see
+    /// [`Person::get_random_salary_in`]. Returns `None` if the `Person` has no occupation.
+    /// ## Example
+    /// ```rust
+    /// use person::{Industry, Occupation, Person, Seniority};
+    /// let occupation = Occupation {
+    ///     industry: Industry::Education,
+    ///     seniority: Seniority::Mid,
+    ///     title: "Teacher".to_string(),
+    /// };
+    /// let mut teacher = Person::random_adult();
+    /// teacher.set_occupation(Some(occupation.clone()));
+    /// let range = occupation.salary_range();
+    /// let salary = teacher.get_random_salary().unwrap();
+    /// assert!((range.min..=range.max).contains(&salary.annual));
+    /// ```
+    pub fn get_random_salary(&self) -> Option<Salary> {
+        self.get_random_salary_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_salary`], but uses the given random number generator.
+    pub fn get_random_salary_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Salary> {
+        let range = self.occupation.as_ref()?.salary_range();
+        self.get_random_salary_in_custom_rng(rng, range)
+    }
+}
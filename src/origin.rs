@@ -0,0 +1,485 @@
+use rand::Rng;
+
+use crate::{Country, Gender};
+
+/// The cultural origin of a `Person`'s names, used to pair first and last names that fit together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Origin {
+    English,
+    Spanish,
+    German,
+    French,
+    Italian,
+    Dutch,
+    Polish,
+    Russian,
+    Chinese,
+    Japanese,
+    Korean,
+    Indian,
+    Arabic,
+    Nigerian,
+}
+impl Origin {
+    /// All origins.
+    pub const ALL: [Origin; 14] = [
+        Origin::English,
+        Origin::Spanish,
+        Origin::German,
+        Origin::French,
+        Origin::Italian,
+        Origin::Dutch,
+        Origin::Polish,
+        Origin::Russian,
+        Origin::Chinese,
+        Origin::Japanese,
+        Origin::Korean,
+        Origin::Indian,
+        Origin::Arabic,
+        Origin::Nigerian,
+    ];
+
+    /// Common female first names of this origin.
+    pub fn female_names(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &[
+                "Olivia",
+                "Amelia",
+                "Emily",
+                "Charlotte",
+                "Sophie",
+                "Grace",
+                "Alice",
+                "Lucy",
+                "Hannah",
+                "Eleanor",
+                "Megan",
+                "Abigail",
+            ],
+            Self::Spanish => &[
+                "María",
+                "Lucía",
+                "Sofía",
+                "Carmen",
+                "Isabel",
+                "Elena",
+                "Paula",
+                "Valentina",
+                "Camila",
+                "Ana",
+                "Rosa",
+                "Marta",
+            ],
+            Self::German => &[
+                "Anna",
+                "Lena",
+                "Leonie",
+                "Hannah",
+                "Johanna",
+                "Katharina",
+                "Greta",
+                "Ursula",
+                "Sabine",
+                "Petra",
+                "Frieda",
+                "Lea",
+            ],
+            Self::French => &[
+                "Camille", "Chloé", "Manon", "Léa", "Juliette", "Inès", "Amélie", "Margaux",
+                "Élodie", "Claire", "Sophie", "Céline",
+            ],
+            Self::Italian => &[
+                "Giulia",
+                "Sofia",
+                "Francesca",
+                "Chiara",
+                "Alessia",
+                "Martina",
+                "Giorgia",
+                "Elena",
+                "Valentina",
+                "Beatrice",
+                "Paola",
+                "Lucia",
+            ],
+            Self::Dutch => &[
+                "Emma", "Sanne", "Femke", "Lotte", "Anouk", "Fenna", "Noor", "Lieke", "Maaike",
+                "Eva", "Julia", "Ilse",
+            ],
+            Self::Polish => &[
+                "Zuzanna",
+                "Julia",
+                "Maja",
+                "Agnieszka",
+                "Katarzyna",
+                "Małgorzata",
+                "Anna",
+                "Magdalena",
+                "Joanna",
+                "Ewa",
+                "Aleksandra",
+                "Natalia",
+            ],
+            Self::Russian => &[
+                "Anastasia",
+                "Maria",
+                "Daria",
+                "Ekaterina",
+                "Olga",
+                "Tatiana",
+                "Natalia",
+                "Irina",
+                "Svetlana",
+                "Yulia",
+                "Polina",
+                "Ksenia",
+            ],
+            Self::Chinese => &[
+                "Mei", "Li", "Xiu", "Ying", "Jing", "Hui", "Lan", "Fang", "Yan", "Xia", "Ling",
+                "Na",
+            ],
+            Self::Japanese => &[
+                "Yui", "Hina", "Sakura", "Yuki", "Aoi", "Haruka", "Emi", "Rin", "Mio", "Akiko",
+                "Naoko", "Keiko",
+            ],
+            Self::Korean => &[
+                "Ji-woo", "Seo-yeon", "Min-ji", "Ha-eun", "Soo-ah", "Ji-yeon", "Eun-ji", "Hye-jin",
+                "Yu-na", "Da-eun", "Seo-yun", "Ye-jin",
+            ],
+            Self::Indian => &[
+                "Priya", "Ananya", "Aditi", "Kavya", "Diya", "Isha", "Meera", "Nisha", "Pooja",
+                "Riya", "Sneha", "Lakshmi",
+            ],
+            Self::Arabic => &[
+                "Fatima", "Aisha", "Mariam", "Layla", "Noor", "Zainab", "Huda", "Salma", "Yasmin",
+                "Amira", "Rania", "Leila",
+            ],
+            Self::Nigerian => &[
+                "Adaeze",
+                "Chiamaka",
+                "Ngozi",
+                "Folake",
+                "Funmilayo",
+                "Amaka",
+                "Ifeoma",
+                "Yetunde",
+                "Bisola",
+                "Nneka",
+                "Kemi",
+                "Zainab",
+            ],
+        }
+    }
+
+    /// Common male first names of this origin.
+    pub fn male_names(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &[
+                "Oliver", "George", "Harry", "Jack", "Thomas", "William", "James", "Charlie",
+                "Henry", "Edward", "Alfie", "Samuel",
+            ],
+            Self::Spanish => &[
+                "José",
+                "Javier",
+                "Carlos",
+                "Alejandro",
+                "Miguel",
+                "Pablo",
+                "Diego",
+                "Antonio",
+                "Manuel",
+                "Sergio",
+                "Luis",
+                "Álvaro",
+            ],
+            Self::German => &[
+                "Lukas",
+                "Jonas",
+                "Felix",
+                "Maximilian",
+                "Paul",
+                "Leon",
+                "Stefan",
+                "Wolfgang",
+                "Jürgen",
+                "Klaus",
+                "Matthias",
+                "Tobias",
+            ],
+            Self::French => &[
+                "Louis", "Gabriel", "Hugo", "Jules", "Lucas", "Théo", "Antoine", "Mathieu",
+                "Julien", "Pierre", "Étienne", "Nicolas",
+            ],
+            Self::Italian => &[
+                "Francesco",
+                "Alessandro",
+                "Lorenzo",
+                "Matteo",
+                "Leonardo",
+                "Giuseppe",
+                "Marco",
+                "Luca",
+                "Giovanni",
+                "Andrea",
+                "Davide",
+                "Riccardo",
+            ],
+            Self::Dutch => &[
+                "Daan", "Sem", "Lucas", "Bram", "Thijs", "Jesse", "Niels", "Pieter", "Joris",
+                "Sander", "Ruben", "Wouter",
+            ],
+            Self::Polish => &[
+                "Jakub",
+                "Kacper",
+                "Piotr",
+                "Tomasz",
+                "Krzysztof",
+                "Michał",
+                "Paweł",
+                "Łukasz",
+                "Marcin",
+                "Wojciech",
+                "Andrzej",
+                "Mateusz",
+            ],
+            Self::Russian => &[
+                "Alexander",
+                "Dmitry",
+                "Ivan",
+                "Mikhail",
+                "Sergei",
+                "Andrei",
+                "Nikolai",
+                "Vladimir",
+                "Pavel",
+                "Alexei",
+                "Yuri",
+                "Maxim",
+            ],
+            Self::Chinese => &[
+                "Wei", "Jun", "Hao", "Lei", "Ming", "Jian", "Qiang", "Tao", "Bo", "Yong", "Feng",
+                "Chen",
+            ],
+            Self::Japanese => &[
+                "Haruto", "Yuto", "Sota", "Riku", "Takumi", "Kenji", "Hiroshi", "Daiki", "Kaito",
+                "Ren", "Takeshi", "Shota",
+            ],
+            Self::Korean => &[
+                "Min-jun",
+                "Seo-jun",
+                "Do-yun",
+                "Ji-ho",
+                "Ha-joon",
+                "Jae-won",
+                "Hyun-woo",
+                "Sung-min",
+                "Joon-young",
+                "Dong-hyun",
+                "Tae-yang",
+                "Woo-jin",
+            ],
+            Self::Indian => &[
+                "Aarav", "Vihaan", "Arjun", "Rohan", "Rahul", "Vikram", "Aditya", "Karan",
+                "Sanjay", "Amit", "Ravi", "Nikhil",
+            ],
+            Self::Arabic => &[
+                "Mohammed", "Ahmed", "Omar", "Ali", "Youssef", "Khalid", "Hassan", "Ibrahim",
+                "Tariq", "Karim", "Samir", "Hamza",
+            ],
+            Self::Nigerian => &[
+                "Chinedu",
+                "Emeka",
+                "Oluwaseun",
+                "Tunde",
+                "Adebayo",
+                "Obinna",
+                "Chukwuma",
+                "Babatunde",
+                "Ikenna",
+                "Segun",
+                "Femi",
+                "Musa",
+            ],
+        }
+    }
+
+    /// Common surnames of this origin.
+    pub fn surnames(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &[
+                "Smith", "Jones", "Taylor", "Brown", "Williams", "Wilson", "Johnson", "Davies",
+                "Robinson", "Wright", "Thompson", "Evans", "Walker", "Hughes", "Green",
+            ],
+            Self::Spanish => &[
+                "García",
+                "Fernández",
+                "González",
+                "Rodríguez",
+                "López",
+                "Martínez",
+                "Sánchez",
+                "Pérez",
+                "Gómez",
+                "Martín",
+                "Jiménez",
+                "Ruiz",
+                "Hernández",
+                "Díaz",
+                "Moreno",
+            ],
+            Self::German => &[
+                "Müller",
+                "Schmidt",
+                "Schneider",
+                "Fischer",
+                "Weber",
+                "Meyer",
+                "Wagner",
+                "Becker",
+                "Schulz",
+                "Hoffmann",
+                "Koch",
+                "Richter",
+                "Klein",
+                "Wolf",
+                "Schröder",
+            ],
+            Self::French => &[
+                "Martin", "Bernard", "Dubois", "Thomas", "Robert", "Richard", "Petit", "Durand",
+                "Leroy", "Moreau", "Simon", "Laurent", "Lefèvre", "Michel", "Garnier",
+            ],
+            Self::Italian => &[
+                "Rossi", "Russo", "Ferrari", "Esposito", "Bianchi", "Romano", "Colombo", "Ricci",
+                "Marino", "Greco", "Bruno", "Gallo", "Conti", "De Luca", "Costa",
+            ],
+            Self::Dutch => &[
+                "de Jong",
+                "Jansen",
+                "de Vries",
+                "van den Berg",
+                "van Dijk",
+                "Bakker",
+                "Janssen",
+                "Visser",
+                "Smit",
+                "Meijer",
+                "de Boer",
+                "Mulder",
+                "de Groot",
+                "Bos",
+                "Vos",
+            ],
+            Self::Polish => &[
+                "Nowak",
+                "Kowalski",
+                "Wiśniewski",
+                "Wójcik",
+                "Kowalczyk",
+                "Kamiński",
+                "Lewandowski",
+                "Zieliński",
+                "Szymański",
+                "Woźniak",
+                "Dąbrowski",
+                "Kozłowski",
+                "Jankowski",
+                "Mazur",
+                "Krawczyk",
+            ],
+            Self::Russian => &[
+                "Ivanov",
+                "Smirnov",
+                "Kuznetsov",
+                "Popov",
+                "Vasiliev",
+                "Petrov",
+                "Sokolov",
+                "Mikhailov",
+                "Novikov",
+                "Fedorov",
+                "Morozov",
+                "Volkov",
+                "Alekseev",
+                "Lebedev",
+                "Semenov",
+            ],
+            Self::Chinese => &[
+                "Wang", "Li", "Zhang", "Liu", "Chen", "Yang", "Huang", "Zhao", "Wu", "Zhou", "Xu",
+                "Sun", "Ma", "Zhu", "Hu",
+            ],
+            Self::Japanese => &[
+                "Sato",
+                "Suzuki",
+                "Takahashi",
+                "Tanaka",
+                "Watanabe",
+                "Ito",
+                "Yamamoto",
+                "Nakamura",
+                "Kobayashi",
+                "Kato",
+                "Yoshida",
+                "Yamada",
+                "Sasaki",
+                "Yamaguchi",
+                "Matsumoto",
+            ],
+            Self::Korean => &[
+                "Kim", "Lee", "Park", "Choi", "Jung", "Kang", "Cho", "Yoon", "Jang", "Lim", "Han",
+                "Oh", "Seo", "Shin", "Kwon",
+            ],
+            Self::Indian => &[
+                "Sharma", "Patel", "Singh", "Kumar", "Gupta", "Reddy", "Iyer", "Nair", "Mehta",
+                "Joshi", "Rao", "Desai", "Chopra", "Banerjee", "Verma",
+            ],
+            Self::Arabic => &[
+                "Al-Sayed", "Haddad", "Khalil", "Mansour", "Nasser", "Hamdan", "Saleh", "Aziz",
+                "Rahman", "Farouk", "Hussein", "Suleiman", "Abdullah", "Qasim", "Darwish",
+            ],
+            Self::Nigerian => &[
+                "Okafor", "Adeyemi", "Okonkwo", "Eze", "Balogun", "Nwosu", "Adebayo", "Okoro",
+                "Bello", "Ogunleye", "Chukwu", "Olawale", "Ibrahim", "Nnamdi", "Afolabi",
+            ],
+        }
+    }
+
+    /// Returns the country most associated with the origin, if it is one of the supported countries.
+    pub fn country(self) -> Option<Country> {
+        match self {
+            Self::English => Some(Country::UnitedKingdom),
+            Self::Spanish => Some(Country::Spain),
+            Self::German => Some(Country::Germany),
+            Self::French => Some(Country::France),
+            Self::Italian => Some(Country::Italy),
+            Self::Dutch => Some(Country::Netherlands),
+            Self::Japanese => Some(Country::Japan),
+            Self::Polish
+            | Self::Russian
+            | Self::Chinese
+            | Self::Korean
+            | Self::Indian
+            | Self::Arabic
+            | Self::Nigerian => None,
+        }
+    }
+
+    /// Returns the first names matching the gender. `Person`s without a gender or with a gender
+    /// that has no dedicated list pick the female or male names at random.
+    pub(crate) fn first_names<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+        gender: Option<Gender>,
+    ) -> &'static [&'static str] {
+        match gender {
+            Some(Gender::Female) => self.female_names(),
+            Some(Gender::Male) => self.male_names(),
+            _ if rng.gen_bool(0.5) => self.female_names(),
+            _ => self.male_names(),
+        }
+    }
+}
+impl std::fmt::Display for Origin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
@@ -0,0 +1,43 @@
+use std::num::NonZeroUsize;
+
+use crate::{GenerationConfig, Person};
+
+impl Person {
+    /// Same as [`Person::random_many`], but splits the generation across all available threads,
+    /// each using its own random number generator.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// assert_eq!(Person::random_many_par(10_000).len(), 10_000);
+    /// ```
+    pub fn random_many_par(n: usize) -> Vec<Self> {
+        Self::random_many_par_with_config(n, &GenerationConfig::default())
+    }
+
+    /// Same as [`Person::random_many_par`], but uses the probabilities of the given config.
+    pub fn random_many_par_with_config(n: usize, config: &GenerationConfig) -> Vec<Self> {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(n.max(1));
+        let chunk_size = n.div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|i| {
+                    let count = chunk_size.min(n.saturating_sub(i * chunk_size));
+                    scope.spawn(move || {
+                        Self::random_many_with_config_custom_rng(
+                            &mut rand::thread_rng(),
+                            count,
+                            config,
+                        )
+                    })
+                })
+                .collect();
+            let mut persons = Vec::with_capacity(n);
+            for handle in handles {
+                persons.extend(handle.join().unwrap());
+            }
+            persons
+        })
+    }
+}
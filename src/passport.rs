@@ -0,0 +1,182 @@
+use chrono::{DateTime, Duration, Months, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{Country, Person};
+
+/// Salts the identity random number generator for passports.
+const PASSPORT_SALT: u64 = 0x5041_5300;
+/// The age below which passports are only valid for five instead of ten years.
+const ADULT_PASSPORT_AGE: u32 = 16;
+/// The letters used in passport numbers, without "O" which is easily confused with "0".
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNPQRSTUVWXYZ";
+/// The characters of German passport numbers, which leave out vowels and easily confused letters.
+const GERMAN_CHARACTERS: &[u8] = b"CFGHJKLMNPRTVWXYZ0123456789";
+
+/// A passport issued to a `Person`, with a number in the format of the issuing country.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TravelDocument {
+    pub number: String,
+    pub country: Country,
+    pub surname: String,
+    /// The first and middle names.
+    pub given_names: String,
+    pub date_of_birth: DateTime<Utc>,
+    pub issued: DateTime<Utc>,
+    pub expires: DateTime<Utc>,
+}
+impl TravelDocument {
+    /// Returns whether the passport is expired at the given time.
+    pub fn is_expired_at(&self, reference: DateTime<Utc>) -> bool {
+        self.expires <= reference
+    }
+}
+
+impl Person {
+    /// Returns the passport of the `Person`, issued by their nationality, or the United States if
+    /// they have none. It is derived from their identity, so the same `Person` always has the same
+    /// passport number. The passport is valid today, and it is renewed whenever it expires, so the
+    /// dates only change with a renewal. Like in [`Person::get_random_travel_document`], it is
+    /// valid for ten years, or five years if it was issued before the `Person` turned 16.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Country, Person};
+    /// let person = Person::builder().nationality(Country::Germany).alive().build();
+    /// let passport = person.get_travel_document();
+    /// assert_eq!(passport, person.get_travel_document());
+    /// assert_eq!(passport.country, Country::Germany);
+    /// assert!(passport.issued >= person.get_date_of_birth());
+    /// assert!(!passport.is_expired_at(Utc::now()));
+    /// ```
+    pub fn get_travel_document(&self) -> TravelDocument {
+        let rng = &mut self.identity_rng(PASSPORT_SALT);
+        let country = self.get_nationality().unwrap_or(Country::UnitedStates);
+        let end = self.date_of_death.unwrap_or_else(Utc::now);
+        let number = passport_number(rng, country);
+        // The first passport is issued within five years after birth.
+        let first_years = (end - self.date_of_birth).min(Duration::days(5 * 365));
+        let mut issued = self.date_of_birth
+            + Duration::milliseconds(rng.gen_range(0..=first_years.num_milliseconds().max(0)));
+        loop {
+            let expires = issued + Months::new(12 * self.passport_validity(issued));
+            if expires > end {
+                break;
+            }
+            issued = expires;
+        }
+        self.travel_document(number, country, issued)
+    }
+
+    /// Generates a new passport issued by the `Person`'s nationality, or the United States if they
+    /// have none, with a different number on every call, see [`Person::get_travel_document`] for the
+    /// one attached to the `Person`. It is valid today, for ten years from the issue date, or five
+    /// years if it was issued before the `Person` turned 16. A deceased `Person`'s passport was valid
+    /// on their date of death.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::{Country, Person};
+    /// let person = Person::builder().nationality(Country::Canada).alive().build();
+    /// let passport = person.get_random_travel_document();
+    /// assert_eq!(passport.country, Country::Canada);
+    /// assert_eq!(passport.number.len(), 8);
+    /// assert!(passport.issued >= person.get_date_of_birth());
+    /// assert!(!passport.is_expired_at(Utc::now()));
+    /// ```
+    pub fn get_random_travel_document(&self) -> TravelDocument {
+        self.get_random_travel_document_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_travel_document`], but uses the given random number generator.
+    pub fn get_random_travel_document_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> TravelDocument {
+        let country = self.get_nationality().unwrap_or(Country::UnitedStates);
+        let end = self.date_of_death.unwrap_or_else(Utc::now);
+
+        let mut issued = self.random_issue_date(rng, end, 10);
+        if self.age_at(issued) < ADULT_PASSPORT_AGE {
+            issued = self.random_issue_date(rng, end, 5);
+        }
+        self.travel_document(passport_number(rng, country), country, issued)
+    }
+
+    /// Returns the years a passport issued at the date is valid for.
+    fn passport_validity(&self, issued: DateTime<Utc>) -> u32 {
+        if self.age_at(issued) < ADULT_PASSPORT_AGE {
+            5
+        } else {
+            10
+        }
+    }
+
+    /// Returns the passport with the number and issue date, in the `Person`'s names.
+    fn travel_document(
+        &self,
+        number: String,
+        country: Country,
+        issued: DateTime<Utc>,
+    ) -> TravelDocument {
+        TravelDocument {
+            number,
+            country,
+            surname: self.last_name.clone(),
+            given_names: std::iter::once(&self.first_name)
+                .chain(&self.middle_names)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" "),
+            date_of_birth: self.date_of_birth,
+            issued,
+            expires: issued + Months::new(12 * self.passport_validity(issued)),
+        }
+    }
+
+    /// Picks an issue date after the date of birth, at most the given number of years before the end.
+    fn random_issue_date<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        end: DateTime<Utc>,
+        years: u32,
+    ) -> DateTime<Utc> {
+        // Leave a day so a passport issued right at the start hasn't expired at the end.
+        let start = (end - Months::new(12 * years) + Duration::days(1)).max(self.date_of_birth);
+        let range_millis = (end - start).num_milliseconds().max(0);
+        start + Duration::milliseconds(rng.gen_range(0..=range_millis))
+    }
+}
+
+/// Generates a passport number in the format of the country, like "AB123456" for Canada or
+/// "12AB34567" for France.
+fn passport_number<R: Rng + ?Sized>(rng: &mut R, country: Country) -> String {
+    match country {
+        Country::UnitedStates | Country::UnitedKingdom => from_pattern(rng, "999999999"),
+        Country::Canada => from_pattern(rng, "AA999999"),
+        Country::Germany => {
+            let first = *b"CFGHJK".choose(rng).unwrap() as char;
+            std::iter::once(first)
+                .chain((0..8).map(|_| *GERMAN_CHARACTERS.choose(rng).unwrap() as char))
+                .collect()
+        }
+        Country::France => from_pattern(rng, "99AA99999"),
+        Country::Spain => from_pattern(rng, "AAA999999"),
+        Country::Italy | Country::Japan => from_pattern(rng, "AA9999999"),
+        Country::Netherlands => from_pattern(rng, "AAXXXXXX9"),
+        Country::Australia => from_pattern(rng, "A9999999"),
+    }
+}
+
+/// Fills a pattern where 'A' stands for a letter, '9' for a digit and 'X' for either.
+fn from_pattern<R: Rng + ?Sized>(rng: &mut R, pattern: &str) -> String {
+    pattern
+        .chars()
+        .map(|p| match p {
+            'A' => *LETTERS.choose(rng).unwrap() as char,
+            '9' => rng.gen_range(b'0'..=b'9') as char,
+            'X' if rng.gen_bool(0.5) => *LETTERS.choose(rng).unwrap() as char,
+            'X' => rng.gen_range(b'0'..=b'9') as char,
+            p => p,
+        })
+        .collect()
+}
@@ -0,0 +1,158 @@
+use chrono::Datelike;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{transliterate, Person};
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &[u8] = b"0123456789";
+const SYMBOLS: &[u8] = b"!@#$%^&*-_+=?";
+
+/// The rules a password generated with [`Person::get_random_password`] follows.
+/// ## Example
+/// ```rust
+/// use person::{PasswordPolicy, Person};
+/// let policy = PasswordPolicy {
+///     min_length: 8,
+///     max_length: 10,
+///     require_symbol: false,
+///     ..PasswordPolicy::default()
+/// };
+/// let password = Person::random().get_random_password(&policy);
+/// assert!((8..=10).contains(&password.len()));
+/// assert!(password.chars().any(|c| c.is_ascii_digit()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PasswordPolicy {
+    /// The minimum number of characters. Defaults to 12.
+    pub min_length: usize,
+    /// The maximum number of characters. Defaults to 16.
+    pub max_length: usize,
+    /// Whether at least one lowercase letter is required. Defaults to `true`.
+    pub require_lowercase: bool,
+    /// Whether at least one uppercase letter is required. Defaults to `true`.
+    pub require_uppercase: bool,
+    /// Whether at least one digit is required. Defaults to `true`.
+    pub require_digit: bool,
+    /// Whether at least one of `!@#$%^&*-_+=?` is required. Defaults to `true`.
+    pub require_symbol: bool,
+    /// Whether the password is built from the `Person`'s name and year of birth, like "Jane#Doe1985",
+    /// instead of random characters. Defaults to `false`.
+    pub memorable: bool,
+}
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 12,
+            max_length: 16,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: true,
+            memorable: false,
+        }
+    }
+}
+impl PasswordPolicy {
+    /// A policy for memorable passwords built from the `Person`'s name and year of birth.
+    pub fn memorable() -> Self {
+        Self {
+            memorable: true,
+            ..Self::default()
+        }
+    }
+
+    fn required_classes(&self) -> Vec<&'static [u8]> {
+        [
+            (self.require_lowercase, LOWERCASE),
+            (self.require_uppercase, UPPERCASE),
+            (self.require_digit, DIGITS),
+            (self.require_symbol, SYMBOLS),
+        ]
+        .into_iter()
+        .filter_map(|(required, class)| required.then_some(class))
+        .collect()
+    }
+}
+
+impl Person {
+    /// Generates a random password following the given policy.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{PasswordPolicy, Person};
+    /// let dob = Utc.with_ymd_and_hms(1985, 4, 12, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("Jane", None, "Doe", dob);
+    /// let password = person.get_random_password(&PasswordPolicy::memorable());
+    /// assert!(password.starts_with("Jane"));
+    /// assert!(password.contains("1985"));
+    /// ```
+    pub fn get_random_password(&self, policy: &PasswordPolicy) -> String {
+        self.get_random_password_custom_rng(&mut rand::thread_rng(), policy)
+    }
+
+    /// Same as [`Person::get_random_password`], but uses the given random number generator.
+    pub fn get_random_password_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        policy: &PasswordPolicy,
+    ) -> String {
+        let required = policy.required_classes();
+        let min_length = policy.min_length.max(required.len()).max(1);
+        let length = rng.gen_range(min_length..=policy.max_length.max(min_length));
+
+        let mut password: Vec<u8> = if policy.memorable {
+            let symbol = *SYMBOLS.choose(rng).unwrap() as char;
+            let mut words = vec![capitalize(&self.get_random_nickname_custom_rng(rng))];
+            if !self.is_mononym() {
+                words.push(capitalize(&self.last_name));
+            }
+            let mut password = words.join(&symbol.to_string());
+            password.push_str(&self.date_of_birth.year().to_string());
+            password.into_bytes()
+        } else {
+            vec![]
+        };
+        let missing = |password: &[u8]| -> Vec<&'static [u8]> {
+            required
+                .iter()
+                .filter(|class| !password.iter().any(|c| class.contains(c)))
+                .copied()
+                .collect()
+        };
+        // Shorten the password until the missing character classes fit in.
+        while password.len() + missing(&password).len() > length {
+            password.pop();
+        }
+        for class in missing(&password) {
+            password.push(*class.choose(rng).unwrap());
+        }
+        let filler: Vec<u8> = if policy.memorable {
+            DIGITS.to_vec()
+        } else if required.is_empty() {
+            [LOWERCASE, UPPERCASE, DIGITS].concat()
+        } else {
+            required.concat()
+        };
+        while password.len() < length {
+            password.push(*filler.choose(rng).unwrap());
+        }
+        if !policy.memorable {
+            password.shuffle(rng);
+        }
+        String::from_utf8(password).unwrap()
+    }
+}
+
+/// Converts the name to ASCII letters and digits, starting with an uppercase letter.
+fn capitalize(name: &str) -> String {
+    let name: String = transliterate::to_ascii(name)
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect();
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => name,
+    }
+}
@@ -0,0 +1,198 @@
+use chrono::{Datelike, Months, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, transliterate, Person};
+
+/// The longest a generated card is valid for, in years.
+const MAX_CARD_VALIDITY_YEARS: u32 = 5;
+
+/// A payment card network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CardNetwork {
+    Visa,
+    Mastercard,
+    Amex,
+}
+impl CardNetwork {
+    /// All networks.
+    pub const ALL: [CardNetwork; 3] = [
+        CardNetwork::Visa,
+        CardNetwork::Mastercard,
+        CardNetwork::Amex,
+    ];
+
+    /// Returns the issuer identification number prefixes of the networks' published test cards,
+    /// which payment processors accept in test mode and never charge.
+    pub fn test_prefixes(self) -> &'static [&'static str] {
+        match self {
+            Self::Visa => &["424242", "411111", "401288"],
+            Self::Mastercard => &["555555", "222300", "510510"],
+            Self::Amex => &["378282", "371449", "378734"],
+        }
+    }
+
+    /// Returns the number of digits of the card numbers.
+    pub fn number_length(self) -> usize {
+        match self {
+            Self::Amex => 15,
+            _ => 16,
+        }
+    }
+
+    /// Returns the number of digits of the card verification code.
+    pub fn cvv_length(self) -> usize {
+        match self {
+            Self::Amex => 4,
+            _ => 3,
+        }
+    }
+}
+impl std::fmt::Display for CardNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Visa => "Visa",
+            Self::Mastercard => "Mastercard",
+            Self::Amex => "American Express",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A payment card with a test number that passes the Luhn check.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PaymentCard {
+    pub network: CardNetwork,
+    /// The digits of the card number, without spaces.
+    pub number: String,
+    /// The name on the card, in uppercase ASCII.
+    pub holder: String,
+    pub expiry_month: u32,
+    pub expiry_year: i32,
+    pub cvv: String,
+}
+impl PaymentCard {
+    /// Generates a random card of the network, with a holder name of "CARDHOLDER".
+    pub fn random(network: CardNetwork) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), network)
+    }
+
+    /// Same as [`PaymentCard::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, network: CardNetwork) -> Self {
+        let prefix = network.test_prefixes().choose(rng).unwrap();
+        let mut number = format!(
+            "{prefix}{}",
+            random_digits(rng, network.number_length() - prefix.len() - 1)
+        );
+        number.push(luhn_check_digit(&number));
+
+        let expiry = Utc::now() + Months::new(rng.gen_range(1..=12 * MAX_CARD_VALIDITY_YEARS));
+        Self {
+            network,
+            number,
+            holder: "CARDHOLDER".to_string(),
+            expiry_month: expiry.month(),
+            expiry_year: expiry.year(),
+            cvv: random_digits(rng, network.cvv_length()),
+        }
+    }
+
+    /// Returns the card number in groups, like "4242 4242 4242 4242", or "3782 822463 10005" for Amex.
+    pub fn formatted_number(&self) -> String {
+        let groups: &[usize] = match self.network {
+            CardNetwork::Amex => &[4, 6, 5],
+            _ => &[4, 4, 4, 4],
+        };
+        let mut rest = self.number.as_str();
+        let mut parts = vec![];
+        for &length in groups {
+            let (part, tail) = rest.split_at(length.min(rest.len()));
+            parts.push(part);
+            rest = tail;
+        }
+        parts.join(" ")
+    }
+
+    /// Returns the expiry date as printed on the card, like "09/28".
+    pub fn expiry(&self) -> String {
+        format!("{:02}/{:02}", self.expiry_month, self.expiry_year % 100)
+    }
+}
+impl std::fmt::Display for PaymentCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.network,
+            self.formatted_number(),
+            self.expiry()
+        )
+    }
+}
+
+impl Person {
+    /// Generates a payment card of the network in the `Person`'s name, transliterated to ASCII.
+    /// ## Example
+    /// ```rust
+    /// use person::{is_luhn_valid, CardNetwork, Person};
+    /// let person = Person::random();
+    /// let card = person.get_random_payment_card(CardNetwork::Amex);
+    /// assert_eq!(card.number.len(), 15);
+    /// assert_eq!(card.cvv.len(), 4);
+    /// assert!(is_luhn_valid(&card.number));
+    /// assert!(card.holder.is_ascii());
+    /// ```
+    pub fn get_random_payment_card(&self, network: CardNetwork) -> PaymentCard {
+        self.get_random_payment_card_custom_rng(&mut rand::thread_rng(), network)
+    }
+
+    /// Same as [`Person::get_random_payment_card`], but uses the given random number generator.
+    pub fn get_random_payment_card_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        network: CardNetwork,
+    ) -> PaymentCard {
+        PaymentCard {
+            holder: transliterate::to_ascii(&self.get_full_name()).to_uppercase(),
+            ..PaymentCard::random_custom_rng(rng, network)
+        }
+    }
+}
+
+/// Returns whether the number passes the Luhn check used by payment cards.
+/// Spaces are ignored, and numbers with other non-digit characters are invalid.
+/// ## Example
+/// ```rust
+/// use person::is_luhn_valid;
+/// assert!(is_luhn_valid("4242 4242 4242 4242"));
+/// assert!(!is_luhn_valid("4242 4242 4242 4241"));
+/// ```
+pub fn is_luhn_valid(number: &str) -> bool {
+    let digits: String = number.chars().filter(|&c| c != ' ').collect();
+    !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && luhn_sum(&digits, false).is_multiple_of(10)
+}
+
+/// Computes the digit that makes the number pass the Luhn check when appended.
+pub(crate) fn luhn_check_digit(number: &str) -> char {
+    char::from(b'0' + ((10 - luhn_sum(number, true) % 10) % 10) as u8)
+}
+
+/// Sums the digits of the number, doubling every second digit from the right, starting with the
+/// last digit if `double_last` is set.
+fn luhn_sum(number: &str, double_last: bool) -> u32 {
+    number
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = (b - b'0') as u32;
+            if (i % 2 == 0) == double_last {
+                let doubled = digit * 2;
+                doubled - if doubled > 9 { 9 } else { 0 }
+            } else {
+                digit
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,89 @@
+use rand::Rng;
+
+use crate::sample_normal;
+
+/// The score from which a trait counts as pronounced in adjectives.
+const HIGH_SCORE: u8 = 60;
+/// The score below which a trait counts as weak in adjectives.
+const LOW_SCORE: u8 = 40;
+/// The average score, from which a trait picks the first letter of its pair in the MBTI-style type.
+const AVERAGE_SCORE: u8 = 50;
+
+/// The personality of a `Person` as Big Five scores between 0 and 100, where 50 is average.
+/// Conscientiousness and agreeableness tend to rise with age, and neuroticism to fall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Personality {
+    pub openness: u8,
+    pub conscientiousness: u8,
+    pub extraversion: u8,
+    pub agreeableness: u8,
+    pub neuroticism: u8,
+}
+impl Personality {
+    /// Creates a random personality that is plausible for the age.
+    pub fn random(age: u32) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), age)
+    }
+
+    /// Same as [`Personality::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, age: u32) -> Self {
+        // Traits mature until about 60, by around ten points from adolescence.
+        let maturity = age.clamp(15, 60).saturating_sub(15) as f64 / 45.0 * 10.0;
+        let mut score = |mean: f64| sample_normal(rng, mean, 15.0).round().clamp(0.0, 100.0) as u8;
+        Self {
+            openness: score(50.0),
+            conscientiousness: score(45.0 + maturity),
+            extraversion: score(50.0),
+            agreeableness: score(45.0 + maturity),
+            neuroticism: score(55.0 - maturity),
+        }
+    }
+
+    /// Returns the four-letter MBTI-style type derived from the scores, like "ENFJ": extraversion
+    /// decides between E and I, openness between N and S, agreeableness between F and T and
+    /// conscientiousness between J and P.
+    /// ## Example
+    /// ```rust
+    /// use person::Personality;
+    /// let personality = Personality {
+    ///     openness: 80,
+    ///     conscientiousness: 35,
+    ///     extraversion: 20,
+    ///     agreeableness: 65,
+    ///     neuroticism: 50,
+    /// };
+    /// assert_eq!(personality.mbti_type(), "INFP");
+    /// assert_eq!(personality.adjectives(), ["curious", "spontaneous", "reserved", "warm"]);
+    /// ```
+    pub fn mbti_type(&self) -> String {
+        let letter =
+            |score: u8, high: char, low: char| if score >= AVERAGE_SCORE { high } else { low };
+        [
+            letter(self.extraversion, 'E', 'I'),
+            letter(self.openness, 'N', 'S'),
+            letter(self.agreeableness, 'F', 'T'),
+            letter(self.conscientiousness, 'J', 'P'),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Returns adjectives describing the pronounced traits, in the order openness, conscientiousness,
+    /// extraversion, agreeableness and neuroticism, for bios and persona descriptions.
+    pub fn adjectives(&self) -> Vec<&'static str> {
+        [
+            (self.openness, "curious", "practical"),
+            (self.conscientiousness, "organized", "spontaneous"),
+            (self.extraversion, "outgoing", "reserved"),
+            (self.agreeableness, "warm", "direct"),
+            (self.neuroticism, "sensitive", "calm"),
+        ]
+        .into_iter()
+        .filter_map(|(score, high, low)| match score {
+            s if s >= HIGH_SCORE => Some(high),
+            s if s < LOW_SCORE => Some(low),
+            _ => None,
+        })
+        .collect()
+    }
+}
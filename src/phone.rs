@@ -0,0 +1,97 @@
+use rand::Rng;
+
+use crate::{random_digits, Country};
+
+/// A mobile phone number of a country.
+/// ## Example
+/// ```rust
+/// use person::{Country, PhoneNumber};
+/// let number = PhoneNumber::random(Country::UnitedKingdom);
+/// assert!(number.national().starts_with("07"));
+/// assert!(number.e164().starts_with("+447"));
+/// assert_eq!(number.e164().len(), 13);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PhoneNumber {
+    country: Country,
+    /// The national significant number: the digits after the calling code, without a trunk prefix.
+    number: String,
+}
+impl PhoneNumber {
+    /// Creates a random mobile phone number of the given country.
+    pub fn random(country: Country) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`PhoneNumber::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Self {
+        let number = match country {
+            Country::UnitedStates | Country::Canada => {
+                // Area codes and exchanges don't start with 0 or 1 and don't end in 11.
+                let area = loop {
+                    let area = format!("{}{}", rng.gen_range(2..10), random_digits(rng, 2));
+                    if !area.ends_with("11") {
+                        break area;
+                    }
+                };
+                let exchange = loop {
+                    let exchange = format!("{}{}", rng.gen_range(2..10), random_digits(rng, 2));
+                    if !exchange.ends_with("11") {
+                        break exchange;
+                    }
+                };
+                format!("{area}{exchange}{}", random_digits(rng, 4))
+            }
+            Country::UnitedKingdom => format!("7{}{}", rng.gen_range(1..10), random_digits(rng, 8)),
+            Country::Germany => format!("1{}{}", rng.gen_range(5..8), random_digits(rng, 9)),
+            Country::France => format!("{}{}", rng.gen_range(6..8), random_digits(rng, 8)),
+            Country::Spain => format!("6{}", random_digits(rng, 8)),
+            Country::Italy => format!("3{}", random_digits(rng, 9)),
+            Country::Netherlands => format!("6{}", random_digits(rng, 8)),
+            Country::Australia => format!("4{}", random_digits(rng, 8)),
+            Country::Japan => format!("{}0{}", rng.gen_range(7..10), random_digits(rng, 8)),
+        };
+        Self { country, number }
+    }
+
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    /// Returns the number in the international E.164 format, like "+14155550123".
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.country.calling_code(), self.number)
+    }
+
+    /// Returns the number formatted as it is dialed and written within its country,
+    /// like "(415) 555-0123" in the US or "06 12 34 56 78" in France.
+    pub fn national(&self) -> String {
+        let n = &self.number;
+        match self.country {
+            Country::UnitedStates | Country::Canada => {
+                format!("({}) {}-{}", &n[..3], &n[3..6], &n[6..])
+            }
+            Country::UnitedKingdom => format!("0{} {}", &n[..4], &n[4..]),
+            Country::Germany => format!("0{} {}", &n[..3], &n[3..]),
+            Country::France => format!(
+                "0{} {} {} {} {}",
+                &n[..1],
+                &n[1..3],
+                &n[3..5],
+                &n[5..7],
+                &n[7..]
+            ),
+            Country::Spain => format!("{} {} {}", &n[..3], &n[3..6], &n[6..]),
+            Country::Italy => format!("{} {} {}", &n[..3], &n[3..6], &n[6..]),
+            Country::Netherlands => format!("0{} {}", &n[..1], &n[1..]),
+            Country::Australia => format!("0{} {} {}", &n[..3], &n[3..6], &n[6..]),
+            Country::Japan => format!("0{}-{}-{}", &n[..2], &n[2..6], &n[6..]),
+        }
+    }
+}
+/// Displays the number in the E.164 format.
+impl std::fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.e164())
+    }
+}
@@ -0,0 +1,191 @@
+use crate::transliterate;
+
+/// Encodes a name with the American Soundex algorithm, e.g. "Robert" and "Rupert" both become "R163".
+/// Accented letters are transliterated first and other characters are ignored.
+/// Returns an empty string if the name contains no letters.
+/// ## Example
+/// ```rust
+/// use person::soundex;
+/// assert_eq!(soundex("Robert"), "R163");
+/// assert_eq!(soundex("Rupert"), "R163");
+/// assert_eq!(soundex("Tymczak"), "T522");
+/// assert_eq!(soundex("Ashcraft"), "A261");
+/// ```
+pub fn soundex(name: &str) -> String {
+    fn code(c: char) -> Option<char> {
+        match c {
+            'B' | 'F' | 'P' | 'V' => Some('1'),
+            'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+            'D' | 'T' => Some('3'),
+            'L' => Some('4'),
+            'M' | 'N' => Some('5'),
+            'R' => Some('6'),
+            _ => None,
+        }
+    }
+
+    let letters = ascii_letters(name);
+    let Some(&first) = letters.first() else {
+        return String::new();
+    };
+    let mut result = first.to_string();
+    let mut last_code = code(first);
+    for &c in &letters[1..] {
+        match c {
+            // H and W don't separate letters with the same code.
+            'H' | 'W' => continue,
+            _ => {
+                let current = code(c);
+                if current.is_some() && current != last_code {
+                    result.extend(current);
+                    if result.len() == 4 {
+                        break;
+                    }
+                }
+                last_code = current;
+            }
+        }
+    }
+    format!("{result:0<4}")
+}
+
+/// Encodes a name with the original Metaphone algorithm, e.g. "Knight" becomes "NT".
+/// "TH" is encoded as "0", like in the original algorithm.
+/// Accented letters are transliterated first and other characters are ignored.
+/// ## Example
+/// ```rust
+/// use person::metaphone;
+/// assert_eq!(metaphone("Knight"), "NT");
+/// assert_eq!(metaphone("Schmidt"), "SKMTT");
+/// assert_eq!(metaphone("Catherine"), "K0RN");
+/// assert_eq!(metaphone("Catherine"), metaphone("Kathryn"));
+/// ```
+pub fn metaphone(name: &str) -> String {
+    let mut letters = ascii_letters(name);
+    match letters.as_slice() {
+        ['A', 'E', ..] | ['G', 'N', ..] | ['K', 'N', ..] | ['P', 'N', ..] | ['W', 'R', ..] => {
+            letters.remove(0);
+        }
+        ['X', ..] => letters[0] = 'S',
+        ['W', 'H', ..] => {
+            letters.remove(1);
+        }
+        _ => {}
+    }
+
+    let is_vowel = |c: Option<&char>| matches!(c, Some('A' | 'E' | 'I' | 'O' | 'U'));
+    let is_front_vowel = |c: Option<&char>| matches!(c, Some('E' | 'I' | 'Y'));
+    let mut result = String::new();
+    for (i, &c) in letters.iter().enumerate() {
+        let prev = i.checked_sub(1).and_then(|i| letters.get(i));
+        let next = letters.get(i + 1);
+        let after_next = letters.get(i + 2);
+        if prev == Some(&c) && c != 'C' {
+            continue;
+        }
+        match c {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if i == 0 {
+                    result.push(c);
+                }
+            }
+            'B' => {
+                if !(prev == Some(&'M') && next.is_none()) {
+                    result.push('B');
+                }
+            }
+            'C' => {
+                if next == Some(&'I') && after_next == Some(&'A') {
+                    result.push('X');
+                } else if next == Some(&'H') {
+                    result.push(if prev == Some(&'S') { 'K' } else { 'X' });
+                } else if is_front_vowel(next) {
+                    if prev != Some(&'S') {
+                        result.push('S');
+                    }
+                } else {
+                    result.push('K');
+                }
+            }
+            'D' => {
+                if next == Some(&'G') && is_front_vowel(after_next) {
+                    result.push('J');
+                } else {
+                    result.push('T');
+                }
+            }
+            'G' => {
+                if next == Some(&'H') && !(after_next.is_none() || is_vowel(after_next)) {
+                    continue;
+                }
+                if next == Some(&'N')
+                    && (after_next.is_none()
+                        || (after_next == Some(&'E') && letters.get(i + 3) == Some(&'D')))
+                {
+                    continue;
+                }
+                if prev == Some(&'D') && is_front_vowel(next) {
+                    continue;
+                }
+                if is_front_vowel(next) {
+                    result.push('J');
+                } else if next == Some(&'H') {
+                    result.push('F');
+                } else {
+                    result.push('K');
+                }
+            }
+            'H' => {
+                let silent = matches!(prev, Some('C' | 'S' | 'P' | 'T' | 'G'))
+                    || (is_vowel(prev) && !is_vowel(next));
+                if !silent {
+                    result.push('H');
+                }
+            }
+            'K' => {
+                if prev != Some(&'C') {
+                    result.push('K');
+                }
+            }
+            'P' => result.push(if next == Some(&'H') { 'F' } else { 'P' }),
+            'Q' => result.push('K'),
+            'S' => {
+                if next == Some(&'H')
+                    || (next == Some(&'I') && matches!(after_next, Some('O' | 'A')))
+                {
+                    result.push('X');
+                } else {
+                    result.push('S');
+                }
+            }
+            'T' => {
+                if next == Some(&'I') && matches!(after_next, Some('O' | 'A')) {
+                    result.push('X');
+                } else if next == Some(&'H') {
+                    result.push('0');
+                } else if !(next == Some(&'C') && after_next == Some(&'H')) {
+                    result.push('T');
+                }
+            }
+            'V' => result.push('F'),
+            'W' | 'Y' => {
+                if is_vowel(next) {
+                    result.push(c);
+                }
+            }
+            'X' => result.push_str("KS"),
+            'Z' => result.push('S'),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Returns the uppercase ASCII letters of the transliterated name.
+fn ascii_letters(name: &str) -> Vec<char> {
+    transliterate::to_ascii(name)
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
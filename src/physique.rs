@@ -0,0 +1,208 @@
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+use crate::{sample_normal, Gender};
+
+/// An ABO blood type with its Rh factor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BloodType {
+    OPositive,
+    ONegative,
+    APositive,
+    ANegative,
+    BPositive,
+    BNegative,
+    AbPositive,
+    AbNegative,
+}
+impl BloodType {
+    /// All blood types.
+    pub const ALL: [BloodType; 8] = [
+        BloodType::OPositive,
+        BloodType::ONegative,
+        BloodType::APositive,
+        BloodType::ANegative,
+        BloodType::BPositive,
+        BloodType::BNegative,
+        BloodType::AbPositive,
+        BloodType::AbNegative,
+    ];
+
+    /// Returns the short notation of the blood type, like "AB+".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OPositive => "O+",
+            Self::ONegative => "O-",
+            Self::APositive => "A+",
+            Self::ANegative => "A-",
+            Self::BPositive => "B+",
+            Self::BNegative => "B-",
+            Self::AbPositive => "AB+",
+            Self::AbNegative => "AB-",
+        }
+    }
+}
+impl std::fmt::Display for BloodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EyeColor {
+    Brown,
+    Blue,
+    Hazel,
+    Green,
+    Gray,
+    Amber,
+}
+impl std::fmt::Display for EyeColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{self:?}").to_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HairColor {
+    Black,
+    Brown,
+    Blond,
+    Red,
+    Gray,
+    White,
+}
+impl std::fmt::Display for HairColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{self:?}").to_lowercase())
+    }
+}
+
+/// Physical attributes of a `Person` at the time they were generated.
+/// ## Example
+/// ```rust
+/// use person::{Gender, Physique};
+/// let mut rng = rand::thread_rng();
+/// let adult = Physique::random_custom_rng(&mut rng, 35, Some(Gender::Male));
+/// let toddler = Physique::random_custom_rng(&mut rng, 2, Some(Gender::Male));
+/// assert!(adult.height_cm > toddler.height_cm);
+/// assert!(adult.bmi() > 10.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Physique {
+    pub height_cm: u16,
+    pub weight_kg: u16,
+    pub blood_type: BloodType,
+    pub eye_color: EyeColor,
+    pub hair_color: HairColor,
+}
+impl Physique {
+    /// Creates a random `Physique` that is plausible for the given age and gender.
+    pub fn random(age: u32, gender: Option<Gender>) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), age, gender)
+    }
+
+    /// Same as [`Physique::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(
+        rng: &mut R,
+        age: u32,
+        gender: Option<Gender>,
+    ) -> Self {
+        let heights = match gender {
+            Some(Gender::Female) => &FEMALE_HEIGHTS_CM,
+            Some(Gender::Male) => &MALE_HEIGHTS_CM,
+            _ if rng.gen_bool(0.5) => &FEMALE_HEIGHTS_CM,
+            _ => &MALE_HEIGHTS_CM,
+        };
+        let growth_age = age.min(18) as usize;
+        // Adults lose a little height every year after 50.
+        let shrinkage = age.saturating_sub(50) as f64 * 0.15;
+        let mean_height = heights[growth_age] - shrinkage;
+        let height_cm = sample_normal(rng, mean_height, mean_height * 0.04).max(40.0);
+
+        let (mean_bmi, sd_bmi) = if age < 18 {
+            (CHILD_BMIS[growth_age], 1.5)
+        } else {
+            (22.0 + (age.min(50) - 18) as f64 * 0.15, 4.5)
+        };
+        let bmi = sample_normal(rng, mean_bmi, sd_bmi).clamp(12.0, 50.0);
+        let weight_kg = bmi * (height_cm / 100.0).powi(2);
+
+        Self {
+            height_cm: height_cm.round() as u16,
+            weight_kg: weight_kg.round().max(1.0) as u16,
+            blood_type: choose_weighted(rng, &BLOOD_TYPES),
+            eye_color: choose_weighted(rng, &EYE_COLORS),
+            hair_color: random_hair_color(rng, age),
+        }
+    }
+
+    /// Returns the body mass index in kg/m².
+    pub fn bmi(&self) -> f64 {
+        let height_m = self.height_cm as f64 / 100.0;
+        self.weight_kg as f64 / (height_m * height_m)
+    }
+}
+
+/// Approximate mean height in centimeters per age from 0 to 18 years.
+const FEMALE_HEIGHTS_CM: [f64; 19] = [
+    49.0, 74.0, 86.0, 95.0, 102.0, 109.0, 115.0, 121.0, 127.0, 133.0, 139.0, 145.0, 151.0, 156.0,
+    159.0, 161.0, 162.0, 162.0, 162.0,
+];
+const MALE_HEIGHTS_CM: [f64; 19] = [
+    50.0, 76.0, 87.0, 96.0, 103.0, 110.0, 116.0, 122.0, 128.0, 134.0, 139.0, 144.0, 150.0, 157.0,
+    164.0, 170.0, 173.0, 175.0, 176.0,
+];
+/// Approximate mean body mass index per age from 0 to 18 years.
+const CHILD_BMIS: [f64; 19] = [
+    13.5, 17.0, 16.5, 16.0, 15.7, 15.5, 15.5, 15.7, 16.0, 16.5, 17.0, 17.5, 18.0, 18.7, 19.4, 20.0,
+    20.6, 21.2, 22.0,
+];
+/// Blood type frequencies in percent in the United States.
+const BLOOD_TYPES: [(BloodType, f64); 8] = [
+    (BloodType::OPositive, 37.4),
+    (BloodType::ONegative, 6.6),
+    (BloodType::APositive, 35.7),
+    (BloodType::ANegative, 6.3),
+    (BloodType::BPositive, 8.5),
+    (BloodType::BNegative, 1.5),
+    (BloodType::AbPositive, 3.4),
+    (BloodType::AbNegative, 0.6),
+];
+/// Eye color frequencies in percent in the United States.
+const EYE_COLORS: [(EyeColor, f64); 6] = [
+    (EyeColor::Brown, 45.0),
+    (EyeColor::Blue, 27.0),
+    (EyeColor::Hazel, 17.0),
+    (EyeColor::Green, 9.0),
+    (EyeColor::Gray, 1.0),
+    (EyeColor::Amber, 1.0),
+];
+/// Natural hair color frequencies in percent, before graying.
+const HAIR_COLORS: [(HairColor, f64); 4] = [
+    (HairColor::Brown, 50.0),
+    (HairColor::Black, 27.0),
+    (HairColor::Blond, 19.0),
+    (HairColor::Red, 4.0),
+];
+
+fn choose_weighted<T: Copy, R: Rng + ?Sized>(rng: &mut R, choices: &[(T, f64)]) -> T {
+    let index = WeightedIndex::new(choices.iter().map(|(_, weight)| weight)).unwrap();
+    choices[index.sample(rng)].0
+}
+
+/// Picks a natural hair color, which becomes more likely to be gray or white from the mid-thirties on.
+fn random_hair_color<R: Rng + ?Sized>(rng: &mut R, age: u32) -> HairColor {
+    let gray_probability = (age.saturating_sub(30) as f64 * 0.02).min(0.8);
+    if rng.gen_bool(gray_probability) {
+        if age >= 70 && rng.gen_bool(0.5) {
+            HairColor::White
+        } else {
+            HairColor::Gray
+        }
+    } else {
+        choose_weighted(rng, &HAIR_COLORS)
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::Utc;
+use rand::Rng;
+
+use crate::{random_digits, GenerationConfig, Person, UsernameSeparator};
+
+/// A platform whose handles follow its own length and character rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Platform {
+    /// 4 to 15 letters, digits and underscores.
+    Twitter,
+    /// Up to 39 letters, digits and single hyphens, not at the start or end.
+    GitHub,
+    /// Up to 30 letters, digits, underscores and single dots, not at the start or end.
+    Instagram,
+    /// 3 to 20 letters, digits, underscores and hyphens.
+    Reddit,
+    /// 2 to 24 letters, digits, underscores and dots, not ending with a dot.
+    TikTok,
+    /// 4 to 25 letters, digits and underscores, not starting with an underscore.
+    Twitch,
+    /// 2 to 32 lowercase letters, digits, underscores and single dots.
+    Discord,
+    /// 5 to 32 letters, digits and underscores, starting with a letter and not ending with an underscore.
+    Telegram,
+}
+impl Platform {
+    /// All platforms.
+    pub const ALL: [Platform; 8] = [
+        Platform::Twitter,
+        Platform::GitHub,
+        Platform::Instagram,
+        Platform::Reddit,
+        Platform::TikTok,
+        Platform::Twitch,
+        Platform::Discord,
+        Platform::Telegram,
+    ];
+
+    /// Returns the minimum length of a handle.
+    pub fn min_length(self) -> usize {
+        match self {
+            Self::GitHub | Self::Instagram => 1,
+            Self::TikTok | Self::Discord => 2,
+            Self::Reddit => 3,
+            Self::Twitter | Self::Twitch => 4,
+            Self::Telegram => 5,
+        }
+    }
+
+    /// Returns the maximum length of a handle.
+    pub fn max_length(self) -> usize {
+        match self {
+            Self::Twitter => 15,
+            Self::Reddit => 20,
+            Self::TikTok => 24,
+            Self::Twitch => 25,
+            Self::Instagram => 30,
+            Self::Discord | Self::Telegram => 32,
+            Self::GitHub => 39,
+        }
+    }
+
+    /// Returns the characters besides ASCII letters and digits a handle may contain.
+    pub fn separators(self) -> &'static [char] {
+        match self {
+            Self::Twitter | Self::Twitch | Self::Telegram => &['_'],
+            Self::GitHub => &['-'],
+            Self::Instagram | Self::TikTok | Self::Discord => &['_', '.'],
+            Self::Reddit => &['_', '-'],
+        }
+    }
+
+    /// Returns whether the handle follows the platform's rules.
+    /// ## Example
+    /// ```rust
+    /// use person::Platform;
+    /// assert!(Platform::GitHub.is_valid("jane-doe"));
+    /// assert!(!Platform::GitHub.is_valid("-jane"));
+    /// assert!(!Platform::Twitter.is_valid("jane.doe"));
+    /// ```
+    pub fn is_valid(self, handle: &str) -> bool {
+        let length = handle.chars().count();
+        if length < self.min_length() || length > self.max_length() {
+            return false;
+        }
+        if !handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || self.separators().contains(&c))
+        {
+            return false;
+        }
+        let first = handle.chars().next().unwrap();
+        let last = handle.chars().last().unwrap();
+        match self {
+            Self::GitHub => first != '-' && last != '-' && !handle.contains("--"),
+            Self::Instagram => first != '.' && last != '.' && !handle.contains(".."),
+            Self::TikTok => last != '.',
+            Self::Twitch => first != '_',
+            Self::Discord => !handle.contains("..") && !handle.contains(char::is_uppercase),
+            Self::Telegram => first.is_ascii_alphabetic() && last != '_',
+            Self::Twitter | Self::Reddit => true,
+        }
+    }
+
+    /// Turns a username into a valid handle by dropping forbidden characters,
+    /// shortening it and padding it with random digits.
+    fn fit<R: Rng + ?Sized>(self, rng: &mut R, username: &str) -> String {
+        let username = match self {
+            Self::Discord => username.to_ascii_lowercase(),
+            // Telegram handles have to start with a letter.
+            Self::Telegram => match username.trim_start_matches(|c: char| !c.is_ascii_alphabetic())
+            {
+                "" => "user".to_string(),
+                username => username.to_string(),
+            },
+            _ => username.to_string(),
+        };
+        let mut handle = String::new();
+        for c in username.chars() {
+            let is_separator = self.separators().contains(&c);
+            if !c.is_ascii_alphanumeric() && !is_separator {
+                continue;
+            }
+            // Separators are never leading or doubled, trailing ones are trimmed below.
+            if is_separator
+                && handle
+                    .chars()
+                    .last()
+                    .is_none_or(|last| !last.is_ascii_alphanumeric())
+            {
+                continue;
+            }
+            handle.push(c);
+        }
+        handle.truncate(self.max_length());
+        while handle.ends_with(|c: char| !c.is_ascii_alphanumeric()) {
+            handle.pop();
+        }
+        let missing = self.min_length().saturating_sub(handle.len());
+        handle.push_str(&random_digits(rng, missing));
+        handle
+    }
+}
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Twitter => "Twitter",
+            Self::GitHub => "GitHub",
+            Self::Instagram => "Instagram",
+            Self::Reddit => "Reddit",
+            Self::TikTok => "TikTok",
+            Self::Twitch => "Twitch",
+            Self::Discord => "Discord",
+            Self::Telegram => "Telegram",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Person {
+    /// Generates a random handle that follows the rules of the given platform.
+    /// ## Example
+    /// ```rust
+    /// use person::{Person, Platform};
+    /// let person = Person::random();
+    /// for platform in Platform::ALL {
+    ///     assert!(platform.is_valid(&person.get_handle_for(platform)));
+    /// }
+    /// ```
+    pub fn get_handle_for(&self, platform: Platform) -> String {
+        self.get_handle_for_custom_rng(&mut rand::thread_rng(), platform)
+    }
+
+    /// Same as [`Person::get_handle_for`], but uses the given random number generator.
+    pub fn get_handle_for_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        platform: Platform,
+    ) -> String {
+        let separators = [
+            UsernameSeparator::None,
+            UsernameSeparator::Hyphen,
+            UsernameSeparator::Underscore,
+            UsernameSeparator::Dot,
+            UsernameSeparator::MiddleInitial,
+        ];
+        let config = GenerationConfig {
+            separators: separators
+                .into_iter()
+                .filter(|separator| match separator {
+                    UsernameSeparator::Hyphen => platform.separators().contains(&'-'),
+                    UsernameSeparator::Underscore => platform.separators().contains(&'_'),
+                    UsernameSeparator::Dot => platform.separators().contains(&'.'),
+                    UsernameSeparator::None | UsernameSeparator::MiddleInitial => true,
+                })
+                .collect(),
+            ..GenerationConfig::default()
+        };
+        let username = self.random_username_at(rng, &config, Utc::now());
+        platform.fit(rng, &username)
+    }
+}
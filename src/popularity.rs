@@ -0,0 +1,283 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Gender;
+
+/// The most popular female and male first names of a decade.
+struct DecadeNames {
+    decade: i32,
+    female: &'static [&'static str],
+    male: &'static [&'static str],
+}
+
+/// The most popular baby names in the United States per decade, oldest first.
+static DECADES: [DecadeNames; 13] = [
+    DecadeNames {
+        decade: 1900,
+        female: &[
+            "Mary",
+            "Helen",
+            "Margaret",
+            "Anna",
+            "Ruth",
+            "Elizabeth",
+            "Dorothy",
+            "Marie",
+            "Florence",
+            "Mildred",
+        ],
+        male: &[
+            "John", "William", "James", "George", "Charles", "Robert", "Joseph", "Frank", "Edward",
+            "Thomas",
+        ],
+    },
+    DecadeNames {
+        decade: 1910,
+        female: &[
+            "Mary",
+            "Helen",
+            "Dorothy",
+            "Margaret",
+            "Ruth",
+            "Mildred",
+            "Anna",
+            "Elizabeth",
+            "Frances",
+            "Gertrude",
+        ],
+        male: &[
+            "John", "William", "James", "Robert", "Joseph", "George", "Charles", "Edward", "Frank",
+            "Walter",
+        ],
+    },
+    DecadeNames {
+        decade: 1920,
+        female: &[
+            "Mary", "Dorothy", "Helen", "Betty", "Margaret", "Ruth", "Virginia", "Doris",
+            "Mildred", "Gertrude",
+        ],
+        male: &[
+            "Robert", "John", "James", "William", "Charles", "George", "Joseph", "Richard",
+            "Edward", "Donald",
+        ],
+    },
+    DecadeNames {
+        decade: 1930,
+        female: &[
+            "Mary", "Betty", "Barbara", "Shirley", "Patricia", "Dorothy", "Joan", "Margaret",
+            "Nancy", "Gertrude",
+        ],
+        male: &[
+            "Robert", "James", "John", "William", "Richard", "Charles", "Donald", "George",
+            "Thomas", "Joseph",
+        ],
+    },
+    DecadeNames {
+        decade: 1940,
+        female: &[
+            "Mary", "Linda", "Barbara", "Patricia", "Carol", "Sandra", "Nancy", "Sharon", "Judith",
+            "Susan",
+        ],
+        male: &[
+            "James", "Robert", "John", "William", "Richard", "David", "Charles", "Thomas",
+            "Michael", "Ronald",
+        ],
+    },
+    DecadeNames {
+        decade: 1950,
+        female: &[
+            "Mary", "Linda", "Patricia", "Susan", "Deborah", "Barbara", "Debra", "Karen", "Nancy",
+            "Donna",
+        ],
+        male: &[
+            "James", "Michael", "Robert", "John", "David", "William", "Richard", "Thomas", "Mark",
+            "Charles",
+        ],
+    },
+    DecadeNames {
+        decade: 1960,
+        female: &[
+            "Lisa", "Mary", "Susan", "Karen", "Kimberly", "Patricia", "Linda", "Donna", "Michelle",
+            "Cynthia",
+        ],
+        male: &[
+            "Michael", "David", "John", "James", "Robert", "Mark", "William", "Richard", "Thomas",
+            "Jeffrey",
+        ],
+    },
+    DecadeNames {
+        decade: 1970,
+        female: &[
+            "Jennifer",
+            "Amy",
+            "Melissa",
+            "Michelle",
+            "Kimberly",
+            "Lisa",
+            "Angela",
+            "Heather",
+            "Stephanie",
+            "Nicole",
+        ],
+        male: &[
+            "Michael",
+            "Christopher",
+            "Jason",
+            "David",
+            "James",
+            "John",
+            "Robert",
+            "Brian",
+            "William",
+            "Matthew",
+        ],
+    },
+    DecadeNames {
+        decade: 1980,
+        female: &[
+            "Jessica",
+            "Jennifer",
+            "Amanda",
+            "Ashley",
+            "Sarah",
+            "Stephanie",
+            "Melissa",
+            "Nicole",
+            "Elizabeth",
+            "Heather",
+        ],
+        male: &[
+            "Michael",
+            "Christopher",
+            "Matthew",
+            "Joshua",
+            "David",
+            "James",
+            "Daniel",
+            "Robert",
+            "John",
+            "Joseph",
+        ],
+    },
+    DecadeNames {
+        decade: 1990,
+        female: &[
+            "Jessica",
+            "Ashley",
+            "Emily",
+            "Sarah",
+            "Samantha",
+            "Amanda",
+            "Brittany",
+            "Elizabeth",
+            "Taylor",
+            "Megan",
+        ],
+        male: &[
+            "Michael",
+            "Christopher",
+            "Matthew",
+            "Joshua",
+            "Jacob",
+            "Nicholas",
+            "Andrew",
+            "Daniel",
+            "Tyler",
+            "Joseph",
+        ],
+    },
+    DecadeNames {
+        decade: 2000,
+        female: &[
+            "Emily",
+            "Madison",
+            "Emma",
+            "Olivia",
+            "Hannah",
+            "Abigail",
+            "Isabella",
+            "Samantha",
+            "Elizabeth",
+            "Ashley",
+        ],
+        male: &[
+            "Jacob",
+            "Michael",
+            "Joshua",
+            "Matthew",
+            "Daniel",
+            "Christopher",
+            "Andrew",
+            "Ethan",
+            "Joseph",
+            "William",
+        ],
+    },
+    DecadeNames {
+        decade: 2010,
+        female: &[
+            "Emma",
+            "Olivia",
+            "Sophia",
+            "Isabella",
+            "Ava",
+            "Mia",
+            "Abigail",
+            "Emily",
+            "Charlotte",
+            "Madison",
+        ],
+        male: &[
+            "Noah",
+            "Liam",
+            "Jacob",
+            "William",
+            "Mason",
+            "Ethan",
+            "Michael",
+            "Alexander",
+            "James",
+            "Elijah",
+        ],
+    },
+    DecadeNames {
+        decade: 2020,
+        female: &[
+            "Olivia",
+            "Emma",
+            "Charlotte",
+            "Amelia",
+            "Ava",
+            "Sophia",
+            "Isabella",
+            "Mia",
+            "Evelyn",
+            "Harper",
+        ],
+        male: &[
+            "Liam", "Noah", "Oliver", "James", "Elijah", "William", "Henry", "Lucas", "Benjamin",
+            "Theodore",
+        ],
+    },
+];
+
+/// Picks a first name that was popular in the decade of the given birth year.
+/// Years outside of the covered decades use the closest one, and `Person`s without a gender
+/// pick from the female and male names alike.
+pub(crate) fn choose_first_name<R: Rng + ?Sized>(
+    rng: &mut R,
+    birth_year: i32,
+    gender: Option<Gender>,
+) -> &'static str {
+    let decade = DECADES
+        .iter()
+        .rev()
+        .find(|names| names.decade <= birth_year)
+        .unwrap_or(&DECADES[0]);
+    let names = match gender {
+        Some(Gender::Female) => decade.female,
+        Some(Gender::Male) => decade.male,
+        _ if rng.gen_bool(0.5) => decade.female,
+        _ => decade.male,
+    };
+    names.choose(rng).unwrap()
+}
@@ -0,0 +1,80 @@
+use rand::Rng;
+
+use crate::{address, Country};
+
+/// The letters British postcodes never use in the last two characters.
+const GB_EXCLUDED_INWARD_LETTERS: &str = "CIKMOV";
+/// The letters Canadian postal codes never use.
+const CA_EXCLUDED_LETTERS: &str = "DFIOQU";
+
+/// Returns whether the postal code has the format of the country, like "12345" or "12345-6789" for
+/// a United States ZIP code, "SW1A 1AA" for the United Kingdom or "K1A 0B1" for Canada.
+/// Only the format is checked, not whether the postal code is in use.
+/// ## Example
+/// ```rust
+/// use person::{is_valid_postal_code, Country};
+/// assert!(is_valid_postal_code(Country::UnitedStates, "02108-1234"));
+/// assert!(is_valid_postal_code(Country::UnitedKingdom, "EC1A 1BB"));
+/// assert!(is_valid_postal_code(Country::Canada, "K1A 0B1"));
+/// assert!(!is_valid_postal_code(Country::Canada, "D1A 0B1"));
+/// assert!(!is_valid_postal_code(Country::Netherlands, "1012 SS"));
+/// ```
+pub fn is_valid_postal_code(country: Country, code: &str) -> bool {
+    match country {
+        Country::UnitedStates => {
+            matches_pattern(code, "99999") || matches_pattern(code, "99999-9999")
+        }
+        Country::Canada => {
+            matches_pattern(code, "A9A 9A9")
+                && !code.contains(|c| CA_EXCLUDED_LETTERS.contains(c))
+                && !code.starts_with(['W', 'Z'])
+        }
+        Country::UnitedKingdom => is_valid_gb_postcode(code),
+        Country::Germany | Country::France | Country::Italy => matches_pattern(code, "99999"),
+        Country::Spain => {
+            matches_pattern(code, "99999") && (1..=52).contains(&code[..2].parse::<u32>().unwrap())
+        }
+        Country::Netherlands => {
+            matches_pattern(code, "9999 AA")
+                && !code.starts_with('0')
+                && !matches!(&code[5..], "SA" | "SD" | "SS")
+        }
+        Country::Australia => matches_pattern(code, "9999"),
+        Country::Japan => matches_pattern(code, "999-9999"),
+    }
+}
+
+/// Generates a random postal code of the country, which always passes [`is_valid_postal_code`].
+pub fn random_postal_code(country: Country) -> String {
+    random_postal_code_custom_rng(&mut rand::thread_rng(), country)
+}
+
+/// Same as [`random_postal_code`], but uses the given random number generator.
+pub fn random_postal_code_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> String {
+    address::random_postal_code(rng, country)
+}
+
+/// Returns whether the code matches the pattern, where 'A' stands for an uppercase ASCII letter,
+/// '9' for a digit and every other character for itself.
+fn matches_pattern(code: &str, pattern: &str) -> bool {
+    code.len() == pattern.len()
+        && code.chars().zip(pattern.chars()).all(|(c, p)| match p {
+            'A' => c.is_ascii_uppercase(),
+            '9' => c.is_ascii_digit(),
+            p => c == p,
+        })
+}
+
+/// Checks a British postcode: an outward code like "M1", "B33", "CR2", "DN55", "W1A" or "EC1A",
+/// a space and an inward code like "1AA".
+fn is_valid_gb_postcode(code: &str) -> bool {
+    let Some((outward, inward)) = code.split_once(' ') else {
+        return false;
+    };
+    let outward_patterns = ["A9", "A99", "AA9", "AA99", "A9A", "AA9A"];
+    outward_patterns
+        .iter()
+        .any(|pattern| matches_pattern(outward, pattern))
+        && matches_pattern(inward, "9AA")
+        && !inward[1..].contains(|c| GB_EXCLUDED_INWARD_LETTERS.contains(c))
+}
@@ -0,0 +1,57 @@
+use crate::Gender;
+
+/// A set of personal pronouns, e.g. she/her or they/them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pronouns {
+    /// E.g. "she" in "she is here".
+    pub subject: &'static str,
+    /// E.g. "her" in "I met her".
+    pub object: &'static str,
+    /// E.g. "her" in "her book".
+    pub possessive_determiner: &'static str,
+    /// E.g. "hers" in "the book is hers".
+    pub possessive: &'static str,
+    /// E.g. "herself" in "she did it herself".
+    pub reflexive: &'static str,
+}
+impl Pronouns {
+    pub const SHE: Pronouns = Pronouns::new("she", "her", "her", "hers", "herself");
+    pub const HE: Pronouns = Pronouns::new("he", "him", "his", "his", "himself");
+    pub const THEY: Pronouns = Pronouns::new("they", "them", "their", "theirs", "themself");
+    pub const XE: Pronouns = Pronouns::new("xe", "xem", "xyr", "xyrs", "xemself");
+    pub const ZE: Pronouns = Pronouns::new("ze", "hir", "hir", "hirs", "hirself");
+
+    /// All built-in pronoun sets.
+    pub const ALL: [Pronouns; 5] = [Self::SHE, Self::HE, Self::THEY, Self::XE, Self::ZE];
+
+    pub const fn new(
+        subject: &'static str,
+        object: &'static str,
+        possessive_determiner: &'static str,
+        possessive: &'static str,
+        reflexive: &'static str,
+    ) -> Self {
+        Self {
+            subject,
+            object,
+            possessive_determiner,
+            possessive,
+            reflexive,
+        }
+    }
+
+    /// Returns the pronouns commonly associated with the given gender, they/them if there is none.
+    pub fn for_gender(gender: Option<Gender>) -> Self {
+        match gender {
+            Some(Gender::Female) => Self::SHE,
+            Some(Gender::Male) => Self::HE,
+            Some(Gender::NonBinary) | None => Self::THEY,
+        }
+    }
+}
+/// Formats the pronouns like "she/her".
+impl std::fmt::Display for Pronouns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.subject, self.object)
+    }
+}
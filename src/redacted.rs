@@ -0,0 +1,53 @@
+use chrono::Datelike;
+
+use crate::Person;
+
+/// A view of a `Person` that masks their names and date of birth when displayed or debug-printed,
+/// created with [`Person::redacted`].
+/// ## Example
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use person::Person;
+/// let dob = Utc.with_ymd_and_hms(1985, 4, 12, 0, 0, 0).unwrap();
+/// let person = Person::from_parts("Jane", Some("Quinn"), "Doe", dob);
+/// assert_eq!(person.redacted().to_string(), "J*** Q**** D**, 1985-**-**");
+/// assert!(!format!("{:?}", person.redacted()).contains("Jane"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Redacted<'a>(pub(crate) &'a Person);
+impl Redacted<'_> {
+    fn masked_name(&self) -> String {
+        self.0
+            .get_full_name()
+            .split(' ')
+            .map(mask)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+impl std::fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {}-**-**",
+            self.masked_name(),
+            self.0.get_date_of_birth().year()
+        )
+    }
+}
+impl std::fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Person")
+            .field("name", &self.masked_name())
+            .field("birth_year", &self.0.get_date_of_birth().year())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Keeps the first character of the word and replaces every other letter or digit with `*`.
+fn mask(word: &str) -> String {
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| if i > 0 && c.is_alphanumeric() { '*' } else { c })
+        .collect()
+}
@@ -0,0 +1,120 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+use crate::{Country, Origin};
+
+/// The religion or belief of a `Person`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Religion {
+    Catholic,
+    Protestant,
+    Orthodox,
+    Muslim,
+    Jewish,
+    Hindu,
+    Buddhist,
+    Sikh,
+    Shinto,
+    /// Atheists, agnostics and `Person`s without a particular religion.
+    Unaffiliated,
+}
+impl Religion {
+    /// All religions and beliefs.
+    pub const ALL: [Religion; 10] = [
+        Religion::Catholic,
+        Religion::Protestant,
+        Religion::Orthodox,
+        Religion::Muslim,
+        Religion::Jewish,
+        Religion::Hindu,
+        Religion::Buddhist,
+        Religion::Sikh,
+        Religion::Shinto,
+        Religion::Unaffiliated,
+    ];
+
+    /// Returns whether the religion is a branch of Christianity.
+    pub fn is_christian(self) -> bool {
+        matches!(self, Self::Catholic | Self::Protestant | Self::Orthodox)
+    }
+
+    /// Returns the approximate share of the population of the country with the religion, in
+    /// percent, based on recent censuses and surveys.
+    pub fn share(self, country: Country) -> f64 {
+        // Catholic, Protestant, Orthodox, Muslim, Jewish, Hindu, Buddhist, Sikh, Shinto and
+        // unaffiliated.
+        let shares = match country {
+            Country::UnitedStates => [21.0, 40.0, 0.5, 1.0, 2.0, 1.0, 1.0, 0.2, 0.0, 29.0],
+            Country::Canada => [29.0, 19.0, 1.6, 5.0, 1.0, 2.3, 1.0, 2.1, 0.0, 35.0],
+            Country::UnitedKingdom => [8.0, 37.0, 0.5, 6.5, 0.5, 1.7, 0.5, 0.9, 0.0, 37.0],
+            Country::Germany => [26.0, 23.0, 2.0, 6.5, 0.2, 0.1, 0.3, 0.0, 0.0, 42.0],
+            Country::France => [47.0, 3.0, 0.5, 8.0, 0.8, 0.1, 0.5, 0.0, 0.0, 40.0],
+            Country::Spain => [56.0, 1.0, 1.0, 3.0, 0.1, 0.0, 0.1, 0.0, 0.0, 39.0],
+            Country::Italy => [72.0, 1.0, 2.0, 3.0, 0.1, 0.1, 0.2, 0.1, 0.0, 22.0],
+            Country::Netherlands => [18.0, 14.0, 0.3, 5.0, 0.1, 0.6, 0.4, 0.0, 0.0, 58.0],
+            Country::Australia => [20.0, 19.0, 2.0, 3.2, 0.4, 2.7, 2.4, 0.8, 0.0, 39.0],
+            Country::Japan => [0.5, 1.0, 0.0, 0.1, 0.0, 0.0, 36.0, 0.0, 4.0, 57.0],
+        };
+        shares[self as usize]
+    }
+
+    /// Picks a religion or belief according to its share in the country.
+    /// ## Example
+    /// ```rust
+    /// use person::{Country, Religion};
+    /// let religion = Religion::random_in(Country::Japan);
+    /// assert!(!matches!(religion, Religion::Jewish | Religion::Hindu | Religion::Sikh));
+    /// ```
+    pub fn random_in(country: Country) -> Self {
+        Self::random_in_custom_rng(&mut rand::thread_rng(), country)
+    }
+
+    /// Same as [`Religion::random_in`], but uses the given random number generator.
+    pub fn random_in_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Self {
+        Self::random_from_shares(rng, Self::ALL.map(|religion| religion.share(country)))
+    }
+
+    /// Picks a religion or belief according to the shares, in the order of [`Religion::ALL`].
+    fn random_from_shares<R: Rng + ?Sized>(rng: &mut R, shares: [f64; 10]) -> Self {
+        let weights = WeightedIndex::new(shares).unwrap();
+        Self::ALL[weights.sample(rng)]
+    }
+}
+impl std::fmt::Display for Religion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Catholic => "Catholic",
+            Self::Protestant => "Protestant",
+            Self::Orthodox => "Orthodox Christian",
+            Self::Muslim => "Muslim",
+            Self::Jewish => "Jewish",
+            Self::Hindu => "Hindu",
+            Self::Buddhist => "Buddhist",
+            Self::Sikh => "Sikh",
+            Self::Shinto => "Shinto",
+            Self::Unaffiliated => "unaffiliated",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Generates the religion of a `Person` living in the country. `Person`s whose origin belongs
+/// to none of the supported countries, like Indian, follow the shares of their origin instead.
+pub(crate) fn random_religion<R: Rng + ?Sized>(
+    rng: &mut R,
+    country: Country,
+    origin: Option<Origin>,
+) -> Religion {
+    // Catholic, Protestant, Orthodox, Muslim, Jewish, Hindu, Buddhist, Sikh, Shinto and
+    // unaffiliated.
+    let shares = match origin {
+        Some(Origin::Polish) => [85.0, 0.5, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 13.5],
+        Some(Origin::Russian) => [0.5, 1.0, 70.0, 7.0, 0.5, 0.0, 0.5, 0.0, 0.0, 20.5],
+        Some(Origin::Chinese) => [2.0, 5.0, 0.0, 2.0, 0.0, 0.0, 18.0, 0.0, 0.0, 73.0],
+        Some(Origin::Korean) => [8.0, 20.0, 0.0, 0.0, 0.0, 0.0, 16.0, 0.0, 0.0, 56.0],
+        Some(Origin::Indian) => [1.0, 1.5, 0.0, 14.0, 0.0, 80.0, 0.7, 1.7, 0.0, 1.1],
+        Some(Origin::Arabic) => [2.0, 1.0, 3.0, 92.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0],
+        Some(Origin::Nigerian) => [13.0, 33.0, 0.0, 50.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0],
+        _ => return Religion::random_in_custom_rng(rng, country),
+    };
+    Religion::random_from_shares(rng, shares)
+}
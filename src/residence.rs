@@ -0,0 +1,74 @@
+use chrono::{DateTime, Duration, Months, Utc};
+use rand::Rng;
+
+use crate::{Address, Jurisdiction, Person};
+
+/// The longest a `Person` stays at one address in a generated address history, in years.
+const MAX_YEARS_PER_RESIDENCE: i64 = 8;
+
+/// An address a `Person` lived at, with the time they lived there.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Residence {
+    pub address: Address,
+    pub moved_in: DateTime<Utc>,
+    /// `None` if the `Person` still lives there.
+    pub moved_out: Option<DateTime<Utc>>,
+}
+
+impl Person {
+    /// Generates the addresses the `Person` lived at since becoming an adult, most recent first,
+    /// for testing forms that ask for previous residences. The first residence is the `Person`'s
+    /// address if they have one, and every residence is in the same country, which is the one of
+    /// their nationality or phone number if they have no address. Minors only have a single
+    /// residence since birth, and the last residence of a deceased `Person` ends at their date of death.
+    /// ## Example
+    /// ```rust
+    /// use person::Person;
+    /// let person = Person::random();
+    /// let history = person.get_address_history();
+    /// assert_eq!(history[0].moved_out.is_none(), person.is_alive());
+    /// assert!(history.windows(2).all(|w| w[1].moved_out == Some(w[0].moved_in)));
+    /// assert!(history.last().unwrap().moved_in >= person.get_date_of_birth());
+    /// ```
+    pub fn get_address_history(&self) -> Vec<Residence> {
+        self.get_address_history_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_address_history`], but uses the given random number generator.
+    pub fn get_address_history_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Residence> {
+        let end = self.date_of_death.unwrap_or_else(Utc::now);
+        let adulthood = self
+            .date_of_birth
+            .checked_add_months(Months::new(12 * Jurisdiction::Common.age_of_majority()))
+            .filter(|&adulthood| adulthood < end);
+        let start = adulthood.unwrap_or(self.date_of_birth);
+        let country = self.financial_country();
+        let mut address = self
+            .address
+            .clone()
+            .unwrap_or_else(|| Address::random_custom_rng(rng, country));
+
+        let mut history = vec![];
+        let mut moved_out = self.date_of_death;
+        let mut until = end;
+        loop {
+            let stay = Duration::days(rng.gen_range(365..=365 * MAX_YEARS_PER_RESIDENCE));
+            let moved_in = if adulthood.is_none() || until - start <= stay {
+                start
+            } else {
+                until - stay
+            };
+            history.push(Residence {
+                address,
+                moved_in,
+                moved_out,
+            });
+            if moved_in == start {
+                break history;
+            }
+            address = Address::random_custom_rng(rng, country);
+            moved_out = Some(moved_in);
+            until = moved_in;
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use rand::Rng;
+
+use crate::{sample_normal, Country, Person};
+
+/// The age at which careers start, for the years of experience.
+const CAREER_START_AGE: u32 = 22;
+/// The youngest age at which a `Person` earns a salary.
+pub(crate) const MIN_WORKING_AGE: u32 = 16;
+
+/// A range of annual salaries in US dollars, from entry level to the most senior.
+/// Ranges whose minimum is above their maximum are treated as if the bounds were swapped.
+/// ## Example
+/// ```rust
+/// use person::{Person, SalaryRange};
+/// let range = SalaryRange { min: 120_000, max: 40_000 };
+/// let salary = Person::random_adult().get_random_salary_in(range).unwrap();
+/// assert!((40_000..=120_000).contains(&salary.annual));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SalaryRange {
+    pub min: u32,
+    pub max: u32,
+}
+impl SalaryRange {
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+        }
+    }
+}
+
+/// An annual salary in the currency of a country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Salary {
+    pub annual: u32,
+    pub country: Country,
+}
+impl Salary {
+    /// Returns the ISO 4217 code of the currency, like "USD".
+    pub fn currency(&self) -> &'static str {
+        self.country.currency()
+    }
+
+    /// Returns the salary per month, rounded down.
+    pub fn monthly(&self) -> u32 {
+        self.annual / 12
+    }
+}
+impl std::fmt::Display for Salary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.annual, self.currency())
+    }
+}
+
+impl Person {
+    /// Generates an annual salary within the range, where more years of experience since the age of 22
+    /// move it toward the maximum. It is converted to the currency the `Person` is paid in: the one of
+    /// their address, or else of their nationality, or else of their phone number, falling back to
+    /// US dollars, and rounded to a multiple of 500, or of 100,000 for yen. Returns `None` if the `Person`
+    /// is younger than 16.
+    /// ## Example
+    /// ```rust
+    /// use person::{Person, SalaryRange};
+    /// let person = Person::random_adult();
+    /// let salary = person.get_random_salary_in(SalaryRange::new(40_000, 120_000)).unwrap();
+    /// assert!((40_000..=120_000).contains(&salary.annual));
+    /// assert_eq!(salary.currency(), "USD");
+    /// ```
+    pub fn get_random_salary_in(&self, range: SalaryRange) -> Option<Salary> {
+        self.get_random_salary_in_custom_rng(&mut rand::thread_rng(), range)
+    }
+
+    /// Same as [`Person::get_random_salary_in`], but uses the given random number generator.
+    pub fn get_random_salary_in_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        range: SalaryRange,
+    ) -> Option<Salary> {
+        let age = self.get_age();
+        if age < MIN_WORKING_AGE {
+            return None;
+        }
+        // Salaries rise quickly early in a career and level off after about two decades.
+        let experience = age.saturating_sub(CAREER_START_AGE) as f64;
+        let seniority = 1.0 - (-experience / 12.0).exp();
+        let position = sample_normal(rng, seniority, 0.12).clamp(0.0, 1.0);
+        // The fields are public, so the bounds may be swapped.
+        let SalaryRange { min, max } = SalaryRange::new(range.min, range.max);
+        let usd = min as f64 + (max - min) as f64 * position;
+        let country = self.financial_country();
+        let local = usd * country.usd_exchange_rate();
+        // Salaries are negotiated in round numbers.
+        let step = if country == Country::Japan {
+            100_000.0
+        } else {
+            500.0
+        };
+        Some(Salary {
+            annual: ((local / step).round() * step) as u32,
+            country,
+        })
+    }
+}
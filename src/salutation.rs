@@ -0,0 +1,14 @@
+/// How formal the greeting returned by [`Person::get_salutation`](crate::Person::get_salutation) is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SalutationStyle {
+    /// "Dear Ms. Doe,", using the title or a title matching the gender.
+    /// Falls back to the full name if neither is known.
+    #[default]
+    Formal,
+    /// "Dear Jane,".
+    Personal,
+    /// "Hi Jane,".
+    Informal,
+    /// "Hey Will!", using the nickname.
+    Casual,
+}
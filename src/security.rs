@@ -0,0 +1,216 @@
+use chrono::{Datelike, Utc};
+use rand::{seq::SliceRandom, SeedableRng};
+
+use crate::{list, DegreeLevel, Person, SeededRng};
+
+/// A classic security question used for account recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SecurityQuestion {
+    MothersMaidenName,
+    FirstPet,
+    CityOfBirth,
+    ChildhoodStreet,
+    FirstCar,
+    FavoriteTeacher,
+    HighSchoolGraduationYear,
+}
+impl SecurityQuestion {
+    /// All questions.
+    pub const ALL: [SecurityQuestion; 7] = [
+        SecurityQuestion::MothersMaidenName,
+        SecurityQuestion::FirstPet,
+        SecurityQuestion::CityOfBirth,
+        SecurityQuestion::ChildhoodStreet,
+        SecurityQuestion::FirstCar,
+        SecurityQuestion::FavoriteTeacher,
+        SecurityQuestion::HighSchoolGraduationYear,
+    ];
+
+    /// Returns the question as it is shown to a user.
+    pub fn text(self) -> &'static str {
+        match self {
+            Self::MothersMaidenName => "What is your mother's maiden name?",
+            Self::FirstPet => "What was the name of your first pet?",
+            Self::CityOfBirth => "In what city were you born?",
+            Self::ChildhoodStreet => "What street did you grow up on?",
+            Self::FirstCar => "What was the make and model of your first car?",
+            Self::FavoriteTeacher => "What was the last name of your favorite teacher?",
+            Self::HighSchoolGraduationYear => "In what year did you graduate from high school?",
+        }
+    }
+}
+impl std::fmt::Display for SecurityQuestion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+const PET_NAMES: [&str; 20] = [
+    "Max", "Bella", "Charlie", "Luna", "Buddy", "Daisy", "Rocky", "Molly", "Bailey", "Coco",
+    "Duke", "Lucy", "Tiger", "Sadie", "Oscar", "Milo", "Ginger", "Shadow", "Pepper", "Rex",
+];
+const CITIES: [&str; 20] = [
+    "New York",
+    "Los Angeles",
+    "Chicago",
+    "Houston",
+    "Phoenix",
+    "Philadelphia",
+    "San Antonio",
+    "San Diego",
+    "Dallas",
+    "San Jose",
+    "Austin",
+    "Columbus",
+    "Denver",
+    "Seattle",
+    "Boston",
+    "Detroit",
+    "Portland",
+    "Atlanta",
+    "Miami",
+    "Minneapolis",
+];
+const STREETS: [&str; 16] = [
+    "Maple Street",
+    "Oak Avenue",
+    "Elm Street",
+    "Pine Road",
+    "Cedar Lane",
+    "Main Street",
+    "Washington Avenue",
+    "Lincoln Drive",
+    "Park Place",
+    "Lake Street",
+    "Hillcrest Road",
+    "Sunset Boulevard",
+    "Church Street",
+    "Willow Way",
+    "Highland Avenue",
+    "Mill Road",
+];
+const CARS: [&str; 16] = [
+    "Honda Civic",
+    "Toyota Corolla",
+    "Ford Focus",
+    "Chevrolet Cavalier",
+    "Volkswagen Golf",
+    "Nissan Sentra",
+    "Toyota Camry",
+    "Honda Accord",
+    "Ford Mustang",
+    "Jeep Wrangler",
+    "Subaru Impreza",
+    "Mazda 3",
+    "Dodge Neon",
+    "Hyundai Elantra",
+    "Ford Escort",
+    "Volvo 240",
+];
+
+impl Person {
+    /// Returns the answer to a security question. The answer is derived from the `Person`'s identity,
+    /// so the same `Person` always gives the same answer, and fits their other attributes:
+    /// the mother's maiden name differs from the last name, the city of birth is the one of the
+    /// place of birth if it is known and the graduation year is the one of their high school
+    /// [education](Person::get_education), or else follows the date of birth. `Person`s who haven't
+    /// graduated from high school yet answer "N/A".
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::{DegreeLevel, Education, Person, SecurityQuestion};
+    /// let dob = Utc.with_ymd_and_hms(1985, 4, 12, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("Jane", None, "Doe", dob);
+    /// let answer = person.get_security_answer(SecurityQuestion::FirstPet);
+    /// assert_eq!(answer, person.get_security_answer(SecurityQuestion::FirstPet));
+    /// assert_eq!(person.get_security_answer(SecurityQuestion::HighSchoolGraduationYear), "2003");
+    /// assert_ne!(person.get_security_answer(SecurityQuestion::MothersMaidenName), "Doe");
+    /// let newborn = Person::from_parts("Jane", None, "Doe", Utc::now());
+    /// assert_eq!(newborn.get_security_answer(SecurityQuestion::HighSchoolGraduationYear), "N/A");
+    ///
+    /// let mut person = person;
+    /// person.set_education(vec![Education {
+    ///     school: "Lincoln High School".to_string(),
+    ///     level: DegreeLevel::HighSchool,
+    ///     field: None,
+    ///     graduation_year: 2004,
+    /// }]);
+    /// assert_eq!(person.get_security_answer(SecurityQuestion::HighSchoolGraduationYear), "2004");
+    /// ```
+    pub fn get_security_answer(&self, question: SecurityQuestion) -> String {
+        let mut rng = self.identity_rng(question as u64);
+        match question {
+            SecurityQuestion::MothersMaidenName => {
+                let surnames = self
+                    .origin
+                    .map_or(&list::SURNAMES[..], |origin| origin.surnames());
+                loop {
+                    let surname = *surnames.choose(&mut rng).unwrap();
+                    if surname != self.last_name || surnames.len() == 1 {
+                        break surname.to_string();
+                    }
+                }
+            }
+            SecurityQuestion::FirstPet => PET_NAMES.choose(&mut rng).unwrap().to_string(),
+            SecurityQuestion::CityOfBirth => match &self.place_of_birth {
+                Some(place_of_birth) => place_of_birth.city.clone(),
+                None => CITIES.choose(&mut rng).unwrap().to_string(),
+            },
+            SecurityQuestion::ChildhoodStreet => STREETS.choose(&mut rng).unwrap().to_string(),
+            SecurityQuestion::FirstCar => CARS.choose(&mut rng).unwrap().to_string(),
+            SecurityQuestion::FavoriteTeacher => {
+                list::SURNAMES.choose(&mut rng).unwrap().to_string()
+            }
+            SecurityQuestion::HighSchoolGraduationYear => {
+                let graduation = self
+                    .education
+                    .iter()
+                    .find(|education| education.level == DegreeLevel::HighSchool);
+                if let Some(education) = graduation {
+                    return education.graduation_year.to_string();
+                }
+                // Students who turn 18 before September graduate in the summer of that year.
+                let year = self.date_of_birth.year() + 18;
+                let year = if self.date_of_birth.month() >= 9 {
+                    year + 1
+                } else {
+                    year
+                };
+                // Like generated educations, only graduations before the current year count.
+                let reference = self.date_of_death.unwrap_or_else(Utc::now);
+                if year < reference.year() {
+                    year.to_string()
+                } else {
+                    "N/A".to_string()
+                }
+            }
+        }
+    }
+
+    /// Returns `n` different security questions with their answers, see [`Person::get_security_answer`].
+    /// The same `Person` always gets the same questions.
+    pub fn get_security_questions(&self, n: usize) -> Vec<(SecurityQuestion, String)> {
+        SecurityQuestion::ALL
+            .choose_multiple(&mut self.identity_rng(u64::MAX), n)
+            .map(|&question| (question, self.get_security_answer(question)))
+            .collect()
+    }
+
+    /// Returns a random number generator seeded from the `Person`'s name and date of birth,
+    /// so attributes derived with it are stable for the same `Person`.
+    pub(crate) fn identity_rng(&self, salt: u64) -> SeededRng {
+        // FNV-1a, which unlike the standard library's hasher is stable across releases.
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let timestamp = self.date_of_birth.timestamp_millis().to_le_bytes();
+        for byte in self
+            .get_full_name()
+            .bytes()
+            .chain(timestamp)
+            .chain(salt.to_le_bytes())
+        {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        SeededRng::seed_from_u64(hash)
+    }
+}
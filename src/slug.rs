@@ -0,0 +1,28 @@
+use crate::transliterate;
+
+/// The letter case of a slug created with [`Person::get_slug_with`](crate::Person::get_slug_with).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SlugCase {
+    /// "jane-q-doe".
+    #[default]
+    Lower,
+    /// "JANE-Q-DOE".
+    Upper,
+    /// "Jane-Q-Doe", keeping the case of the name.
+    Preserve,
+}
+
+/// Turns the name into a URL-safe slug made of ASCII letters and digits joined by the separator.
+/// Apostrophes are removed and every other non-alphanumeric character separates words.
+pub(crate) fn slugify(name: &str, separator: &str, case: SlugCase) -> String {
+    let name = transliterate::to_ascii(name).replace(['\'', '’'], "");
+    let words = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| match case {
+            SlugCase::Lower => word.to_ascii_lowercase(),
+            SlugCase::Upper => word.to_ascii_uppercase(),
+            SlugCase::Preserve => word.to_string(),
+        });
+    words.collect::<Vec<_>>().join(separator)
+}
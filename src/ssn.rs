@@ -0,0 +1,80 @@
+use rand::Rng;
+
+use crate::Person;
+
+/// The salt of the identity random number generator for Social Security numbers.
+const SSN_SALT: u64 = 0x5353_4e00;
+
+/// A United States Social Security number in the 900-999 area, which the Social Security
+/// Administration never issues, so it can't belong to a real person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ssn {
+    area: u16,
+    group: u8,
+    serial: u16,
+}
+impl Ssn {
+    /// Generates a random never-issued Social Security number.
+    pub fn random() -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Ssn::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            area: rng.gen_range(900..=999),
+            group: rng.gen_range(1..=99),
+            serial: rng.gen_range(1..=9999),
+        }
+    }
+
+    /// Returns the area number, the first three digits.
+    pub fn area(&self) -> u16 {
+        self.area
+    }
+
+    /// Returns the group number, the middle two digits.
+    pub fn group(&self) -> u8 {
+        self.group
+    }
+
+    /// Returns the serial number, the last four digits.
+    pub fn serial(&self) -> u16 {
+        self.serial
+    }
+
+    /// Returns the number formatted with dashes, like "912-34-5678".
+    pub fn formatted(&self) -> String {
+        format!("{:03}-{:02}-{:04}", self.area, self.group, self.serial)
+    }
+
+    /// Returns the nine digits without dashes, like "912345678".
+    pub fn raw(&self) -> String {
+        format!("{:03}{:02}{:04}", self.area, self.group, self.serial)
+    }
+}
+impl std::fmt::Display for Ssn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.formatted())
+    }
+}
+
+impl Person {
+    /// Returns the `Person`'s Social Security number, which is always the same for the same name
+    /// and date of birth and lies in the never-issued 900-999 area.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::Person;
+    /// let dob = Utc.with_ymd_and_hms(1990, 5, 17, 0, 0, 0).unwrap();
+    /// let person = Person::from_parts("Jane", None, "Doe", dob);
+    /// let ssn = person.get_ssn();
+    /// assert!(ssn.area() >= 900);
+    /// assert_eq!(ssn.formatted().len(), 11);
+    /// assert_eq!(ssn.raw(), ssn.formatted().replace('-', ""));
+    /// assert_eq!(ssn, person.get_ssn());
+    /// ```
+    pub fn get_ssn(&self) -> Ssn {
+        Ssn::random_custom_rng(&mut self.identity_rng(SSN_SALT))
+    }
+}
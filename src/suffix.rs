@@ -0,0 +1,40 @@
+/// A generational suffix placed after a `Person`'s name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Suffix {
+    Jr,
+    Sr,
+    II,
+    III,
+    IV,
+}
+impl Suffix {
+    /// All suffixes.
+    pub const ALL: [Suffix; 5] = [Suffix::Jr, Suffix::Sr, Suffix::II, Suffix::III, Suffix::IV];
+
+    /// Returns the abbreviated suffix, e.g. "Jr.".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Jr => "Jr.",
+            Self::Sr => "Sr.",
+            Self::II => "II",
+            Self::III => "III",
+            Self::IV => "IV",
+        }
+    }
+
+    /// Parses a suffix like "Jr." or "III", ignoring case and the trailing dot.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().trim_end_matches('.');
+        Self::ALL.into_iter().find(|suffix| {
+            suffix
+                .as_str()
+                .trim_end_matches('.')
+                .eq_ignore_ascii_case(s)
+        })
+    }
+}
+impl std::fmt::Display for Suffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
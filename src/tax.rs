@@ -0,0 +1,182 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{is_luhn_valid, national_id, payment, random_digits, Country};
+
+/// The prefixes the IRS assigns employer identification numbers from.
+const EIN_PREFIXES: [std::ops::RangeInclusive<u32>; 9] = [
+    1..=6,
+    10..=16,
+    20..=27,
+    30..=48,
+    50..=68,
+    71..=77,
+    80..=88,
+    90..=95,
+    98..=99,
+];
+
+/// Generates a random United States employer identification number, like "12-3456789".
+/// ## Example
+/// ```rust
+/// use person::{is_valid_ein, random_ein};
+/// let ein = random_ein();
+/// assert_eq!(ein.len(), 10);
+/// assert!(is_valid_ein(&ein));
+/// ```
+pub fn random_ein() -> String {
+    random_ein_custom_rng(&mut rand::thread_rng())
+}
+
+/// Same as [`random_ein`], but uses the given random number generator.
+pub fn random_ein_custom_rng<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let prefixes = EIN_PREFIXES.choose(rng).unwrap().clone();
+    let prefix = rng.gen_range(prefixes);
+    format!("{prefix:02}-{}", random_digits(rng, 7))
+}
+
+/// Returns whether the employer identification number has the format "12-3456789", with or without
+/// the dash, and a prefix the IRS assigns.
+pub fn is_valid_ein(ein: &str) -> bool {
+    let digits = ein.replacen('-', "", 1);
+    (ein.len() == 9 || ein.find('-') == Some(2))
+        && digits.len() == 9
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && EIN_PREFIXES
+            .iter()
+            .any(|prefix| prefix.contains(&digits[..2].parse().unwrap()))
+}
+
+/// Generates a random VAT identification number of the country with a correct check digit, like
+/// "DE136695976", as used on invoices of companies and sole traders. Returns `None` for countries
+/// outside of Europe. Spanish numbers are in the format of sole traders, whose VAT ID is their DNI.
+/// ## Example
+/// ```rust
+/// use person::{is_valid_vat_id, random_vat_id, Country};
+/// let vat_id = random_vat_id(Country::France).unwrap();
+/// assert!(vat_id.starts_with("FR"));
+/// assert!(is_valid_vat_id(&vat_id));
+/// assert_eq!(random_vat_id(Country::Japan), None);
+/// ```
+pub fn random_vat_id(country: Country) -> Option<String> {
+    random_vat_id_custom_rng(&mut rand::thread_rng(), country)
+}
+
+/// Same as [`random_vat_id`], but uses the given random number generator.
+pub fn random_vat_id_custom_rng<R: Rng + ?Sized>(rng: &mut R, country: Country) -> Option<String> {
+    let number = match country {
+        Country::Germany => {
+            let base = format!("{}{}", rng.gen_range(1..=9), random_digits(rng, 7));
+            format!("{base}{}", german_check_digit(&base))
+        }
+        Country::France => {
+            let mut siren = random_digits(rng, 8);
+            siren.push(payment::luhn_check_digit(&siren));
+            format!("{:02}{siren}", french_key(&siren))
+        }
+        Country::Spain => {
+            let number = rng.gen_range(0..100_000_000);
+            format!("{number:08}{}", national_id::dni_letter(number))
+        }
+        Country::Italy => {
+            let mut base = format!("{}{:03}", random_digits(rng, 7), rng.gen_range(1..=100));
+            base.push(payment::luhn_check_digit(&base));
+            base
+        }
+        Country::Netherlands => loop {
+            let number = random_digits(rng, 9);
+            if national_id::bsn_sum(&number) % 11 == 0 {
+                break format!("{number}B{:02}", rng.gen_range(1..=99));
+            }
+        },
+        Country::UnitedKingdom => {
+            let base = format!("{}{}", rng.gen_range(1..=9), random_digits(rng, 6));
+            format!("{base}{:02}", (97 - british_sum(&base) % 97) % 97)
+        }
+        Country::UnitedStates | Country::Canada | Country::Australia | Country::Japan => {
+            return None
+        }
+    };
+    Some(format!("{}{number}", country.code()))
+}
+
+/// Returns whether the VAT identification number has the format of its country prefix and
+/// a correct check digit. Spaces are ignored, and only the countries [`random_vat_id`] generates
+/// numbers for are supported.
+/// ## Example
+/// ```rust
+/// use person::is_valid_vat_id;
+/// assert!(is_valid_vat_id("DE136695976"));
+/// assert!(is_valid_vat_id("FR 40 303265045"));
+/// assert!(is_valid_vat_id("GB980780684"));
+/// assert!(!is_valid_vat_id("DE136695977"));
+/// ```
+pub fn is_valid_vat_id(vat_id: &str) -> bool {
+    let vat_id: String = vat_id.chars().filter(|&c| c != ' ').collect();
+    if !vat_id.is_ascii() || vat_id.len() < 3 {
+        return false;
+    }
+    let (prefix, number) = vat_id.split_at(2);
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    match prefix {
+        "DE" => {
+            number.len() == 9
+                && all_digits(number)
+                && !number.starts_with('0')
+                && number[8..] == german_check_digit(&number[..8]).to_string()
+        }
+        "FR" => {
+            number.len() == 11
+                && all_digits(number)
+                && is_luhn_valid(&number[2..])
+                && number[..2] == format!("{:02}", french_key(&number[2..]))
+        }
+        "ES" => {
+            number.len() == 9
+                && all_digits(&number[..8])
+                && number[8..] == national_id::dni_letter(number[..8].parse().unwrap()).to_string()
+        }
+        "IT" => number.len() == 11 && all_digits(number) && is_luhn_valid(number),
+        "NL" => {
+            number.len() == 12
+                && all_digits(&number[..9])
+                && &number[9..10] == "B"
+                && all_digits(&number[10..])
+                && national_id::bsn_sum(&number[..9]) % 11 == 0
+        }
+        "GB" => {
+            let sum = british_sum(number.get(..7).unwrap_or_default())
+                + number
+                    .get(7..)
+                    .and_then(|s| s.parse::<u32>().ok())
+                    .unwrap_or(0);
+            number.len() == 9
+                && all_digits(number)
+                && (sum.is_multiple_of(97) || (sum + 55).is_multiple_of(97))
+        }
+        _ => false,
+    }
+}
+
+/// Computes the check digit of a German VAT ID with ISO 7064 MOD 11,10.
+fn german_check_digit(digits: &str) -> u32 {
+    let product = digits.chars().fold(10, |product, c| {
+        let sum = (c.to_digit(10).unwrap() + product) % 10;
+        let sum = if sum == 0 { 10 } else { sum };
+        2 * sum % 11
+    });
+    (11 - product) % 10
+}
+
+/// Computes the two-digit key of a French VAT ID from the SIREN, the company number.
+fn french_key(siren: &str) -> u64 {
+    (12 + 3 * (siren.parse::<u64>().unwrap() % 97)) % 97
+}
+
+/// Computes the weighted sum of the first seven digits of a British VAT number.
+fn british_sum(digits: &str) -> u32 {
+    digits
+        .chars()
+        .zip((2..=8).rev())
+        .map(|(c, weight)| c.to_digit(10).unwrap_or(0) * weight)
+        .sum()
+}
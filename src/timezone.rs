@@ -0,0 +1,183 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc, Weekday};
+
+use crate::Country;
+
+/// When a time zone observes daylight saving time, following the current rules for every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaylightSaving {
+    None,
+    /// From the second Sunday of March until the first Sunday of November, at 2:00 local time.
+    NorthAmerica,
+    /// From the last Sunday of March until the last Sunday of October, at 1:00 UTC.
+    Europe,
+    /// From the first Sunday of October until the first Sunday of April, at 2:00 local standard time.
+    SouthernAustralia,
+}
+
+/// An IANA time zone of the supported countries.
+/// Daylight saving time follows today's rules for every year, so offsets of dates before the rules
+/// were introduced may be off by an hour.
+/// ## Example
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use person::Timezone;
+/// let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+/// let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+/// assert_eq!(Timezone::NewYork.offset_at(winter).local_minus_utc(), -5 * 3600);
+/// assert_eq!(Timezone::NewYork.offset_at(summer).local_minus_utc(), -4 * 3600);
+/// assert_eq!(Timezone::Sydney.offset_at(winter).local_minus_utc(), 11 * 3600);
+/// assert_eq!(Timezone::Tokyo.to_local(summer).to_rfc3339(), "2024-07-15T21:00:00+09:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Timezone {
+    NewYork,
+    Chicago,
+    Denver,
+    Phoenix,
+    LosAngeles,
+    Toronto,
+    Winnipeg,
+    Edmonton,
+    Vancouver,
+    London,
+    Berlin,
+    Paris,
+    Madrid,
+    Rome,
+    Amsterdam,
+    Sydney,
+    Melbourne,
+    Brisbane,
+    Adelaide,
+    Perth,
+    Hobart,
+    Tokyo,
+}
+impl Timezone {
+    /// Returns the IANA name, like "America/New_York".
+    pub fn iana_name(self) -> &'static str {
+        match self {
+            Self::NewYork => "America/New_York",
+            Self::Chicago => "America/Chicago",
+            Self::Denver => "America/Denver",
+            Self::Phoenix => "America/Phoenix",
+            Self::LosAngeles => "America/Los_Angeles",
+            Self::Toronto => "America/Toronto",
+            Self::Winnipeg => "America/Winnipeg",
+            Self::Edmonton => "America/Edmonton",
+            Self::Vancouver => "America/Vancouver",
+            Self::London => "Europe/London",
+            Self::Berlin => "Europe/Berlin",
+            Self::Paris => "Europe/Paris",
+            Self::Madrid => "Europe/Madrid",
+            Self::Rome => "Europe/Rome",
+            Self::Amsterdam => "Europe/Amsterdam",
+            Self::Sydney => "Australia/Sydney",
+            Self::Melbourne => "Australia/Melbourne",
+            Self::Brisbane => "Australia/Brisbane",
+            Self::Adelaide => "Australia/Adelaide",
+            Self::Perth => "Australia/Perth",
+            Self::Hobart => "Australia/Hobart",
+            Self::Tokyo => "Asia/Tokyo",
+        }
+    }
+
+    /// Returns the time zone of the country's capital or largest city.
+    pub fn for_country(country: Country) -> Self {
+        match country {
+            Country::UnitedStates => Self::NewYork,
+            Country::Canada => Self::Toronto,
+            Country::UnitedKingdom => Self::London,
+            Country::Germany => Self::Berlin,
+            Country::France => Self::Paris,
+            Country::Spain => Self::Madrid,
+            Country::Italy => Self::Rome,
+            Country::Netherlands => Self::Amsterdam,
+            Country::Australia => Self::Sydney,
+            Country::Japan => Self::Tokyo,
+        }
+    }
+
+    /// Returns the offset from UTC outside of daylight saving time.
+    pub fn standard_offset(self) -> FixedOffset {
+        let minutes = match self {
+            Self::NewYork | Self::Toronto => -5 * 60,
+            Self::Chicago | Self::Winnipeg => -6 * 60,
+            Self::Denver | Self::Phoenix | Self::Edmonton => -7 * 60,
+            Self::LosAngeles | Self::Vancouver => -8 * 60,
+            Self::London => 0,
+            Self::Berlin | Self::Paris | Self::Madrid | Self::Rome | Self::Amsterdam => 60,
+            Self::Perth => 8 * 60,
+            Self::Tokyo => 9 * 60,
+            Self::Adelaide => 9 * 60 + 30,
+            Self::Sydney | Self::Melbourne | Self::Brisbane | Self::Hobart => 10 * 60,
+        };
+        FixedOffset::east_opt(minutes * 60).unwrap()
+    }
+
+    /// Returns the offset from UTC at the given time, including daylight saving time.
+    pub fn offset_at(self, time: DateTime<Utc>) -> FixedOffset {
+        let standard = self.standard_offset().local_minus_utc();
+        let year = time.year();
+        // The local standard time at which the clocks change on the given day, in UTC.
+        let change = |month, weekday_number, hour: i64| {
+            let date = nth_sunday(year, month, weekday_number);
+            Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+                + chrono::Duration::hours(hour)
+                - chrono::Duration::seconds(standard as i64)
+        };
+        let is_daylight_saving = match self.daylight_saving() {
+            DaylightSaving::None => false,
+            DaylightSaving::NorthAmerica => time >= change(3, 2, 2) && time < change(11, 1, 1),
+            DaylightSaving::Europe => {
+                let change = |month| {
+                    Utc.from_utc_datetime(&nth_sunday(year, month, 5).and_hms_opt(1, 0, 0).unwrap())
+                };
+                time >= change(3) && time < change(10)
+            }
+            DaylightSaving::SouthernAustralia => time < change(4, 1, 2) || time >= change(10, 1, 2),
+        };
+        let daylight_saving = if is_daylight_saving { 3600 } else { 0 };
+        FixedOffset::east_opt(standard + daylight_saving).unwrap()
+    }
+
+    /// Converts the time to the local time of the time zone.
+    pub fn to_local(self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        time.with_timezone(&self.offset_at(time))
+    }
+
+    fn daylight_saving(self) -> DaylightSaving {
+        match self {
+            Self::NewYork
+            | Self::Chicago
+            | Self::Denver
+            | Self::LosAngeles
+            | Self::Toronto
+            | Self::Winnipeg
+            | Self::Edmonton
+            | Self::Vancouver => DaylightSaving::NorthAmerica,
+            Self::London
+            | Self::Berlin
+            | Self::Paris
+            | Self::Madrid
+            | Self::Rome
+            | Self::Amsterdam => DaylightSaving::Europe,
+            Self::Sydney | Self::Melbourne | Self::Adelaide | Self::Hobart => {
+                DaylightSaving::SouthernAustralia
+            }
+            Self::Phoenix | Self::Brisbane | Self::Perth | Self::Tokyo => DaylightSaving::None,
+        }
+    }
+}
+impl std::fmt::Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.iana_name())
+    }
+}
+
+/// Returns the `n`th Sunday of the month, or the last one if the month has fewer.
+fn nth_sunday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n)
+        .or_else(|| NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n - 1))
+        .unwrap()
+}
@@ -0,0 +1,52 @@
+use crate::Gender;
+
+/// An honorific title placed before a `Person`'s name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Title {
+    Mr,
+    Mrs,
+    Ms,
+    Miss,
+    Mx,
+    Dr,
+    Prof,
+}
+impl Title {
+    /// All titles.
+    pub const ALL: [Title; 7] = [
+        Title::Mr,
+        Title::Mrs,
+        Title::Ms,
+        Title::Miss,
+        Title::Mx,
+        Title::Dr,
+        Title::Prof,
+    ];
+
+    /// Returns the titles that fit the given gender. Gender-neutral titles are used if there is no gender.
+    pub fn for_gender(gender: Option<Gender>) -> &'static [Title] {
+        match gender {
+            Some(Gender::Female) => &[Title::Ms, Title::Mrs, Title::Miss, Title::Dr, Title::Prof],
+            Some(Gender::Male) => &[Title::Mr, Title::Dr, Title::Prof],
+            Some(Gender::NonBinary) | None => &[Title::Mx, Title::Dr, Title::Prof],
+        }
+    }
+
+    /// Returns the abbreviated title, e.g. "Dr.".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mr => "Mr.",
+            Self::Mrs => "Mrs.",
+            Self::Ms => "Ms.",
+            Self::Miss => "Miss",
+            Self::Mx => "Mx.",
+            Self::Dr => "Dr.",
+            Self::Prof => "Prof.",
+        }
+    }
+}
+impl std::fmt::Display for Title {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
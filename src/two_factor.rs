@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+
+use crate::{hash::hmac_sha1, random_digits, Person};
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// The number of backup codes of a random [`TwoFactor`].
+pub const BACKUP_CODE_COUNT: usize = 10;
+
+/// A TOTP (RFC 6238) secret with one-time backup codes, for seeding two-factor enrollment and recovery flows.
+/// ## Example
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use person::TwoFactor;
+/// // The test vector of RFC 6238.
+/// let two_factor = TwoFactor {
+///     account: "jdoe".into(),
+///     secret: "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ".into(),
+///     backup_codes: vec![],
+/// };
+/// assert_eq!(two_factor.totp_at(Utc.timestamp_opt(59, 0).unwrap()), "287082");
+/// assert_eq!(
+///     two_factor.otpauth_uri("Acme Inc"),
+///     "otpauth://totp/Acme%20Inc:jdoe?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Acme%20Inc"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TwoFactor {
+    /// The account name shown by authenticator apps.
+    pub account: String,
+    /// The base32 encoded secret, without padding.
+    pub secret: String,
+    /// Backup codes like "1234-5678".
+    pub backup_codes: Vec<String>,
+}
+impl TwoFactor {
+    /// Creates a random 160 bit secret and [`BACKUP_CODE_COUNT`] backup codes for the account.
+    pub fn random(account: &str) -> Self {
+        Self::random_custom_rng(&mut rand::thread_rng(), account)
+    }
+
+    /// Same as [`TwoFactor::random`], but uses the given random number generator.
+    pub fn random_custom_rng<R: Rng + ?Sized>(rng: &mut R, account: &str) -> Self {
+        let secret: [u8; 20] = rng.gen();
+        let backup_codes = (0..BACKUP_CODE_COUNT)
+            .map(|_| format!("{}-{}", random_digits(rng, 4), random_digits(rng, 4)))
+            .collect();
+        Self {
+            account: account.to_string(),
+            secret: base32_encode(&secret),
+            backup_codes,
+        }
+    }
+
+    /// Returns the 6 digit code valid at the given time, using 30 second steps and HMAC-SHA1
+    /// like most authenticator apps.
+    pub fn totp_at(&self, time: DateTime<Utc>) -> String {
+        let step = (time.timestamp().max(0) / 30) as u64;
+        let hmac = hmac_sha1(&base32_decode(&self.secret), &step.to_be_bytes());
+        let offset = (hmac[19] & 0x0f) as usize;
+        let code = u32::from_be_bytes([
+            hmac[offset] & 0x7f,
+            hmac[offset + 1],
+            hmac[offset + 2],
+            hmac[offset + 3],
+        ]);
+        format!("{:06}", code % 1_000_000)
+    }
+
+    /// Returns the code valid now.
+    pub fn totp_now(&self) -> String {
+        self.totp_at(Utc::now())
+    }
+
+    /// Returns the `otpauth://` URI authenticator apps enroll from, usually shown as a QR code.
+    pub fn otpauth_uri(&self, issuer: &str) -> String {
+        let issuer = percent_encode(issuer);
+        format!(
+            "otpauth://totp/{issuer}:{}?secret={}&issuer={issuer}",
+            percent_encode(&self.account),
+            self.secret
+        )
+    }
+}
+
+impl Person {
+    /// Generates a random TOTP secret and backup codes for the `Person`'s
+    /// [canonical username](Person::get_canonical_username).
+    pub fn get_random_two_factor(&self) -> TwoFactor {
+        self.get_random_two_factor_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_two_factor`], but uses the given random number generator.
+    pub fn get_random_two_factor_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> TwoFactor {
+        TwoFactor::random_custom_rng(rng, &self.get_canonical_username())
+    }
+}
+
+fn base32_encode(data: &[u8]) -> String {
+    let mut result = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        result.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    result
+}
+
+/// Decodes base32, ignoring padding, whitespace, case and invalid characters.
+fn base32_decode(data: &str) -> Vec<u8> {
+    let mut result = vec![];
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in data.chars() {
+        let Some(value) = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a as char == c.to_ascii_uppercase())
+        else {
+            continue;
+        };
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+    result
+}
+
+/// Percent-encodes everything except unreserved URI characters.
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
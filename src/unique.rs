@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use rand::{rngs::ThreadRng, Rng};
+
+use crate::{Person, PersonError, PersonGenerator, DEFAULT_MAX_ATTEMPTS};
+
+/// Wraps a [`PersonGenerator`] and guarantees that no full name (and optionally no username)
+/// is emitted twice, e.g. for seeding databases with unique constraints.
+/// ## Example
+/// ```rust
+/// use person::{NameList, PersonError, PersonGenerator, UniqueGenerator};
+/// let generator = PersonGenerator::from_seed(5)
+///     .with_middle_name_probability(0.0)
+///     .with_first_names(NameList::from_iter(["Jane", "John"]))
+///     .with_last_names(NameList::from_iter(["Doe"]));
+/// let mut unique = UniqueGenerator::new(generator);
+/// assert!(unique.generate().is_ok());
+/// assert!(unique.generate().is_ok());
+/// assert!(matches!(unique.generate(), Err(PersonError::UniqueNamesExhausted { .. })));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UniqueGenerator<R = ThreadRng> {
+    generator: PersonGenerator<R>,
+    full_names: HashSet<String>,
+    usernames: HashSet<String>,
+    max_attempts: usize,
+}
+impl<R: Rng> UniqueGenerator<R> {
+    pub fn new(generator: PersonGenerator<R>) -> Self {
+        Self {
+            generator,
+            full_names: HashSet::new(),
+            usernames: HashSet::new(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    /// Sets how many duplicates in a row are generated before the name space is considered exhausted.
+    /// Defaults to [`DEFAULT_MAX_ATTEMPTS`].
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Returns how many unique `Person`s have been emitted.
+    pub fn len(&self) -> usize {
+        self.full_names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.full_names.is_empty()
+    }
+
+    /// Returns whether a `Person` with the given full name has been emitted.
+    pub fn contains_full_name(&self, full_name: &str) -> bool {
+        self.full_names.contains(full_name)
+    }
+
+    /// Returns whether the given username has been emitted by [`UniqueGenerator::generate_with_username`].
+    pub fn contains_username(&self, username: &str) -> bool {
+        self.usernames.contains(username)
+    }
+
+    /// Generates a `Person` whose full name hasn't been emitted before.
+    pub fn generate(&mut self) -> Result<Person, PersonError> {
+        for _ in 0..self.max_attempts {
+            let person = self.generator.try_generate()?;
+            if self.full_names.insert(person.get_full_name()) {
+                return Ok(person);
+            }
+        }
+        Err(PersonError::UniqueNamesExhausted {
+            unique: self.full_names.len(),
+        })
+    }
+
+    /// Generates `n` `Person`s with unique full names.
+    pub fn generate_n(&mut self, n: usize) -> Result<Vec<Person>, PersonError> {
+        let mut persons = Vec::with_capacity(n);
+        for _ in 0..n {
+            persons.push(self.generate()?);
+        }
+        Ok(persons)
+    }
+
+    /// Generates a `Person` and a username for it, neither of which has been emitted before.
+    pub fn generate_with_username(&mut self) -> Result<(Person, String), PersonError> {
+        for _ in 0..self.max_attempts {
+            let person = self.generator.try_generate()?;
+            if self.full_names.contains(&person.get_full_name()) {
+                continue;
+            }
+            let username = self.generator.generate_username(&person);
+            if self.usernames.insert(username.clone()) {
+                self.full_names.insert(person.get_full_name());
+                return Ok((person, username));
+            }
+        }
+        Err(PersonError::UniqueNamesExhausted {
+            unique: self.full_names.len(),
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use chrono::Utc;
+use rand::Rng;
+
+use crate::{
+    username_part, GenerationConfig, Person, UsernameNumber, UsernameSeparator,
+    DEFAULT_MAX_ATTEMPTS,
+};
+
+/// The rules a username generated with [`Person::get_random_username_with_policy`] follows.
+/// ## Example
+/// ```rust
+/// use person::{Person, UsernamePolicy, UsernameSeparator};
+/// let policy = UsernamePolicy {
+///     max_length: 12,
+///     separators: vec![UsernameSeparator::Dot],
+///     ..UsernamePolicy::clean()
+/// };
+/// let username = Person::random().get_random_username_with_policy(&policy);
+/// assert!((3..=12).contains(&username.len()));
+/// assert!(username.chars().all(|c| c.is_lowercase() || c == '.'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsernamePolicy {
+    /// The minimum number of characters, lowered to the maximum if it is larger. Defaults to 3.
+    pub min_length: usize,
+    /// The maximum number of characters. Defaults to 20.
+    pub max_length: usize,
+    /// The separators a username picks from. Defaults to all of them,
+    /// an empty list means the names are joined without a separator.
+    pub separators: Vec<UsernameSeparator>,
+    /// Whether a number may be appended. Defaults to `true`.
+    pub numbers: bool,
+    /// Whether characters may be leetified. Defaults to `true`.
+    pub leet: bool,
+    /// Whether the username is lowercased. Defaults to `false`.
+    pub lowercase: bool,
+}
+impl Default for UsernamePolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 3,
+            max_length: 20,
+            separators: GenerationConfig::default().separators,
+            numbers: true,
+            leet: true,
+            lowercase: false,
+        }
+    }
+}
+impl UsernamePolicy {
+    /// A policy for clean lowercase usernames: no numbers and no leetification.
+    pub fn clean() -> Self {
+        Self {
+            numbers: false,
+            leet: false,
+            lowercase: true,
+            ..Self::default()
+        }
+    }
+
+    fn config(&self) -> GenerationConfig {
+        let defaults = GenerationConfig::default();
+        GenerationConfig {
+            separators: self.separators.clone(),
+            number_styles: if self.numbers {
+                defaults.number_styles.clone()
+            } else {
+                vec![UsernameNumber::None]
+            },
+            leet_probability: if self.leet {
+                defaults.leet_probability
+            } else {
+                0.0
+            },
+            ..defaults
+        }
+    }
+}
+
+impl Person {
+    /// Derives a username from the first initial and the last name without any randomness,
+    /// so the same `Person` always maps to the same username. Mononyms use their first name.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("José", None, "O'Brien-Smith", Utc::now());
+    /// assert_eq!(person.get_canonical_username(), "jobriensmith");
+    /// ```
+    pub fn get_canonical_username(&self) -> String {
+        let first_name = username_part(&self.first_name, true).to_lowercase();
+        if self.is_mononym() {
+            return first_name;
+        }
+        let initial: String = first_name.chars().take(1).collect();
+        initial + &username_part(&self.last_name, true).to_lowercase()
+    }
+
+    /// Generates a random username following the given policy. Usernames that are too long are shortened,
+    /// usernames that are too short are padded with random digits, or with their last letter
+    /// if numbers are not allowed. The maximum length always wins over the minimum length.
+    /// ## Example
+    /// ```rust
+    /// use person::{Person, UsernamePolicy};
+    /// let policy = UsernamePolicy {
+    ///     min_length: 30,
+    ///     max_length: 10,
+    ///     ..UsernamePolicy::default()
+    /// };
+    /// let username = Person::random().get_random_username_with_policy(&policy);
+    /// assert_eq!(username.chars().count(), 10);
+    /// ```
+    pub fn get_random_username_with_policy(&self, policy: &UsernamePolicy) -> String {
+        self.get_random_username_with_policy_custom_rng(&mut rand::thread_rng(), policy)
+    }
+
+    /// Same as [`Person::get_random_username_with_policy`], but uses the given random number generator.
+    pub fn get_random_username_with_policy_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        policy: &UsernamePolicy,
+    ) -> String {
+        let username = self.random_username_at(rng, &policy.config(), Utc::now());
+        let mut username = if policy.lowercase {
+            username.to_lowercase()
+        } else {
+            username
+        };
+        if let Some((index, _)) = username.char_indices().nth(policy.max_length) {
+            username.truncate(index);
+        }
+        while username.ends_with(['-', '_', '.']) {
+            username.pop();
+        }
+        let min_length = policy.min_length.min(policy.max_length);
+        while username.chars().count() < min_length {
+            let padding = if policy.numbers {
+                char::from(b'0' + rng.gen_range(0..10))
+            } else {
+                username.chars().last().unwrap_or('x')
+            };
+            username.push(padding);
+        }
+        username
+    }
+    /// Suggests `n` distinct usernames like a signup form would, ranked by style: the canonical username
+    /// and clean ones first, then numbered ones, then leetified ones. Fewer than `n` usernames are returned
+    /// if no new one is found within [`DEFAULT_MAX_ATTEMPTS`] attempts.
+    /// ## Example
+    /// ```rust
+    /// use chrono::Utc;
+    /// use person::Person;
+    /// let person = Person::from_parts("Jane", None, "Doe", Utc::now());
+    /// let candidates = person.get_username_candidates(5);
+    /// assert_eq!(candidates.len(), 5);
+    /// assert_eq!(candidates[0], "jdoe");
+    /// ```
+    pub fn get_username_candidates(&self, n: usize) -> Vec<String> {
+        self.get_username_candidates_custom_rng(&mut rand::thread_rng(), n)
+    }
+
+    /// Same as [`Person::get_username_candidates`], but uses the given random number generator.
+    pub fn get_username_candidates_custom_rng<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        n: usize,
+    ) -> Vec<String> {
+        let now = Utc::now();
+        let clean = GenerationConfig::clean();
+        let numbered = GenerationConfig {
+            number_styles: vec![
+                UsernameNumber::BirthYear,
+                UsernameNumber::Age,
+                UsernameNumber::Random,
+            ],
+            ..clean.clone()
+        };
+        let leetified = GenerationConfig {
+            leet_probability: 0.5,
+            ..numbered.clone()
+        };
+        let mut candidates = vec![self.get_canonical_username()];
+        candidates.truncate(n);
+        // Each style gets its share of the suggestions, the last one fills up the rest.
+        for (tier, config) in [clean, numbered, leetified].iter().enumerate() {
+            let target = if tier == 2 { n } else { n * (tier + 1) / 3 };
+            let to_lowercase = tier < 2;
+            let mut attempts = 0;
+            while candidates.len() < target && attempts < DEFAULT_MAX_ATTEMPTS {
+                attempts += 1;
+                let username = self.random_username_at(rng, config, now);
+                let username = if to_lowercase {
+                    username.to_lowercase()
+                } else {
+                    username
+                };
+                if !candidates.contains(&username) {
+                    candidates.push(username);
+                }
+            }
+        }
+        candidates
+    }
+}
@@ -0,0 +1,250 @@
+use chrono::{Datelike, Utc};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{random_digits, Country, Person, UsState};
+
+/// The youngest age at which a `Person` owns a vehicle.
+const MIN_OWNER_AGE: u32 = 16;
+/// The age of the oldest generated vehicles, in years.
+const MAX_VEHICLE_AGE: i32 = 20;
+/// The characters allowed in a VIN, which leaves out "I", "O" and "Q".
+const VIN_CHARACTERS: &[u8] = b"0123456789ABCDEFGHJKLMNPRSTUVWXYZ";
+/// The weights of the VIN positions in the check digit, where the check digit itself weighs 0.
+const VIN_WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+/// The model year codes of the 10th VIN position, which repeat every 30 years from 1980.
+const MODEL_YEAR_CODES: &[u8; 30] = b"ABCDEFGHJKLMNPRSTVWXY123456789";
+/// The make, model, first model year and world manufacturer identifier of generated vehicles.
+const MODELS: [(&str, &str, i32, &str); 20] = [
+    ("Toyota", "Corolla", 1966, "JTD"),
+    ("Toyota", "Camry", 1982, "4T1"),
+    ("Toyota", "RAV4", 1994, "JTM"),
+    ("Honda", "Civic", 1972, "2HG"),
+    ("Honda", "Accord", 1976, "1HG"),
+    ("Ford", "F-150", 1975, "1FT"),
+    ("Ford", "Mustang", 1964, "1FA"),
+    ("Chevrolet", "Silverado", 1999, "1GC"),
+    ("Chevrolet", "Malibu", 1997, "1G1"),
+    ("Nissan", "Rogue", 2008, "JN8"),
+    ("Hyundai", "Tucson", 2005, "KM8"),
+    ("Tesla", "Model 3", 2017, "5YJ"),
+    ("Volkswagen", "Golf", 1974, "WVW"),
+    ("Volkswagen", "Tiguan", 2008, "WVG"),
+    ("BMW", "3 Series", 1975, "WBA"),
+    ("Mercedes-Benz", "C-Class", 1993, "WDD"),
+    ("Renault", "Clio", 1990, "VF1"),
+    ("Peugeot", "208", 2012, "VF3"),
+    ("Fiat", "500", 2007, "ZFA"),
+    ("SEAT", "Ibiza", 1984, "VSS"),
+];
+/// The letters used on license plates of most countries, which leave out "I", "O" and "Q".
+const PLATE_LETTERS: &[u8] = b"ABCDEFGHJKLMNPRSTUVWXYZ";
+/// The consonants used on Spanish and Dutch license plates.
+const PLATE_CONSONANTS: &[u8] = b"BCDFGHJKLMNPRSTVWXYZ";
+/// The hiragana used on the license plates of privately owned Japanese cars.
+const PLATE_HIRAGANA: [char; 12] = [
+    'さ', 'す', 'せ', 'そ', 'た', 'ち', 'つ', 'て', 'と', 'な', 'に', 'ぬ',
+];
+
+/// A vehicle owned by a `Person`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vehicle {
+    pub make: String,
+    pub model: String,
+    /// The model year.
+    pub year: i32,
+    /// The vehicle identification number, like "1HGCM82633A004352".
+    pub vin: String,
+    /// The license plate in the format of the country, and of the state or city where it varies.
+    pub license_plate: String,
+    /// The country the vehicle is registered in.
+    pub country: Country,
+}
+impl std::fmt::Display for Vehicle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.year, self.make, self.model)
+    }
+}
+
+/// Returns whether the vehicle identification number has 17 characters allowed in a VIN
+/// and a correct check digit in the 9th position.
+/// ## Example
+/// ```rust
+/// use person::is_valid_vin;
+/// assert!(is_valid_vin("1HGCM82633A004352"));
+/// assert!(!is_valid_vin("1HGCM82643A004352"));
+/// assert!(!is_valid_vin("1HGCM8263OA004352"));
+/// ```
+pub fn is_valid_vin(vin: &str) -> bool {
+    vin.len() == 17
+        && vin.bytes().all(|b| VIN_CHARACTERS.contains(&b))
+        && vin.as_bytes()[8] == vin_check_digit(vin.as_bytes())
+}
+
+impl Person {
+    /// Generates a vehicle owned by the `Person`, registered in the country of their address,
+    /// or else of their nationality, with a plate of the state or city of the address.
+    /// Returns `None` if the `Person` is younger than 16, or died before cars were made.
+    /// ## Example
+    /// ```rust
+    /// use person::{is_valid_vin, Person};
+    /// let vehicle = Person::random_adult().get_random_vehicle().unwrap();
+    /// assert!(is_valid_vin(&vehicle.vin));
+    /// assert!(vehicle.to_string().starts_with(&vehicle.year.to_string()));
+    /// ```
+    pub fn get_random_vehicle(&self) -> Option<Vehicle> {
+        self.get_random_vehicle_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Person::get_random_vehicle`], but uses the given random number generator.
+    pub fn get_random_vehicle_custom_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Vehicle> {
+        let now = Utc::now();
+        if self.age_at(now) < MIN_OWNER_AGE {
+            return None;
+        }
+        let last_year = self.date_of_death.unwrap_or(now).year();
+        let &&(make, model, first_year, wmi) = MODELS
+            .iter()
+            .filter(|(_, _, first_year, _)| *first_year <= last_year)
+            .collect::<Vec<_>>()
+            .choose(rng)?;
+        let year = rng.gen_range(first_year.max(last_year - MAX_VEHICLE_AGE)..=last_year);
+
+        let mut vin: Vec<u8> = wmi.bytes().collect();
+        vin.extend((0..5).map(|_| *VIN_CHARACTERS.choose(rng).unwrap()));
+        vin.push(b'0');
+        vin.push(MODEL_YEAR_CODES[(year - 1980).rem_euclid(30) as usize]);
+        vin.push(*VIN_CHARACTERS.choose(rng).unwrap());
+        vin.extend(random_digits(rng, 6).bytes());
+        vin[8] = vin_check_digit(&vin);
+
+        let country = self.financial_country();
+        let address = self
+            .address
+            .as_ref()
+            .filter(|address| address.country == country);
+        let region = address.map_or("", |address| address.region.as_str());
+        let city = address.map_or("", |address| address.city.as_str());
+        Some(Vehicle {
+            make: make.to_string(),
+            model: model.to_string(),
+            year,
+            vin: String::from_utf8(vin).unwrap(),
+            license_plate: license_plate(rng, country, region, city, year),
+            country,
+        })
+    }
+}
+
+/// Computes the check digit of a VIN: the sum of the transliterated characters times the weights of
+/// their positions, modulo 11, where 10 is written as "X".
+fn vin_check_digit(vin: &[u8]) -> u8 {
+    let sum: u32 = vin
+        .iter()
+        .zip(VIN_WEIGHTS)
+        .map(|(&c, weight)| {
+            // Letters count from 1 for "A", skipping a value before "J" and two before "S".
+            let value = match c {
+                b'0'..=b'9' => (c - b'0') as u32,
+                b'A'..=b'H' => (c - b'A' + 1) as u32,
+                b'J'..=b'R' => (c - b'J' + 1) as u32,
+                _ => (c.saturating_sub(b'S') + 2) as u32,
+            };
+            value * weight
+        })
+        .sum();
+    match sum % 11 {
+        10 => b'X',
+        digit => b'0' + digit as u8,
+    }
+}
+
+/// Generates a license plate in the format of the country, the state or province given as `region`
+/// and the city, for a vehicle of the given model year.
+fn license_plate<R: Rng + ?Sized>(
+    rng: &mut R,
+    country: Country,
+    region: &str,
+    city: &str,
+    year: i32,
+) -> String {
+    let mut letters = |alphabet: &[u8], count: usize| -> String {
+        (0..count)
+            .map(|_| *alphabet.choose(rng).unwrap() as char)
+            .collect()
+    };
+    let l = letters(PLATE_LETTERS, 5);
+    let c = letters(PLATE_CONSONANTS, 3);
+    let (l, c) = (l.as_str(), c.as_str());
+    let d = random_digits(rng, 7);
+
+    match country {
+        Country::UnitedStates => {
+            let state =
+                UsState::from_code(region).unwrap_or_else(|| *UsState::ALL.choose(rng).unwrap());
+            match state {
+                UsState::California => format!("{}{}{}", rng.gen_range(1..=9), &l[..3], &d[..3]),
+                UsState::Massachusetts => format!("{}{}{}", rng.gen_range(1..=9), &l[..3], &d[..2]),
+                UsState::Colorado | UsState::Florida => {
+                    format!("{}-{}{}", &l[..3], &l[3..4], &d[..2])
+                }
+                UsState::Illinois => format!("{} {}", &l[..2], &d[..5]),
+                _ => format!("{}-{}", &l[..3], &d[..4]),
+            }
+        }
+        Country::Canada => match region {
+            "QC" => format!("{}{} {}", &l[..1], &d[..2], &l[1..4]),
+            "BC" => format!("{}{} {}{}", &l[..2], &d[..1], &d[1..3], &l[2..3]),
+            _ => format!("{} {}", &l[..4], &d[..3]),
+        },
+        Country::UnitedKingdom => {
+            // The age identifier is the year for registrations from March, and the year plus 50 for
+            // registrations from September, which count towards the next model year.
+            let age = if rng.gen_bool(0.5) {
+                year % 100
+            } else {
+                (year - 1) % 100 + 50
+            };
+            format!("{}{age:02} {}", &l[..2], &l[2..])
+        }
+        Country::Germany => {
+            let district = match city {
+                "Berlin" => "B",
+                "Hamburg" => "HH",
+                "München" => "M",
+                "Köln" => "K",
+                "Frankfurt am Main" => "F",
+                "Stuttgart" => "S",
+                "Leipzig" => "L",
+                "Dresden" => "DD",
+                _ => *["B", "HH", "M", "K", "F", "S"].choose(rng).unwrap(),
+            };
+            let letters = &l[..rng.gen_range(1..=2)];
+            let number = rng.gen_range(1..=9999);
+            format!("{district}-{letters} {number}")
+        }
+        Country::France => format!("{}-{}-{}", &c[..2], &d[..3], &l[..2]),
+        Country::Spain => format!("{} {c}", &d[..4]),
+        Country::Italy => format!("{} {} {}", &l[..2], &d[..3], &l[2..4]),
+        Country::Netherlands => format!("{}-{c}-{}", &d[..2], &d[2..3]),
+        Country::Australia => match region {
+            "VIC" => format!("{}{}{}{}", &d[..1], &l[..2], &d[1..2], &l[2..4]),
+            "QLD" => format!("{} {}", &d[..3], &l[..3]),
+            _ => format!("{}{}{}", &l[..2], &d[..2], &l[2..4]),
+        },
+        Country::Japan => {
+            let office = match city {
+                "Tokyo" => "品川",
+                "Osaka" => "大阪",
+                "Yokohama" => "横浜",
+                "Nagoya" => "名古屋",
+                "Sapporo" => "札幌",
+                "Fukuoka" => "福岡",
+                "Kyoto" => "京都",
+                _ => *["品川", "大阪", "横浜", "名古屋"].choose(rng).unwrap(),
+            };
+            let class = *[300, 330, 500, 530].choose(rng).unwrap();
+            let kana = PLATE_HIRAGANA.choose(rng).unwrap();
+            format!("{office} {class} {kana} {}-{}", &d[..2], &d[2..4])
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use rand::Rng;
+
+use crate::{
+    hash::{keccak256, sha256},
+    Person,
+};
+
+/// The alphabet of Base58, which leaves out "0", "O", "I" and "l".
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// The Base58Check version byte of Bitcoin pay-to-public-key-hash addresses.
+const BITCOIN_P2PKH_VERSION: u8 = 0x00;
+/// The Base58Check version byte of Bitcoin pay-to-script-hash addresses.
+const BITCOIN_P2SH_VERSION: u8 = 0x05;
+/// A cryptocurrency network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CryptoNetwork {
+    Bitcoin,
+    Ethereum,
+}
+impl CryptoNetwork {
+    /// All networks.
+    pub const ALL: [CryptoNetwork; 2] = [CryptoNetwork::Bitcoin, CryptoNetwork::Ethereum];
+
+    /// Generates a random address: a Base58Check pay-to-public-key-hash address like
+    /// "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2" for Bitcoin, or an EIP-55 checksummed address like
+    /// "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed" for Ethereum.
+    /// The addresses hash random bytes instead of a public key, so nobody holds their private key.
+    pub fn random_address(self) -> String {
+        self.random_address_custom_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as [`CryptoNetwork::random_address`], but uses the given random number generator.
+    pub fn random_address_custom_rng<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+        let hash: [u8; 20] = rng.gen();
+        match self {
+            Self::Bitcoin => {
+                let mut payload = vec![BITCOIN_P2PKH_VERSION];
+                payload.extend(hash);
+                base58check(&payload)
+            }
+            Self::Ethereum => {
+                let hex: String = hash.iter().map(|byte| format!("{byte:02x}")).collect();
+                format!("0x{}", eip55_checksum(&hex))
+            }
+        }
+    }
+
+    /// Returns whether the address is valid on the network, including its checksum.
+    /// Bitcoin accepts Base58Check addresses, Ethereum accepts checksummed addresses and
+    /// addresses in a single case, which carry no checksum.
+    /// ## Example
+    /// ```rust
+    /// use person::CryptoNetwork;
+    /// assert!(CryptoNetwork::Bitcoin.is_valid_address("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"));
+    /// assert!(!CryptoNetwork::Bitcoin.is_valid_address("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"));
+    /// assert!(CryptoNetwork::Ethereum.is_valid_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+    /// assert!(!CryptoNetwork::Ethereum.is_valid_address("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+    /// ```
+    pub fn is_valid_address(self, address: &str) -> bool {
+        match self {
+            Self::Bitcoin => base58_decode(address).is_some_and(|bytes| {
+                let (payload, checksum) = bytes.split_at(bytes.len().saturating_sub(4));
+                bytes.len() == 25
+                    && [BITCOIN_P2PKH_VERSION, BITCOIN_P2SH_VERSION].contains(&payload[0])
+                    && checksum == &sha256(&sha256(payload))[..4]
+            }),
+            Self::Ethereum => {
+                let Some(hex) = address.strip_prefix("0x") else {
+                    return false;
+                };
+                hex.len() == 40
+                    && hex.bytes().all(|b| b.is_ascii_hexdigit())
+                    && (hex == hex.to_ascii_lowercase()
+                        || hex == hex.to_ascii_uppercase()
+                        || hex == eip55_checksum(&hex.to_ascii_lowercase()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for CryptoNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bitcoin => write!(f, "Bitcoin"),
+            Self::Ethereum => write!(f, "Ethereum"),
+        }
+    }
+}
+
+impl Person {
+    /// Returns a wallet address of the network for the `Person`, which is always the same for the same
+    /// name and date of birth.
+    /// ## Example
+    /// ```rust
+    /// use person::{CryptoNetwork, Person};
+    /// let person = Person::random();
+    /// let address = person.get_wallet_address(CryptoNetwork::Ethereum);
+    /// assert!(CryptoNetwork::Ethereum.is_valid_address(&address));
+    /// assert_eq!(address, person.get_wallet_address(CryptoNetwork::Ethereum));
+    /// ```
+    pub fn get_wallet_address(&self, network: CryptoNetwork) -> String {
+        let salt = match network {
+            CryptoNetwork::Bitcoin => 0x4254_4300,
+            CryptoNetwork::Ethereum => 0x4554_4800,
+        };
+        network.random_address_custom_rng(&mut self.identity_rng(salt))
+    }
+}
+
+/// Appends the first four bytes of the double SHA-256 digest and encodes the result with Base58.
+fn base58check(payload: &[u8]) -> String {
+    let mut bytes = payload.to_vec();
+    bytes.extend_from_slice(&sha256(&sha256(payload))[..4]);
+    // Leading zero bytes are encoded as "1"s, the rest as a big-endian number in base 58.
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let mut digits: Vec<u8> = vec![];
+    for &byte in &bytes[zeros..] {
+        let mut carry = byte as u32;
+        for digit in &mut digits {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| BASE58_ALPHABET[digit as usize] as char),
+        )
+        .collect()
+}
+
+/// Decodes a Base58 string, or returns `None` if it contains characters outside of the alphabet.
+fn base58_decode(encoded: &str) -> Option<Vec<u8>> {
+    let zeros = encoded.bytes().take_while(|&b| b == b'1').count();
+    let mut bytes: Vec<u8> = vec![];
+    for c in encoded.bytes().skip(zeros) {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in &mut bytes {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    Some(
+        std::iter::repeat_n(0, zeros)
+            .chain(bytes.into_iter().rev())
+            .collect(),
+    )
+}
+
+/// Capitalizes the letters of a lowercase hexadecimal address whose nibble in the Keccak-256 digest
+/// of the address is 8 or more, as specified by EIP-55.
+fn eip55_checksum(hex: &str) -> String {
+    let digest = keccak256(hex.as_bytes());
+    hex.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (digest[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
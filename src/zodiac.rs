@@ -0,0 +1,119 @@
+use chrono::{DateTime, Datelike, Utc};
+
+/// A western zodiac sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ZodiacSign {
+    Aries,
+    Taurus,
+    Gemini,
+    Cancer,
+    Leo,
+    Virgo,
+    Libra,
+    Scorpio,
+    Sagittarius,
+    Capricorn,
+    Aquarius,
+    Pisces,
+}
+impl ZodiacSign {
+    /// All signs, starting with Aries.
+    pub const ALL: [ZodiacSign; 12] = [
+        ZodiacSign::Aries,
+        ZodiacSign::Taurus,
+        ZodiacSign::Gemini,
+        ZodiacSign::Cancer,
+        ZodiacSign::Leo,
+        ZodiacSign::Virgo,
+        ZodiacSign::Libra,
+        ZodiacSign::Scorpio,
+        ZodiacSign::Sagittarius,
+        ZodiacSign::Capricorn,
+        ZodiacSign::Aquarius,
+        ZodiacSign::Pisces,
+    ];
+
+    /// Returns the sign of someone born on the given date.
+    /// ## Example
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use person::ZodiacSign;
+    /// let date = Utc.with_ymd_and_hms(1990, 3, 21, 0, 0, 0).unwrap();
+    /// assert_eq!(ZodiacSign::from_date(date), ZodiacSign::Aries);
+    /// let date = Utc.with_ymd_and_hms(1990, 12, 31, 0, 0, 0).unwrap();
+    /// assert_eq!(ZodiacSign::from_date(date), ZodiacSign::Capricorn);
+    /// ```
+    pub fn from_date(date: DateTime<Utc>) -> Self {
+        match (date.month(), date.day()) {
+            (3, 21..) | (4, ..=19) => Self::Aries,
+            (4, _) | (5, ..=20) => Self::Taurus,
+            (5, _) | (6, ..=20) => Self::Gemini,
+            (6, _) | (7, ..=22) => Self::Cancer,
+            (7, _) | (8, ..=22) => Self::Leo,
+            (8, _) | (9, ..=22) => Self::Virgo,
+            (9, _) | (10, ..=22) => Self::Libra,
+            (10, _) | (11, ..=21) => Self::Scorpio,
+            (11, _) | (12, ..=21) => Self::Sagittarius,
+            (12, _) | (1, ..=19) => Self::Capricorn,
+            (1, _) | (2, ..=18) => Self::Aquarius,
+            _ => Self::Pisces,
+        }
+    }
+}
+impl std::fmt::Display for ZodiacSign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+/// An animal of the Chinese zodiac.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChineseZodiac {
+    Rat,
+    Ox,
+    Tiger,
+    Rabbit,
+    Dragon,
+    Snake,
+    Horse,
+    Goat,
+    Monkey,
+    Rooster,
+    Dog,
+    Pig,
+}
+impl ChineseZodiac {
+    /// All animals in the order of the twelve-year cycle, starting with the Rat.
+    pub const ALL: [ChineseZodiac; 12] = [
+        ChineseZodiac::Rat,
+        ChineseZodiac::Ox,
+        ChineseZodiac::Tiger,
+        ChineseZodiac::Rabbit,
+        ChineseZodiac::Dragon,
+        ChineseZodiac::Snake,
+        ChineseZodiac::Horse,
+        ChineseZodiac::Goat,
+        ChineseZodiac::Monkey,
+        ChineseZodiac::Rooster,
+        ChineseZodiac::Dog,
+        ChineseZodiac::Pig,
+    ];
+
+    /// Returns the animal of the given Gregorian year. Since the Chinese new year falls in January or
+    /// February, someone born early in the year may belong to the previous year's animal.
+    /// ## Example
+    /// ```rust
+    /// use person::ChineseZodiac;
+    /// assert_eq!(ChineseZodiac::from_year(2020), ChineseZodiac::Rat);
+    /// assert_eq!(ChineseZodiac::from_year(1987), ChineseZodiac::Rabbit);
+    /// ```
+    pub fn from_year(year: i32) -> Self {
+        // 1900 was a year of the Rat.
+        Self::ALL[(year - 1900).rem_euclid(12) as usize]
+    }
+}
+impl std::fmt::Display for ChineseZodiac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}